@@ -1,6 +1,6 @@
 multiversx_sc::imports!();
 
-use crate::structs::JobData;
+use crate::structs::{AgentDetails, JobData};
 
 /// Cross-contract storage reads shared across contracts.
 #[multiversx_sc::module]
@@ -12,6 +12,14 @@ pub trait CrossContractModule {
         address: ManagedAddress,
     ) -> BiDiMapper<u64, ManagedAddress, ManagedAddress<Self::Api>>;
 
+    /// Read agent details (name, public key) from identity-registry's `agentDetails` storage.
+    #[storage_mapper_from_address("agentDetails")]
+    fn external_agent_details(
+        &self,
+        address: ManagedAddress,
+        nonce: u64,
+    ) -> SingleValueMapper<AgentDetails<Self::Api>, ManagedAddress>;
+
     /// Read job data from validation-registry's `jobData` storage.
     #[storage_mapper_from_address("jobData")]
     fn external_job_data(
@@ -20,6 +28,14 @@ pub trait CrossContractModule {
         job_id: &ManagedBuffer,
     ) -> SingleValueMapper<JobData<Self::Api>, ManagedAddress>;
 
+    /// Read when a job was verified from validation-registry's `jobVerifiedAt` storage.
+    #[storage_mapper_from_address("jobVerifiedAt")]
+    fn external_job_verified_at(
+        &self,
+        address: ManagedAddress,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<TimestampSeconds, ManagedAddress>;
+
     /// Read agent service config from identity-registry's `agentServiceConfigs` storage.
     #[storage_mapper_from_address("agentServiceConfigs")]
     fn external_agent_service_config(
@@ -28,6 +44,15 @@ pub trait CrossContractModule {
         nonce: u64,
     ) -> MapMapper<u32, Payment<Self::Api>, ManagedAddress<Self::Api>>;
 
+    /// Read how long a service locks its payment in escrow from identity-registry's
+    /// `agentServiceEscrowDurations` storage. Services without an entry are paid directly.
+    #[storage_mapper_from_address("agentServiceEscrowDurations")]
+    fn external_agent_service_escrow_duration(
+        &self,
+        address: ManagedAddress,
+        nonce: u64,
+    ) -> MapMapper<u32, DurationSeconds, ManagedAddress<Self::Api>>;
+
     /// Read agent token ID from identity-registry's NonFungibleTokenMapper.
     #[storage_mapper_from_address("agentTokenId")]
     fn external_agent_token_id(
@@ -0,0 +1,95 @@
+multiversx_sc::imports!();
+
+use crate::errors::{
+    ERR_AGENT_NOT_SYNCED, ERR_AGENT_SNAPSHOT_STALE, ERR_CROSS_SHARD_UNSUPPORTED, ERR_SAME_SHARD,
+};
+use crate::structs::SyncedAgent;
+
+/// How long an agent owner snapshot may be used after it was read.
+pub const MAX_AGENT_SNAPSHOT_AGE: DurationSeconds = DurationSeconds::new(60 * 60);
+
+/// Fallback for the reads in `cross_contract`, which only see contracts on the
+/// caller's shard. When identity-registry lives on another shard, anyone can
+/// call `sync_agent`: an async call reads `get_agent_snapshot` and the
+/// callback caches the owner (or that the agent is missing) with the time of
+/// the read; a failed call leaves the cache as it was. Checks then use the
+/// snapshot, failing with `ERR_AGENT_NOT_SYNCED` until one exists and with
+/// `ERR_AGENT_SNAPSHOT_STALE` once it is older than `MAX_AGENT_SNAPSHOT_AGE`.
+/// Paths that cannot work across shards call `require_same_shard`.
+#[multiversx_sc::module]
+pub trait CrossShardModule: crate::cross_contract::CrossContractModule {
+    fn is_same_shard(&self, address: &ManagedAddress) -> bool {
+        let blockchain = self.blockchain();
+        blockchain.get_shard_of_address(address)
+            == blockchain.get_shard_of_address(&blockchain.get_sc_address())
+    }
+
+    fn require_same_shard(&self, address: &ManagedAddress) {
+        require!(self.is_same_shard(address), ERR_CROSS_SHARD_UNSUPPORTED);
+    }
+
+    /// Current owner of an agent: read from `identity` on the same shard,
+    /// otherwise from the synced snapshot. `None` if the agent does not exist.
+    fn resolve_agent_owner(&self, identity: ManagedAddress, nonce: u64) -> Option<ManagedAddress> {
+        match self.read_agent_owner(identity, nonce) {
+            Ok(owner) => owner,
+            Err(err) => sc_panic!(err),
+        }
+    }
+
+    /// `resolve_agent_owner` without failing: `ERR_AGENT_NOT_SYNCED` if there
+    /// is no snapshot to read, `ERR_AGENT_SNAPSHOT_STALE` if it is too old.
+    fn read_agent_owner(
+        &self,
+        identity: ManagedAddress,
+        nonce: u64,
+    ) -> Result<Option<ManagedAddress>, &'static str> {
+        if self.is_same_shard(&identity) {
+            let agents = self.external_agents(identity);
+            return Ok(agents.contains_id(&nonce).then(|| agents.get_value(&nonce)));
+        }
+
+        let synced_mapper = self.synced_agent(nonce);
+        if synced_mapper.is_empty() {
+            return Err(ERR_AGENT_NOT_SYNCED);
+        }
+        let synced = synced_mapper.get();
+        if self.blockchain().get_block_timestamp_seconds()
+            > synced.synced_at + MAX_AGENT_SNAPSHOT_AGE
+        {
+            return Err(ERR_AGENT_SNAPSHOT_STALE);
+        }
+        Ok(synced.owner)
+    }
+
+    /// Refreshes the owner snapshot of `nonce` from a cross-shard `identity`.
+    fn sync_agent_owner(&self, identity: ManagedAddress, nonce: u64) -> ! {
+        require!(!self.is_same_shard(&identity), ERR_SAME_SHARD);
+        self.tx()
+            .to(&identity)
+            .raw_call("get_agent_snapshot")
+            .argument(&nonce)
+            .callback(self.callbacks().agent_owner_synced(nonce))
+            .async_call_and_exit()
+    }
+
+    #[callback]
+    fn agent_owner_synced(
+        &self,
+        nonce: u64,
+        #[call_result] result: ManagedAsyncCallResult<SyncedAgent<Self::Api>>,
+    ) {
+        // A failed read leaves the previous snapshot in place
+        if let ManagedAsyncCallResult::Ok(synced) = result {
+            self.agent_synced_event(nonce, &synced);
+            self.synced_agent(nonce).set(synced);
+        }
+    }
+
+    #[view(get_synced_agent)]
+    #[storage_mapper("syncedAgent")]
+    fn synced_agent(&self, nonce: u64) -> SingleValueMapper<SyncedAgent<Self::Api>>;
+
+    #[event("agent_synced")]
+    fn agent_synced_event(&self, #[indexed] nonce: u64, synced: &SyncedAgent<Self::Api>);
+}
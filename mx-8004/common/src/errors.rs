@@ -1,3 +1,199 @@
-pub const ERR_JOB_NOT_FOUND: &str = "Job not found";
-pub const ERR_NOT_AGENT_OWNER: &str = "Only the agent owner can perform this action";
-pub const ERR_AGENT_NOT_FOUND: &str = "Agent not found";
+multiversx_sc::imports!();
+
+/// Declares the error catalog: one `ERR_*` message constant per entry, each
+/// prefixed with its stable code (`"E1001: Job not found"`), and the
+/// matching [`ErrorCode`] variant.
+macro_rules! error_catalog {
+    ($($code:literal $variant:ident $name:ident = $description:literal;)*) => {
+        $(pub const $name: &str = concat!("E", stringify!($code), ": ", $description);)*
+
+        /// Every error the MX-8004 contracts can fail with. Codes never change
+        /// once released: 1xxx shared, 2xxx identity, 3xxx validation,
+        /// 4xxx reputation, 5xxx escrow.
+        #[repr(u32)]
+        #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+        pub enum ErrorCode {
+            $($variant = $code,)*
+        }
+
+        impl ErrorCode {
+            pub const ALL: &'static [ErrorCode] = &[$(ErrorCode::$variant,)*];
+
+            pub fn from_code(code: u32) -> Option<Self> {
+                match code {
+                    $($code => Some(ErrorCode::$variant),)*
+                    _ => None,
+                }
+            }
+
+            pub const fn code(self) -> u32 {
+                self as u32
+            }
+
+            /// The full failure message, as signalled by the contract.
+            pub const fn message(self) -> &'static str {
+                match self {
+                    $(ErrorCode::$variant => $name,)*
+                }
+            }
+
+            /// The message without its code prefix.
+            pub const fn description(self) -> &'static str {
+                match self {
+                    $(ErrorCode::$variant => $description,)*
+                }
+            }
+        }
+    };
+}
+
+error_catalog! {
+    // ── Shared ──
+    1001 JobNotFound ERR_JOB_NOT_FOUND = "Job not found";
+    1002 NotAgentOwner ERR_NOT_AGENT_OWNER = "Only the agent owner can perform this action";
+    1003 AgentNotFound ERR_AGENT_NOT_FOUND = "Agent not found";
+    1004 ContractPaused ERR_CONTRACT_PAUSED = "Contract is paused";
+    1005 NotOwner ERR_NOT_OWNER = "Only the owner can call this";
+    1006 NotPendingOwner ERR_NOT_PENDING_OWNER = "Only the pending owner can accept ownership";
+    1007 MissingRole ERR_MISSING_ROLE = "Caller does not have the required role";
+    1008 NotRoleAdmin ERR_NOT_ROLE_ADMIN = "Only the owner or an admin can manage roles";
+    1009 OnlyOwnerManagesAdmins ERR_ONLY_OWNER_MANAGES_ADMINS =
+        "Only the owner can grant or revoke the admin role";
+    1010 MigrationBatchTooLarge ERR_MIGRATION_BATCH_TOO_LARGE =
+        "Migration batch exceeds the maximum size";
+    1011 CrossShardUnsupported ERR_CROSS_SHARD_UNSUPPORTED =
+        "Contract is on another shard; this call needs a same-shard deployment";
+    1012 AgentNotSynced ERR_AGENT_NOT_SYNCED =
+        "Agent not synced from the identity registry on another shard; call sync_agent";
+    1013 SameShard ERR_SAME_SHARD = "Contract is on this shard; reads are direct, no sync needed";
+    1014 ProtocolOwnerNotHandedOver ERR_PROTOCOL_OWNER_NOT_HANDED_OVER =
+        "Change the contract owner address to the new owner before accepting";
+    1015 AgentSnapshotStale ERR_AGENT_SNAPSHOT_STALE =
+        "Agent snapshot is too old; call sync_agent";
+
+    // ── Identity registry ──
+    2001 TokenAlreadyIssued ERR_TOKEN_ALREADY_ISSUED = "Token already issued";
+    2002 TokenNotIssued ERR_TOKEN_NOT_ISSUED = "Token not issued";
+    2003 AgentAlreadyRegistered ERR_AGENT_ALREADY_REGISTERED =
+        "Agent already registered for this address";
+    2004 InvalidNft ERR_INVALID_NFT = "Invalid NFT sent";
+    2005 ServiceNotFound ERR_SERVICE_NOT_FOUND = "Service config not found";
+    2006 RegistrationPaymentMismatch ERR_REGISTRATION_PAYMENT_MISMATCH =
+        "Payment must match the registration fee and bond";
+    2007 NoBond ERR_NO_BOND = "Agent has no bond";
+    2008 InvalidServiceToken ERR_INVALID_SERVICE_TOKEN =
+        "Service price must be in EGLD (nonce 0) or a valid ESDT";
+    2009 InvalidRegistrationToken ERR_INVALID_REGISTRATION_TOKEN =
+        "Registration fee and bond must be in EGLD or a valid ESDT";
+
+    // ── Validation registry ──
+    3001 JobAlreadyInitialized ERR_JOB_ALREADY_INITIALIZED = "Job already initialized";
+    3002 InsufficientPayment ERR_INSUFFICIENT_PAYMENT = "Insufficient payment";
+    3003 AgentServiceNotFound ERR_AGENT_SERVICE_NOT_FOUND = "Service config not found for agent";
+    3004 InvalidPayment ERR_INVALID_PAYMENT = "Invalid payment token";
+    3005 ValidationRequestNotFound ERR_VALIDATION_REQUEST_NOT_FOUND =
+        "Validation request not found";
+    3006 NotValidator ERR_NOT_VALIDATOR = "Only the designated validator can respond";
+    3007 InvalidAgentNft ERR_INVALID_AGENT_NFT = "Invalid agent NFT: wrong token ID or nonce";
+    3008 EscrowNotSet ERR_ESCROW_NOT_SET = "Escrow contract address not set";
+    3009 QuoteNotFound ERR_QUOTE_NOT_FOUND = "Quote not found";
+    3010 QuoteExpired ERR_QUOTE_EXPIRED = "Quote has expired";
+    3011 QuoteMismatch ERR_QUOTE_MISMATCH = "Quote was made for another employer";
+
+    // ── Reputation registry ──
+    4001 NotFeedbackEmployer ERR_NOT_FEEDBACK_EMPLOYER = "Only the employer can provide feedback";
+    4002 FeedbackAlreadyProvided ERR_FEEDBACK_ALREADY_PROVIDED =
+        "Feedback already provided for this job";
+    4003 AgentOwnerCannotSelfReview ERR_AGENT_OWNER_CANNOT_SELF_REVIEW =
+        "Agent owner cannot give feedback to own agent";
+    4004 InvalidValueDecimals ERR_INVALID_VALUE_DECIMALS = "Value decimals must be 0-18";
+    4005 FeedbackNotFound ERR_FEEDBACK_NOT_FOUND = "Feedback not found";
+    4006 FeedbackAlreadyRevoked ERR_FEEDBACK_ALREADY_REVOKED = "Feedback already revoked";
+    4007 FeedbackAuthRequired ERR_FEEDBACK_AUTH_REQUIRED = "Feedback authorization required";
+    4008 FeedbackAuthAgentMismatch ERR_FEEDBACK_AUTH_AGENT_MISMATCH =
+        "Feedback authorization is for another agent";
+    4009 FeedbackAuthClientMismatch ERR_FEEDBACK_AUTH_CLIENT_MISMATCH =
+        "Feedback authorization is for another client";
+    4010 FeedbackAuthIndexLimit ERR_FEEDBACK_AUTH_INDEX_LIMIT = "Feedback index limit exceeded";
+    4011 FeedbackAuthExpired ERR_FEEDBACK_AUTH_EXPIRED = "Feedback authorization expired";
+    4012 FeedbackAuthInvalidSigner ERR_FEEDBACK_AUTH_INVALID_SIGNER =
+        "Feedback authorization signer is not the agent owner";
+    4013 InvalidLeaderboardSize ERR_INVALID_LEADERBOARD_SIZE =
+        "Leaderboard size must be between 1 and 100";
+    4014 NotDisputingAgentOwner ERR_NOT_DISPUTING_AGENT_OWNER =
+        "Only the agent owner can dispute feedback";
+    4015 FeedbackAlreadyDisputed ERR_FEEDBACK_ALREADY_DISPUTED = "Feedback already disputed";
+    4016 DisputeNotFound ERR_DISPUTE_NOT_FOUND = "Dispute not found";
+    4017 DisputeAlreadyResolved ERR_DISPUTE_ALREADY_RESOLVED = "Dispute already resolved";
+    4018 NotModerator ERR_NOT_MODERATOR = "Only a moderator can resolve disputes";
+    4019 FeedbackAgentMismatch ERR_FEEDBACK_AGENT_MISMATCH = "Job was done for another agent";
+
+    // ── Escrow ──
+    5001 EscrowAlreadyExists ERR_ESCROW_ALREADY_EXISTS = "Escrow already exists for this job";
+    5002 EscrowNotFound ERR_ESCROW_NOT_FOUND = "Escrow not found for this job";
+    5003 NotEmployer ERR_NOT_EMPLOYER = "Only the employer can call this";
+    5004 JobNotVerified ERR_JOB_NOT_VERIFIED = "Job must be verified before release";
+    5005 DeadlineNotPassed ERR_DEADLINE_NOT_PASSED = "Deadline has not passed yet";
+    5006 DeadlineInPast ERR_DEADLINE_IN_PAST = "Deadline must be in the future";
+    5007 AlreadySettled ERR_ALREADY_SETTLED = "Escrow already settled";
+    5008 ZeroDeposit ERR_ZERO_DEPOSIT = "Deposit amount must be greater than zero";
+    5009 NoMilestones ERR_NO_MILESTONES = "At least one milestone is required";
+    5010 TooManyMilestones ERR_TOO_MANY_MILESTONES = "Too many milestones";
+    5011 MilestoneAmountMismatch ERR_MILESTONE_AMOUNT_MISMATCH =
+        "Milestone amounts must add up to the deposit";
+    5012 ZeroMilestone ERR_ZERO_MILESTONE = "Milestone amount must be greater than zero";
+    5013 MilestoneNotFound ERR_MILESTONE_NOT_FOUND = "Milestone not found";
+    5014 MilestoneSettled ERR_MILESTONE_SETTLED = "Milestone already settled";
+    5015 HasMilestones ERR_HAS_MILESTONES = "Escrow has milestones; use release_milestone";
+    5016 InvalidArbiter ERR_INVALID_ARBITER = "Arbiter cannot be the employer or the receiver";
+    5017 NotParty ERR_NOT_PARTY = "Only the employer or the receiver can dispute";
+    5018 DeadlinePassed ERR_DEADLINE_PASSED = "Deadline has passed";
+    5019 EscrowDisputed ERR_ESCROW_DISPUTED = "Escrow is under dispute";
+    5020 NotDisputed ERR_NOT_DISPUTED = "Escrow is not under dispute";
+    5021 NotArbiter ERR_NOT_ARBITER = "Only the arbiter can resolve this dispute";
+    5022 SplitExceedsLocked ERR_SPLIT_EXCEEDS_LOCKED = "Split exceeds the locked amount";
+    5023 ChallengeWindowOpen ERR_CHALLENGE_WINDOW_OPEN = "Challenge window has not elapsed";
+    5024 JobVerified ERR_JOB_VERIFIED = "Job is verified; it can only be released";
+    5025 JobNotInValidationRegistry ERR_JOB_NOT_IN_VALIDATION_REGISTRY =
+        "Job not found in the validation registry";
+    5026 JobAgentMismatch ERR_JOB_AGENT_MISMATCH = "Job belongs to another agent";
+    5027 JobEmployerMismatch ERR_JOB_EMPLOYER_MISMATCH = "Job belongs to another employer";
+    5028 FeeTooHigh ERR_FEE_TOO_HIGH = "Fee exceeds the maximum";
+    5029 TreasuryNotSet ERR_TREASURY_NOT_SET = "Treasury address not set";
+    5030 TooManyPayments ERR_TOO_MANY_PAYMENTS = "Too many payments in one deposit";
+    5031 BundleSplit ERR_BUNDLE_SPLIT = "Multi-token escrows settle all-or-nothing";
+    5032 TopUpTokenMismatch ERR_TOP_UP_TOKEN_MISMATCH = "Top-up token is not part of this escrow";
+    5033 DeadlineNotExtended ERR_DEADLINE_NOT_EXTENDED =
+        "New deadline must be later than the current one";
+    5034 NotPartyToCancel ERR_NOT_PARTY_TO_CANCEL = "Only the employer or the receiver can cancel";
+    5035 EmptyBatch ERR_EMPTY_BATCH = "Batch is empty";
+    5036 BatchTooLarge ERR_BATCH_TOO_LARGE = "Batch exceeds the maximum size";
+    5037 IsStream ERR_IS_STREAM = "Escrow is a stream; use withdraw_vested or stop_stream";
+    5038 NotStream ERR_NOT_STREAM = "Escrow is not a stream";
+    5039 InvalidStreamPeriod ERR_INVALID_STREAM_PERIOD = "Stream must end after it starts";
+    5040 NotReceiver ERR_NOT_RECEIVER = "Only the receiver can withdraw";
+    5041 NothingVested ERR_NOTHING_VESTED = "Nothing vested to withdraw";
+    5042 NotValidationRegistry ERR_NOT_VALIDATION_REGISTRY =
+        "Only the validation registry can call this";
+    5043 JobNotSynced ERR_JOB_NOT_SYNCED =
+        "Job not synced from the validation registry on another shard; call sync_job";
+    5044 JobSnapshotStale ERR_JOB_SNAPSHOT_STALE =
+        "Job snapshot was taken before the deadline; call sync_job";
+    5045 ArbiterNotListed ERR_ARBITER_NOT_LISTED = "Arbiter is not on the arbiter list";
+    5046 DisputeWindowClosed ERR_DISPUTE_WINDOW_CLOSED =
+        "Job is verified and its challenge window has elapsed; release it instead";
+}
+
+/// Publishes the error catalog through each contract's ABI.
+#[multiversx_sc::module]
+pub trait ErrorCatalogModule {
+    /// Every `(code, description)` pair the MX-8004 contracts can fail with.
+    #[view(get_error_codes)]
+    fn get_error_codes(&self) -> MultiValueEncoded<MultiValue2<u32, ManagedBuffer>> {
+        let mut codes = MultiValueEncoded::new();
+        for error in ErrorCode::ALL {
+            codes.push((error.code(), ManagedBuffer::from(error.description())).into());
+        }
+        codes
+    }
+}
@@ -1,7 +1,11 @@
 #![no_std]
 
 pub mod cross_contract;
+pub mod cross_shard;
 pub mod errors;
+pub mod migration;
+pub mod pause;
+pub mod roles;
 pub mod structs;
 
 pub use structs::*;
@@ -0,0 +1,33 @@
+multiversx_sc::imports!();
+
+use crate::errors::ERR_MIGRATION_BATCH_TOO_LARGE;
+
+/// Most records one migration call (or upgrade) may rewrite.
+pub const MAX_MIGRATION_BATCH: usize = 100;
+
+/// Storage layout version shared by all MX-8004 contracts. Deployments made
+/// before versioning existed read as version 0. `upgrade` runs the
+/// contract-level steps and records the new version; per-record migrations
+/// detect each record's layout, so they can run in batches before or after.
+#[multiversx_sc::module]
+pub trait StorageVersionModule {
+    #[view(get_storage_version)]
+    #[storage_mapper("storageVersion")]
+    fn storage_version(&self) -> SingleValueMapper<u32>;
+
+    /// Records `version` as the current layout, emitting when it changes.
+    fn set_storage_version(&self, version: u32) {
+        let from_version = self.storage_version().get();
+        if from_version != version {
+            self.storage_version().set(version);
+            self.storage_migrated_event(from_version, version);
+        }
+    }
+
+    fn require_migration_batch(&self, count: usize) {
+        require!(count <= MAX_MIGRATION_BATCH, ERR_MIGRATION_BATCH_TOO_LARGE);
+    }
+
+    #[event("storage_migrated")]
+    fn storage_migrated_event(&self, #[indexed] from_version: u32, #[indexed] to_version: u32);
+}
@@ -0,0 +1,43 @@
+multiversx_sc::imports!();
+
+use crate::errors::ERR_CONTRACT_PAUSED;
+use crate::roles::Role;
+
+/// Emergency stop shared by all MX-8004 contracts. Pausers (and the owner and
+/// admins) can pause; only the owner or an admin can unpause. Each contract
+/// decides which endpoints call `require_not_paused` — views, configuration
+/// and paths that only return funds stay available.
+#[multiversx_sc::module]
+pub trait PauseModule: crate::roles::RolesModule {
+    #[endpoint(pause)]
+    fn pause(&self) {
+        let caller = self.require_role(Role::Pauser);
+        if !self.paused().get() {
+            self.paused().set(true);
+            self.paused_event(&caller);
+        }
+    }
+
+    #[endpoint(unpause)]
+    fn unpause(&self) {
+        let caller = self.require_role(Role::Admin);
+        if self.paused().get() {
+            self.paused().clear();
+            self.unpaused_event(&caller);
+        }
+    }
+
+    fn require_not_paused(&self) {
+        require!(!self.paused().get(), ERR_CONTRACT_PAUSED);
+    }
+
+    #[view(is_paused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+
+    #[event("paused")]
+    fn paused_event(&self, #[indexed] by: &ManagedAddress);
+
+    #[event("unpaused")]
+    fn unpaused_event(&self, #[indexed] by: &ManagedAddress);
+}
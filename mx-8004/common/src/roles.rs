@@ -0,0 +1,177 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::errors::{
+    ERR_MISSING_ROLE, ERR_NOT_OWNER, ERR_NOT_PENDING_OWNER, ERR_NOT_ROLE_ADMIN,
+    ERR_ONLY_OWNER_MANAGES_ADMINS, ERR_PROTOCOL_OWNER_NOT_HANDED_OVER,
+};
+
+/// Access roles shared by all MX-8004 contracts.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, Copy, PartialEq, Debug)]
+pub enum Role {
+    /// Manages the other roles and holds every permission below.
+    Admin,
+    /// Changes contract addresses, fees and other settings.
+    ConfigManager,
+    /// Resolves disputes over feedback.
+    Moderator,
+    /// Stops the contract in an emergency.
+    Pauser,
+}
+
+/// Role-based access control. The owner holds every role, grants and revokes
+/// `Admin`, and hands ownership over in two steps (transfer, then accept).
+/// Admins hold every other role and manage them. Until a transfer is accepted
+/// the owner is the deployer.
+///
+/// Upgrade rights belong to the protocol owner, which only the current
+/// protocol owner can move (`ChangeOwnerAddress`). So the handover is: the
+/// owner calls `transfer_ownership`, then sends `ChangeOwnerAddress` to the
+/// new owner, and the new owner calls `accept_ownership`. Acceptance fails
+/// until the protocol owner is the caller, so both owners always move together.
+#[multiversx_sc::module]
+pub trait RolesModule {
+    #[endpoint(grant_role)]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        let caller = self.require_role_manager(role);
+        if self.role_members(role).insert(address.clone()) {
+            self.role_granted_event(role, &address, &caller);
+        }
+    }
+
+    #[endpoint(revoke_role)]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        let caller = self.require_role_manager(role);
+        if self.role_members(role).swap_remove(&address) {
+            self.role_revoked_event(role, &address, &caller);
+        }
+    }
+
+    /// First step of an ownership transfer. Calling it again replaces the
+    /// pending owner.
+    #[endpoint(transfer_ownership)]
+    fn transfer_ownership(&self, new_owner: ManagedAddress) {
+        let owner = self.require_owner();
+        self.pending_owner().set(&new_owner);
+        self.ownership_transfer_started_event(&owner, &new_owner);
+    }
+
+    /// Second step: the pending owner takes over. The protocol owner must
+    /// already have been changed to the caller.
+    #[endpoint(accept_ownership)]
+    fn accept_ownership(&self) {
+        let caller = self.blockchain().get_caller();
+        let pending_mapper = self.pending_owner();
+        require!(
+            !pending_mapper.is_empty() && pending_mapper.get() == caller,
+            ERR_NOT_PENDING_OWNER
+        );
+        require!(
+            self.blockchain().get_owner_address() == caller,
+            ERR_PROTOCOL_OWNER_NOT_HANDED_OVER
+        );
+
+        let previous_owner = self.current_owner();
+        self.owner().set(&caller);
+        pending_mapper.clear();
+        self.ownership_transferred_event(&previous_owner, &caller);
+    }
+
+    #[view(get_owner)]
+    fn current_owner(&self) -> ManagedAddress {
+        let owner_mapper = self.owner();
+        if owner_mapper.is_empty() {
+            self.blockchain().get_owner_address()
+        } else {
+            owner_mapper.get()
+        }
+    }
+
+    #[view(get_pending_owner)]
+    fn get_pending_owner(&self) -> OptionalValue<ManagedAddress> {
+        let pending_mapper = self.pending_owner();
+        if pending_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(pending_mapper.get())
+        }
+    }
+
+    /// Whether `address` may act as `role`: the owner and admins always can.
+    #[view(has_role)]
+    fn has_role(&self, role: Role, address: ManagedAddress) -> bool {
+        address == self.current_owner()
+            || self.role_members(Role::Admin).contains(&address)
+            || self.role_members(role).contains(&address)
+    }
+
+    fn require_owner(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(caller == self.current_owner(), ERR_NOT_OWNER);
+        caller
+    }
+
+    fn require_role(&self, role: Role) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(self.has_role(role, caller.clone()), ERR_MISSING_ROLE);
+        caller
+    }
+
+    fn require_role_manager(&self, role: Role) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        if role == Role::Admin {
+            require!(
+                caller == self.current_owner(),
+                ERR_ONLY_OWNER_MANAGES_ADMINS
+            );
+        } else {
+            require!(
+                self.has_role(Role::Admin, caller.clone()),
+                ERR_NOT_ROLE_ADMIN
+            );
+        }
+        caller
+    }
+
+    /// Unset until the first accepted transfer; see `current_owner`.
+    #[storage_mapper("owner")]
+    fn owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("pendingOwner")]
+    fn pending_owner(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(get_role_members)]
+    #[storage_mapper("roleMembers")]
+    fn role_members(&self, role: Role) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[event("role_granted")]
+    fn role_granted_event(
+        &self,
+        #[indexed] role: Role,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] granted_by: &ManagedAddress,
+    );
+
+    #[event("role_revoked")]
+    fn role_revoked_event(
+        &self,
+        #[indexed] role: Role,
+        #[indexed] address: &ManagedAddress,
+        #[indexed] revoked_by: &ManagedAddress,
+    );
+
+    #[event("ownership_transfer_started")]
+    fn ownership_transfer_started_event(
+        &self,
+        #[indexed] owner: &ManagedAddress,
+        #[indexed] pending_owner: &ManagedAddress,
+    );
+
+    #[event("ownership_transferred")]
+    fn ownership_transferred_event(
+        &self,
+        #[indexed] previous_owner: &ManagedAddress,
+        #[indexed] new_owner: &ManagedAddress,
+    );
+}
@@ -1,6 +1,8 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+use multiversx_sc::codec::NestedDecodeInput;
+
 // ── Job types (used by validation-registry and reputation-registry) ──
 
 #[type_abi]
@@ -12,14 +14,162 @@ pub enum JobStatus {
     ValidationRequested,
 }
 
+/// Jobs stored before `service_id` was added end after `agent_nonce`; they
+/// decode with `service_id: None`, so readers handle them before
+/// `migrate_jobs` rewrites them.
 #[type_abi]
-#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+#[derive(TopEncode, NestedEncode, NestedDecode, PartialEq, Debug)]
 pub struct JobData<M: ManagedTypeApi> {
     pub status: JobStatus,
     pub proof: ManagedBuffer<M>,
     pub employer: ManagedAddress<M>,
     pub creation_timestamp: TimestampMillis,
     pub agent_nonce: u64,
+    pub service_id: Option<u32>,
+}
+
+impl<M: ManagedTypeApi> TopDecode for JobData<M> {
+    fn top_decode_or_handle_err<I, H>(input: I, h: H) -> Result<Self, H::HandledErr>
+    where
+        I: codec::TopDecodeInput,
+        H: codec::DecodeErrorHandler,
+    {
+        let mut nested = input.into_nested_buffer();
+        let job = JobData {
+            status: JobStatus::dep_decode_or_handle_err(&mut nested, h)?,
+            proof: ManagedBuffer::dep_decode_or_handle_err(&mut nested, h)?,
+            employer: ManagedAddress::dep_decode_or_handle_err(&mut nested, h)?,
+            creation_timestamp: TimestampMillis::dep_decode_or_handle_err(&mut nested, h)?,
+            agent_nonce: u64::dep_decode_or_handle_err(&mut nested, h)?,
+            service_id: if nested.is_depleted() {
+                None
+            } else {
+                Option::<u32>::dep_decode_or_handle_err(&mut nested, h)?
+            },
+        };
+        if !nested.is_depleted() {
+            return Err(h.handle_error(codec::DecodeError::INPUT_TOO_LONG));
+        }
+        Ok(job)
+    }
+}
+
+/// A price an agent offers one employer for one job, in place of the agent's
+/// service config. `init_job` for that job id with `accept_quote` pays it and
+/// consumes the quote.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct JobQuote<M: ManagedTypeApi> {
+    pub agent_nonce: u64,
+    pub employer: ManagedAddress<M>,
+    pub price: Payment<M>,
+    pub expires_at: TimestampSeconds,
+}
+
+/// The parts of a job escrow checks. Escrow caches it via `sync_job` when
+/// validation-registry is deployed on another shard.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct JobSnapshot<M: ManagedTypeApi> {
+    pub employer: ManagedAddress<M>,
+    pub agent_nonce: u64,
+    pub status: JobStatus,
+    pub verified_at: TimestampSeconds,
+}
+
+/// An agent's owner as read from an identity-registry on another shard.
+/// `owner` is `None` if the agent did not exist; `synced_at` is the source
+/// shard's block time of the read.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct SyncedAgent<M: ManagedTypeApi> {
+    pub owner: Option<ManagedAddress<M>>,
+    pub synced_at: TimestampSeconds,
+}
+
+/// A job as read from a validation-registry on another shard. `job` is
+/// `None` if it did not exist; `synced_at` is the source shard's block time
+/// of the read.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct SyncedJob<M: ManagedTypeApi> {
+    pub job: Option<JobSnapshot<M>>,
+    pub synced_at: TimestampSeconds,
+}
+
+// ── Escrow types (used by escrow and validation-registry) ──
+
+/// Escrow settlement status.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum EscrowStatus {
+    Active,
+    Released,
+    Refunded,
+    PartiallyReleased,
+    Disputed,
+    Resolved,
+}
+
+/// On-chain escrow record.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct EscrowData<M: ManagedTypeApi> {
+    pub employer: ManagedAddress<M>,
+    pub receiver: ManagedAddress<M>,
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub amount: BigUint<M>,
+    pub poa_hash: ManagedBuffer<M>,
+    pub deadline: TimestampSeconds,
+    pub status: EscrowStatus,
+    pub released_amount: BigUint<M>,
+    pub refunded_amount: BigUint<M>,
+    pub arbiter: Option<ManagedAddress<M>>,
+    /// Set for agent-bound escrows: payouts go to the agent's owner at
+    /// settlement time and `receiver` records the owner at deposit, who is
+    /// paid instead if the agent is deregistered.
+    pub agent_nonce: Option<u64>,
+    /// Further tokens of a multi-token deposit. The first payment lives in
+    /// `token_id`/`token_nonce`/`amount`; a bundle always settles as a whole.
+    pub extra_payments: ManagedVec<M, EgldOrEsdtTokenPayment<M>>,
+}
+
+impl<M: ManagedTypeApi> EscrowData<M> {
+    pub fn is_bundle(&self) -> bool {
+        !self.extra_payments.is_empty()
+    }
+
+    /// Every token deposited, first payment included.
+    pub fn payments(&self) -> ManagedVec<M, EgldOrEsdtTokenPayment<M>> {
+        let mut payments = ManagedVec::from_single_item(EgldOrEsdtTokenPayment::new(
+            self.token_id.clone(),
+            self.token_nonce,
+            self.amount.clone(),
+        ));
+        payments.append_vec(self.extra_payments.clone());
+        payments
+    }
+
+    /// Amount still held by the contract for this job.
+    pub fn locked_amount(&self) -> BigUint<M> {
+        &self.amount - &self.released_amount - &self.refunded_amount
+    }
+
+    /// Derives the status from what has been paid out so far.
+    pub fn update_status(&mut self) {
+        self.status = if self.released_amount == 0 {
+            if self.locked_amount() == 0 {
+                EscrowStatus::Refunded
+            } else {
+                EscrowStatus::Active
+            }
+        } else if self.released_amount == self.amount {
+            EscrowStatus::Released
+        } else {
+            EscrowStatus::PartiallyReleased
+        };
+    }
 }
 
 // ── Validation types (ERC-8004 validationRequest/Response) ──
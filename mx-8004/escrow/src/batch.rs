@@ -0,0 +1,77 @@
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::storage::BatchOutcome;
+
+pub const MAX_BATCH_SIZE: usize = 50;
+/// Gas kept in reserve per job: enough for the checks, a fee transfer and the
+/// payout. Below this the remaining jobs are reported as `NotProcessed`.
+pub const MIN_GAS_PER_SETTLEMENT: u64 = 15_000_000;
+
+/// Batch settlement for keepers. Each job goes through exactly the checks of
+/// `release` / `refund`; ineligible jobs are skipped instead of failing the
+/// whole transaction.
+#[multiversx_sc::module]
+pub trait BatchModule:
+    common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::utils::UtilsModule
+    + common::roles::RolesModule
+    + common::pause::PauseModule
+{
+    /// Releases every eligible escrow in `job_ids`, as `release` would for the
+    /// caller. Returns `(job_id, outcome, reason)` per job, in input order.
+    #[endpoint(release_batch)]
+    fn release_batch(
+        &self,
+        job_ids: MultiValueEncoded<ManagedBuffer>,
+    ) -> MultiValueEncoded<MultiValue3<ManagedBuffer, BatchOutcome, ManagedBuffer>> {
+        self.require_not_paused();
+        self.settle_batch(job_ids, |job_id| {
+            let escrow = self.releasable_escrow(job_id)?;
+            self.execute_release(job_id, escrow);
+            Ok(())
+        })
+    }
+
+    /// Refunds every escrow in `job_ids` whose deadline has passed, as `refund`
+    /// would. Returns `(job_id, outcome, reason)` per job, in input order.
+    #[endpoint(refund_batch)]
+    fn refund_batch(
+        &self,
+        job_ids: MultiValueEncoded<ManagedBuffer>,
+    ) -> MultiValueEncoded<MultiValue3<ManagedBuffer, BatchOutcome, ManagedBuffer>> {
+        self.settle_batch(job_ids, |job_id| {
+            let escrow = self.refundable_escrow(job_id)?;
+            self.execute_refund(job_id, escrow);
+            Ok(())
+        })
+    }
+
+    fn settle_batch<F: Fn(&ManagedBuffer) -> Result<(), &'static str>>(
+        &self,
+        job_ids: MultiValueEncoded<ManagedBuffer>,
+        settle: F,
+    ) -> MultiValueEncoded<MultiValue3<ManagedBuffer, BatchOutcome, ManagedBuffer>> {
+        require!(!job_ids.is_empty(), ERR_EMPTY_BATCH);
+        require!(job_ids.len() <= MAX_BATCH_SIZE, ERR_BATCH_TOO_LARGE);
+
+        let mut outcomes = MultiValueEncoded::new();
+        let mut out_of_gas = false;
+        for job_id in job_ids.into_iter() {
+            out_of_gas = out_of_gas || self.blockchain().get_gas_left() < MIN_GAS_PER_SETTLEMENT;
+            let (outcome, reason) = if out_of_gas {
+                (BatchOutcome::NotProcessed, ManagedBuffer::new())
+            } else {
+                match settle(&job_id) {
+                    Ok(()) => (BatchOutcome::Settled, ManagedBuffer::new()),
+                    Err(err) => (BatchOutcome::Skipped, ManagedBuffer::from(err)),
+                }
+            };
+            outcomes.push((job_id, outcome, reason).into());
+        }
+        outcomes
+    }
+}
@@ -0,0 +1,55 @@
+multiversx_sc::imports!();
+
+use crate::errors::{ERR_FEE_TOO_HIGH, ERR_TREASURY_NOT_SET};
+use common::roles::Role;
+
+pub const BPS_DENOMINATOR: u32 = 10_000;
+pub const MAX_FEE_BPS: u32 = 1_000;
+
+pub const DEFAULT_CHALLENGE_WINDOW: DurationSeconds = DurationSeconds::new(24 * 60 * 60);
+
+#[multiversx_sc::module]
+pub trait ConfigModule: crate::storage::StorageModule + common::roles::RolesModule {
+    /// How long after verification only the employer may release. Once it
+    /// elapses, anyone can trigger the release (e.g. the receiver or a keeper).
+    #[endpoint(set_challenge_window)]
+    fn set_challenge_window(&self, window: DurationSeconds) {
+        self.require_role(Role::ConfigManager);
+        self.challenge_window().set(window);
+    }
+
+    /// Address that receives protocol fees.
+    #[endpoint(set_treasury)]
+    fn set_treasury(&self, treasury: ManagedAddress) {
+        self.require_role(Role::ConfigManager);
+        self.treasury().set(&treasury);
+    }
+
+    /// Fee taken from releases in `token_id`, in basis points (at most
+    /// `MAX_FEE_BPS`). Applies to releases from now on, including escrows
+    /// already funded. Zero disables the fee.
+    #[endpoint(set_fee_bps)]
+    fn set_fee_bps(&self, token_id: EgldOrEsdtTokenIdentifier, fee_bps: u32) {
+        self.require_role(Role::ConfigManager);
+        require!(fee_bps <= MAX_FEE_BPS, ERR_FEE_TOO_HIGH);
+        if fee_bps == 0 {
+            self.fee_bps(&token_id).clear();
+            return;
+        }
+        require!(!self.treasury().is_empty(), ERR_TREASURY_NOT_SET);
+        self.fee_bps(&token_id).set(fee_bps);
+    }
+
+    /// Accumulated fees for every token that has collected any.
+    #[view(get_all_collected_fees)]
+    fn get_all_collected_fees(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for token_id in self.fee_tokens().iter() {
+            let collected = self.collected_fees(&token_id).get();
+            result.push((token_id, collected).into());
+        }
+        result
+    }
+}
@@ -0,0 +1,139 @@
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::events::EscrowResolvedEventData;
+use crate::storage::{EscrowDispute, EscrowStatus, MilestoneStatus};
+use common::roles::Role;
+
+/// Escrow disputes: either party freezes the escrow before its deadline and an
+/// arbiter splits whatever is still locked between receiver and employer.
+#[multiversx_sc::module]
+pub trait DisputesModule:
+    common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::utils::UtilsModule
+    + common::roles::RolesModule
+    + common::pause::PauseModule
+{
+    #[endpoint(add_arbiter)]
+    fn add_arbiter(&self, arbiter: ManagedAddress) {
+        self.require_role(Role::Admin);
+        if self.arbiters().insert(arbiter.clone()) {
+            self.arbiter_added_event(&arbiter);
+        }
+    }
+
+    #[endpoint(remove_arbiter)]
+    fn remove_arbiter(&self, arbiter: ManagedAddress) {
+        self.require_role(Role::Admin);
+        if self.arbiters().swap_remove(&arbiter) {
+            self.arbiter_removed_event(&arbiter);
+        }
+    }
+
+    /// Employer or receiver freezes the escrow until an arbiter decides.
+    /// Must be opened before the deadline, while funds are still locked, and
+    /// before a verified job's challenge window has elapsed.
+    #[endpoint(open_dispute)]
+    fn open_dispute(&self, job_id: ManagedBuffer, reason_uri: ManagedBuffer) {
+        self.require_not_paused();
+        let escrow_mapper = self.escrow_data(&job_id);
+        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
+        let mut escrow = escrow_mapper.get();
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == escrow.employer || caller == self.current_receiver(&escrow),
+            ERR_NOT_PARTY
+        );
+        require!(escrow.locked_amount() > 0u64, ERR_ALREADY_SETTLED);
+        self.require_not_disputed(&escrow);
+
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds();
+        require!(current_timestamp <= escrow.deadline, ERR_DEADLINE_PASSED);
+        self.require_disputable(&job_id);
+
+        escrow.status = EscrowStatus::Disputed;
+        escrow_mapper.set(&escrow);
+        self.escrow_dispute(&job_id).set(EscrowDispute {
+            opened_by: caller.clone(),
+            reason_uri: reason_uri.clone(),
+            opened_at: current_timestamp,
+        });
+
+        self.escrow_disputed_event(&job_id, &caller, reason_uri);
+    }
+
+    /// Arbiter pays `receiver_amount` of the locked funds to the receiver, minus
+    /// the protocol fee, and the rest back to the employer. Multi-token escrows
+    /// go entirely to one side: `receiver_amount` is either 0 or the full
+    /// first-payment amount. The escrow's own arbiter decides if one was named
+    /// at deposit and is still listed; otherwise any listed arbiter can.
+    #[endpoint(resolve_dispute)]
+    fn resolve_dispute(&self, job_id: ManagedBuffer, receiver_amount: BigUint) {
+        self.require_not_paused();
+        let escrow_mapper = self.escrow_data(&job_id);
+        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
+        let mut escrow = escrow_mapper.get();
+        require!(escrow.status == EscrowStatus::Disputed, ERR_NOT_DISPUTED);
+
+        let caller = self.blockchain().get_caller();
+        let is_arbiter = match &escrow.arbiter {
+            Some(arbiter) if self.arbiters().contains(arbiter) => arbiter == &caller,
+            _ => self.arbiters().contains(&caller),
+        };
+        require!(is_arbiter, ERR_NOT_ARBITER);
+
+        let locked = escrow.locked_amount();
+        require!(receiver_amount <= locked, ERR_SPLIT_EXCEEDS_LOCKED);
+        require!(
+            !escrow.is_bundle() || receiver_amount == 0u64 || receiver_amount == locked,
+            ERR_BUNDLE_SPLIT
+        );
+        let employer_amount = locked - &receiver_amount;
+
+        // Effects
+        let mut milestone_mapper = self.escrow_milestones(&job_id);
+        for index in 1..=milestone_mapper.len() {
+            let mut milestone = milestone_mapper.get(index);
+            if milestone.status == MilestoneStatus::Pending {
+                milestone.status = MilestoneStatus::Resolved;
+                milestone_mapper.set(index, &milestone);
+            }
+        }
+        escrow.released_amount += &receiver_amount;
+        escrow.refunded_amount += &employer_amount;
+        escrow.status = EscrowStatus::Resolved;
+        escrow_mapper.set(&escrow);
+
+        // Interactions: the receiver's share pays the protocol fee like a
+        // release. A bundle goes whole to one side in a single transfer.
+        if escrow.is_bundle() {
+            if receiver_amount > 0u64 {
+                let receiver = self.current_receiver(&escrow);
+                self.pay_receiver_bundle(&job_id, &escrow, &receiver);
+            } else {
+                self.send_bundle(&escrow.employer, &escrow.payments());
+            }
+        } else {
+            if receiver_amount > 0u64 {
+                let receiver = self.current_receiver(&escrow);
+                self.pay_receiver(&job_id, &escrow, &receiver, &receiver_amount);
+            }
+            if employer_amount > 0u64 {
+                self.send_escrowed(&escrow, &escrow.employer, &employer_amount);
+            }
+        }
+
+        self.escrow_resolved_event(
+            &job_id,
+            &caller,
+            EscrowResolvedEventData {
+                receiver_amount,
+                employer_amount,
+            },
+        );
+    }
+}
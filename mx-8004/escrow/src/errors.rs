@@ -1,8 +1,15 @@
-pub const ERR_ESCROW_ALREADY_EXISTS: &str = "Escrow already exists for this job";
-pub const ERR_ESCROW_NOT_FOUND: &str = "Escrow not found for this job";
-pub const ERR_NOT_EMPLOYER: &str = "Only the employer can call this";
-pub const ERR_JOB_NOT_VERIFIED: &str = "Job must be verified before release";
-pub const ERR_DEADLINE_NOT_PASSED: &str = "Deadline has not passed yet";
-pub const ERR_DEADLINE_IN_PAST: &str = "Deadline must be in the future";
-pub const ERR_ALREADY_SETTLED: &str = "Escrow already settled";
-pub const ERR_ZERO_DEPOSIT: &str = "Deposit amount must be greater than zero";
+pub use common::errors::{
+    ERR_AGENT_NOT_FOUND, ERR_ALREADY_SETTLED, ERR_ARBITER_NOT_LISTED, ERR_BATCH_TOO_LARGE,
+    ERR_BUNDLE_SPLIT, ERR_CHALLENGE_WINDOW_OPEN, ERR_DEADLINE_IN_PAST, ERR_DEADLINE_NOT_EXTENDED,
+    ERR_DEADLINE_NOT_PASSED, ERR_DEADLINE_PASSED, ERR_DISPUTE_WINDOW_CLOSED, ERR_EMPTY_BATCH,
+    ERR_ESCROW_ALREADY_EXISTS, ERR_ESCROW_DISPUTED, ERR_ESCROW_NOT_FOUND, ERR_FEE_TOO_HIGH,
+    ERR_HAS_MILESTONES, ERR_INVALID_ARBITER, ERR_INVALID_STREAM_PERIOD, ERR_IS_STREAM,
+    ERR_JOB_AGENT_MISMATCH, ERR_JOB_EMPLOYER_MISMATCH, ERR_JOB_NOT_IN_VALIDATION_REGISTRY,
+    ERR_JOB_NOT_SYNCED, ERR_JOB_NOT_VERIFIED, ERR_JOB_SNAPSHOT_STALE, ERR_JOB_VERIFIED,
+    ERR_MILESTONE_AMOUNT_MISMATCH, ERR_MILESTONE_NOT_FOUND, ERR_MILESTONE_SETTLED,
+    ERR_NO_MILESTONES, ERR_NOT_ARBITER, ERR_NOT_DISPUTED, ERR_NOT_EMPLOYER, ERR_NOT_PARTY,
+    ERR_NOT_PARTY_TO_CANCEL, ERR_NOT_RECEIVER, ERR_NOT_STREAM, ERR_NOT_VALIDATION_REGISTRY,
+    ERR_NOTHING_VESTED, ERR_SAME_SHARD, ERR_SPLIT_EXCEEDS_LOCKED, ERR_TOO_MANY_MILESTONES,
+    ERR_TOO_MANY_PAYMENTS, ERR_TOP_UP_TOKEN_MISMATCH, ERR_TREASURY_NOT_SET, ERR_ZERO_DEPOSIT,
+    ERR_ZERO_MILESTONE,
+};
@@ -1,4 +1,23 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::storage::SyncedJob;
+
+/// Dispute outcome — packed as a single data argument.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct EscrowResolvedEventData<M: ManagedTypeApi> {
+    pub receiver_amount: BigUint<M>,
+    pub employer_amount: BigUint<M>,
+}
+
+/// Final split of a stopped stream — packed as a single data argument.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct StreamStoppedEventData<M: ManagedTypeApi> {
+    pub receiver_amount: BigUint<M>,
+    pub employer_amount: BigUint<M>,
+}
 
 #[multiversx_sc::module]
 pub trait EventsModule {
@@ -25,4 +44,127 @@ pub trait EventsModule {
         #[indexed] employer: &ManagedAddress,
         amount: BigUint,
     );
+
+    #[event("milestone_released")]
+    fn milestone_released_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] milestone_index: usize,
+        #[indexed] receiver: &ManagedAddress,
+        amount: BigUint,
+    );
+
+    #[event("milestone_refunded")]
+    fn milestone_refunded_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] milestone_index: usize,
+        #[indexed] employer: &ManagedAddress,
+        amount: BigUint,
+    );
+
+    #[event("escrow_disputed")]
+    fn escrow_disputed_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] opened_by: &ManagedAddress,
+        reason_uri: ManagedBuffer,
+    );
+
+    #[event("escrow_resolved")]
+    fn escrow_resolved_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] arbiter: &ManagedAddress,
+        data: EscrowResolvedEventData<Self::Api>,
+    );
+
+    #[event("bundle_deposited")]
+    fn bundle_deposited_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+        payments: ManagedVec<EgldOrEsdtTokenPayment>,
+    );
+
+    #[event("bundle_released")]
+    fn bundle_released_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] receiver: &ManagedAddress,
+        payments: ManagedVec<EgldOrEsdtTokenPayment>,
+    );
+
+    #[event("bundle_refunded")]
+    fn bundle_refunded_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+        payments: ManagedVec<EgldOrEsdtTokenPayment>,
+    );
+
+    #[event("escrow_topped_up")]
+    fn escrow_topped_up_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+        payment: EgldOrEsdtTokenPayment,
+    );
+
+    #[event("deadline_extended")]
+    fn deadline_extended_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+        new_deadline: TimestampSeconds,
+    );
+
+    #[event("cancel_requested")]
+    fn cancel_requested_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] requested_by: &ManagedAddress,
+    );
+
+    #[event("escrow_cancelled")]
+    fn escrow_cancelled_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+        amount: BigUint,
+    );
+
+    #[event("stream_withdrawn")]
+    fn stream_withdrawn_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] receiver: &ManagedAddress,
+        amount: BigUint,
+    );
+
+    #[event("stream_stopped")]
+    fn stream_stopped_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] employer: &ManagedAddress,
+        data: StreamStoppedEventData<Self::Api>,
+    );
+
+    #[event("fee_collected")]
+    fn fee_collected_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] treasury: &ManagedAddress,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    );
+
+    #[event("arbiter_added")]
+    fn arbiter_added_event(&self, #[indexed] arbiter: &ManagedAddress);
+
+    #[event("arbiter_removed")]
+    fn arbiter_removed_event(&self, #[indexed] arbiter: &ManagedAddress);
+
+    #[event("job_synced")]
+    fn job_synced_event(&self, #[indexed] job_id: &ManagedBuffer, synced: &SyncedJob<Self::Api>);
 }
@@ -3,12 +3,24 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
+pub mod batch;
+pub mod config;
+pub mod disputes;
 pub mod errors;
 pub mod events;
+pub mod lifecycle;
+pub mod migration;
+pub mod milestones;
 pub mod storage;
+pub mod streams;
+pub mod sync;
+pub mod utils;
+pub mod views;
 
-use errors::*;
-use storage::{EscrowData, EscrowStatus};
+use errors::ERR_NOT_VALIDATION_REGISTRY;
+
+/// Layout version written by this code; see `common::migration`.
+pub const STORAGE_VERSION: u32 = 1;
 
 /// ACP Escrow Contract — locks funds for agent jobs, releases on proof verification,
 /// refunds if deadline passes without verified proof.
@@ -16,7 +28,24 @@ use storage::{EscrowData, EscrowStatus};
 /// Follows Checks-Effects-Interactions pattern throughout.
 #[multiversx_sc::contract]
 pub trait EscrowContract:
-    common::cross_contract::CrossContractModule + storage::StorageModule + events::EventsModule
+    common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
+    + storage::StorageModule
+    + events::EventsModule
+    + utils::UtilsModule
+    + milestones::MilestonesModule
+    + disputes::DisputesModule
+    + config::ConfigModule
+    + lifecycle::LifecycleModule
+    + views::ViewsModule
+    + batch::BatchModule
+    + streams::StreamsModule
+    + migration::MigrationModule
+    + sync::SyncModule
+    + common::roles::RolesModule
+    + common::pause::PauseModule
+    + common::migration::StorageVersionModule
+    + common::errors::ErrorCatalogModule
 {
     #[init]
     fn init(
@@ -28,14 +57,27 @@ pub trait EscrowContract:
             .set(&validation_contract_address);
         self.identity_contract_address()
             .set(&identity_contract_address);
+        self.challenge_window()
+            .set(config::DEFAULT_CHALLENGE_WINDOW);
+        self.storage_version().set(STORAGE_VERSION);
     }
 
+    /// Brings storage up to `STORAGE_VERSION`. The escrows of `job_ids` are
+    /// migrated right away; larger sets go through `migrate_escrows` in batches.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self, job_ids: MultiValueEncoded<ManagedBuffer>) {
+        self.challenge_window()
+            .set_if_empty(config::DEFAULT_CHALLENGE_WINDOW);
+        self.migrate_escrow_records(job_ids);
+        self.set_storage_version(STORAGE_VERSION);
+    }
 
     /// Deposit funds into escrow for a specific job.
-    /// Accepts EGLD or any ESDT token.
+    /// Accepts EGLD, any ESDT token, or a multi-token bundle (up to
+    /// `MAX_PAYMENTS`) that is released or refunded as a whole.
     /// `deadline` is a Unix timestamp (seconds) after which a refund is allowed.
+    /// `arbiter` optionally names who settles disputes; otherwise any arbiter
+    /// from the owner-managed list can.
     #[payable("*")]
     #[endpoint(deposit)]
     fn deposit(
@@ -44,103 +86,110 @@ pub trait EscrowContract:
         receiver: ManagedAddress,
         poa_hash: ManagedBuffer,
         deadline: TimestampSeconds,
+        arbiter: OptionalValue<ManagedAddress>,
     ) {
-        let payment = self.call_value().egld_or_single_esdt();
-        require!(payment.amount > 0u64, ERR_ZERO_DEPOSIT);
-
-        let current_timestamp = self.blockchain().get_block_timestamp_seconds();
-        require!(deadline > current_timestamp, ERR_DEADLINE_IN_PAST);
-
-        let escrow_mapper = self.escrow_data(&job_id);
-        require!(escrow_mapper.is_empty(), ERR_ESCROW_ALREADY_EXISTS);
+        self.require_not_paused();
+        let payments = self.received_payments();
+        self.create_escrow(
+            &job_id,
+            self.blockchain().get_caller(),
+            receiver,
+            poa_hash,
+            deadline,
+            &payments,
+            arbiter.into_option(),
+            None,
+        );
+    }
 
+    /// Deposit funds for a job with a registered agent instead of a raw address.
+    /// The job must exist in the ValidationRegistry for this agent with the
+    /// caller as employer. Payouts follow the agent's owner at settlement time.
+    #[payable("*")]
+    #[endpoint(deposit_for_agent)]
+    fn deposit_for_agent(
+        &self,
+        job_id: ManagedBuffer,
+        agent_nonce: u64,
+        poa_hash: ManagedBuffer,
+        deadline: TimestampSeconds,
+        arbiter: OptionalValue<ManagedAddress>,
+    ) {
+        self.require_not_paused();
         let caller = self.blockchain().get_caller();
+        self.require_job_matches(&job_id, agent_nonce, &caller);
+        let receiver = self.agent_owner(agent_nonce);
 
-        let escrow = EscrowData {
-            employer: caller.clone(),
+        let payments = self.received_payments();
+        self.create_escrow(
+            &job_id,
+            caller,
             receiver,
-            token_id: payment.token_identifier.clone(),
-            token_nonce: payment.token_nonce,
-            amount: payment.amount.clone(),
             poa_hash,
             deadline,
-            status: EscrowStatus::Active,
-        };
-
-        // Effects: store escrow
-        escrow_mapper.set(&escrow);
-
-        self.escrow_deposited_event(&job_id, &caller, payment.amount);
+            &payments,
+            arbiter.into_option(),
+            Some(agent_nonce),
+        );
     }
 
-    /// Release escrowed funds to the receiver.
-    /// Only callable by the employer. Job must be verified in the ValidationRegistry.
-    #[endpoint(release)]
-    fn release(&self, job_id: ManagedBuffer) {
-        let escrow_mapper = self.escrow_data(&job_id);
-        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
-
-        let mut escrow = escrow_mapper.get();
-        require!(escrow.status == EscrowStatus::Active, ERR_ALREADY_SETTLED);
-
+    /// Deposit made by the ValidationRegistry from `init_job`, for services
+    /// that lock their payment in escrow. `employer` is the account that
+    /// created the job; everything else works as in `deposit_for_agent`.
+    #[payable("*")]
+    #[endpoint(deposit_for_job)]
+    fn deposit_for_job(
+        &self,
+        job_id: ManagedBuffer,
+        agent_nonce: u64,
+        employer: ManagedAddress,
+        deadline: TimestampSeconds,
+    ) {
+        self.require_not_paused();
         let caller = self.blockchain().get_caller();
-        require!(caller == escrow.employer, ERR_NOT_EMPLOYER);
-
-        // Cross-contract check: read job status from validation-registry
-        let validation_addr = self.validation_contract_address().get();
-        let job_mapper = self.external_job_data(validation_addr, &job_id);
-        require!(!job_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
-
-        let job_data = job_mapper.get();
         require!(
-            job_data.status == common::structs::JobStatus::Verified,
-            ERR_JOB_NOT_VERIFIED
+            caller == self.validation_contract_address().get(),
+            ERR_NOT_VALIDATION_REGISTRY
         );
+        self.require_job_matches(&job_id, agent_nonce, &employer);
+        let receiver = self.agent_owner(agent_nonce);
 
-        // Effects: mark as released BEFORE interactions
-        escrow.status = EscrowStatus::Released;
-        let receiver = escrow.receiver.clone();
-        let amount = escrow.amount.clone();
-        let token_id = escrow.token_id.clone();
-        let token_nonce = escrow.token_nonce;
-        escrow_mapper.set(&escrow);
-
-        // Interactions: transfer funds to receiver
-        self.tx()
-            .to(&receiver)
-            .egld_or_single_esdt(&token_id, token_nonce, &amount)
-            .transfer();
+        let payments = self.received_payments();
+        self.create_escrow(
+            &job_id,
+            employer,
+            receiver,
+            ManagedBuffer::new(),
+            deadline,
+            &payments,
+            None,
+            Some(agent_nonce),
+        );
+    }
 
-        self.escrow_released_event(&job_id, &receiver, amount);
+    /// Release escrowed funds to the receiver once the job is verified in the
+    /// ValidationRegistry (and, for agent-bound escrows, still belongs to the
+    /// same agent and employer). The employer can release immediately; after the
+    /// challenge window anyone can (receiver, keeper bots).
+    #[endpoint(release)]
+    fn release(&self, job_id: ManagedBuffer) {
+        self.require_not_paused();
+        match self.releasable_escrow(&job_id) {
+            Ok(escrow) => self.execute_release(&job_id, escrow),
+            Err(err) => sc_panic!(err),
+        }
     }
 
     /// Refund escrowed funds to the employer if the deadline has passed.
+    /// For milestone escrows this returns whatever is still locked.
+    /// Verified work is never refunded: the job (or any pending milestone's
+    /// job) must not be verified.
     /// Anyone can call this (allows automated cleanup).
     #[endpoint(refund)]
     fn refund(&self, job_id: ManagedBuffer) {
-        let escrow_mapper = self.escrow_data(&job_id);
-        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
-
-        let mut escrow = escrow_mapper.get();
-        require!(escrow.status == EscrowStatus::Active, ERR_ALREADY_SETTLED);
-
-        let current_timestamp = self.blockchain().get_block_timestamp_seconds();
-        require!(current_timestamp > escrow.deadline, ERR_DEADLINE_NOT_PASSED);
-
-        // Effects: mark as refunded BEFORE interactions
-        escrow.status = EscrowStatus::Refunded;
-        let employer = escrow.employer.clone();
-        let amount = escrow.amount.clone();
-        let token_id = escrow.token_id.clone();
-        let token_nonce = escrow.token_nonce;
-        escrow_mapper.set(&escrow);
-
-        // Interactions: transfer funds back to employer
-        self.tx()
-            .to(&employer)
-            .egld_or_single_esdt(&token_id, token_nonce, &amount)
-            .transfer();
-
-        self.escrow_refunded_event(&job_id, &employer, amount);
+        match self.refundable_escrow(&job_id) {
+            Ok(escrow) => self.execute_refund(&job_id, escrow),
+            Err(err) => sc_panic!(err),
+        }
     }
 }
@@ -0,0 +1,131 @@
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::storage::EscrowStatus;
+
+/// Changes to a running escrow: the employer adds funds or moves the deadline
+/// out, and both parties can agree to cancel before the deadline.
+#[multiversx_sc::module]
+pub trait LifecycleModule:
+    common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::utils::UtilsModule
+    + common::roles::RolesModule
+    + common::pause::PauseModule
+{
+    /// Employer adds funds to an active escrow. The payment must be one of the
+    /// escrow's tokens; milestone and streaming escrows are fixed at deposit.
+    #[payable("*")]
+    #[endpoint(top_up)]
+    fn top_up(&self, job_id: ManagedBuffer) {
+        self.require_not_paused();
+        let escrow_mapper = self.escrow_data(&job_id);
+        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
+        let mut escrow = escrow_mapper.get();
+
+        let caller = self.blockchain().get_caller();
+        require!(caller == escrow.employer, ERR_NOT_EMPLOYER);
+        self.require_not_disputed(&escrow);
+        require!(escrow.status == EscrowStatus::Active, ERR_ALREADY_SETTLED);
+        require!(
+            self.escrow_milestones(&job_id).is_empty(),
+            ERR_HAS_MILESTONES
+        );
+        require!(self.escrow_stream(&job_id).is_empty(), ERR_IS_STREAM);
+
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.amount > 0u64, ERR_ZERO_DEPOSIT);
+
+        if payment.token_identifier == escrow.token_id && payment.token_nonce == escrow.token_nonce
+        {
+            escrow.amount += &payment.amount;
+        } else {
+            let mut found = false;
+            let mut extra_payments = ManagedVec::new();
+            for extra in escrow.extra_payments.iter() {
+                let mut extra = extra.clone();
+                if extra.token_identifier == payment.token_identifier
+                    && extra.token_nonce == payment.token_nonce
+                {
+                    extra.amount += &payment.amount;
+                    found = true;
+                }
+                extra_payments.push(extra);
+            }
+            require!(found, ERR_TOP_UP_TOKEN_MISMATCH);
+            escrow.extra_payments = extra_payments;
+        }
+        escrow_mapper.set(&escrow);
+        self.add_locked(&payment.token_identifier, &payment.amount);
+
+        self.escrow_topped_up_event(&job_id, &caller, payment);
+    }
+
+    /// Employer moves the refund deadline later. Never earlier. Not for
+    /// milestone or streaming escrows, whose deadlines are part of the schedule.
+    #[endpoint(extend_deadline)]
+    fn extend_deadline(&self, job_id: ManagedBuffer, new_deadline: TimestampSeconds) {
+        self.require_not_paused();
+        let escrow_mapper = self.escrow_data(&job_id);
+        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
+        let mut escrow = escrow_mapper.get();
+
+        let caller = self.blockchain().get_caller();
+        require!(caller == escrow.employer, ERR_NOT_EMPLOYER);
+        require!(escrow.locked_amount() > 0u64, ERR_ALREADY_SETTLED);
+        require!(
+            self.escrow_milestones(&job_id).is_empty(),
+            ERR_HAS_MILESTONES
+        );
+        require!(self.escrow_stream(&job_id).is_empty(), ERR_IS_STREAM);
+        require!(new_deadline > escrow.deadline, ERR_DEADLINE_NOT_EXTENDED);
+
+        escrow.deadline = new_deadline;
+        escrow_mapper.set(&escrow);
+
+        self.deadline_extended_event(&job_id, &caller, new_deadline);
+    }
+
+    /// Employer and receiver each call this to cancel by mutual consent. The
+    /// first call records the request; when the other party confirms,
+    /// everything still locked goes back to the employer without waiting for
+    /// the deadline.
+    #[endpoint(mutual_cancel)]
+    fn mutual_cancel(&self, job_id: ManagedBuffer) {
+        let escrow_mapper = self.escrow_data(&job_id);
+        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
+        let mut escrow = escrow_mapper.get();
+
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == escrow.employer || caller == self.current_receiver(&escrow),
+            ERR_NOT_PARTY_TO_CANCEL
+        );
+        require!(escrow.locked_amount() > 0u64, ERR_ALREADY_SETTLED);
+        self.require_not_disputed(&escrow);
+
+        let request_mapper = self.cancel_requested_by(&job_id);
+        if request_mapper.is_empty() || request_mapper.get() == caller {
+            request_mapper.set(&caller);
+            self.cancel_requested_event(&job_id, &caller);
+            return;
+        }
+
+        let amount = self.refund_locked(&job_id, &mut escrow);
+
+        self.escrow_cancelled_event(&job_id, &escrow.employer, amount);
+    }
+
+    /// Who asked for a mutual cancellation, if the other party has not confirmed yet.
+    #[view(get_cancel_request)]
+    fn get_cancel_request(&self, job_id: ManagedBuffer) -> OptionalValue<ManagedAddress> {
+        let request_mapper = self.cancel_requested_by(&job_id);
+        if request_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(request_mapper.get())
+        }
+    }
+}
@@ -0,0 +1,93 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::storage::{EscrowData, EscrowStatus};
+use common::roles::Role;
+
+/// `EscrowData` as stored before partial settlement, arbiters, agent binding
+/// and bundles were added (storage version 0).
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct EscrowDataV0<M: ManagedTypeApi> {
+    pub employer: ManagedAddress<M>,
+    pub receiver: ManagedAddress<M>,
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub token_nonce: u64,
+    pub amount: BigUint<M>,
+    pub poa_hash: ManagedBuffer<M>,
+    pub deadline: TimestampSeconds,
+    pub status: EscrowStatus,
+}
+
+/// Rewrites escrows stored in an older `EscrowData` layout and adds them to
+/// the employer/receiver indexes and, while still active, the locked totals.
+/// Job ids cannot be enumerated on-chain, so callers pass them in batches;
+/// escrows that are missing or already current are skipped, which makes every
+/// batch safe to repeat.
+#[multiversx_sc::module]
+pub trait MigrationModule:
+    common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::utils::UtilsModule
+    + common::roles::RolesModule
+    + common::migration::StorageVersionModule
+{
+    /// Returns how many escrows were rewritten.
+    #[endpoint(migrate_escrows)]
+    fn migrate_escrows(&self, job_ids: MultiValueEncoded<ManagedBuffer>) -> u32 {
+        self.require_role(Role::Admin);
+        self.migrate_escrow_records(job_ids)
+    }
+
+    fn migrate_escrow_records(&self, job_ids: MultiValueEncoded<ManagedBuffer>) -> u32 {
+        self.require_migration_batch(job_ids.len());
+        let mut migrated = 0u32;
+        for job_id in job_ids {
+            let raw_mapper = self.raw_escrow_data(&job_id);
+            if raw_mapper.is_empty() {
+                continue;
+            }
+            let raw = raw_mapper.get();
+            if EscrowData::<Self::Api>::top_decode(raw.clone()).is_ok() {
+                continue;
+            }
+            let Ok(legacy) = EscrowDataV0::<Self::Api>::top_decode(raw) else {
+                continue;
+            };
+
+            let mut escrow = EscrowData {
+                employer: legacy.employer,
+                receiver: legacy.receiver,
+                token_id: legacy.token_id,
+                token_nonce: legacy.token_nonce,
+                amount: legacy.amount,
+                poa_hash: legacy.poa_hash,
+                deadline: legacy.deadline,
+                status: legacy.status,
+                released_amount: BigUint::zero(),
+                refunded_amount: BigUint::zero(),
+                arbiter: None,
+                agent_nonce: None,
+                extra_payments: ManagedVec::new(),
+            };
+            match escrow.status {
+                EscrowStatus::Released => escrow.released_amount = escrow.amount.clone(),
+                EscrowStatus::Refunded => escrow.refunded_amount = escrow.amount.clone(),
+                _ => self.add_locked(&escrow.token_id, &escrow.amount),
+            }
+
+            self.employer_escrows(&escrow.employer)
+                .insert(job_id.clone());
+            self.receiver_escrows(&escrow.receiver)
+                .insert(job_id.clone());
+            self.escrow_data(&job_id).set(escrow);
+            migrated += 1;
+        }
+        migrated
+    }
+
+    /// `escrowData` as raw bytes, to tell layouts apart.
+    #[storage_mapper("escrowData")]
+    fn raw_escrow_data(&self, job_id: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+}
@@ -0,0 +1,151 @@
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::storage::{Milestone, MilestoneStatus};
+
+pub const MAX_MILESTONES: usize = 20;
+
+/// Staged escrows: the deposit is split into milestones, each released on its
+/// own verified job and refundable on its own deadline.
+#[multiversx_sc::module]
+pub trait MilestonesModule:
+    common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::utils::UtilsModule
+    + common::roles::RolesModule
+    + common::pause::PauseModule
+{
+    /// Deposit funds split into milestones of `(amount, deadline, verification_job_id)`.
+    /// Amounts must add up to the payment; the escrow deadline is the latest
+    /// milestone deadline. Disputes go to the owner-managed arbiters.
+    #[payable("*")]
+    #[endpoint(deposit_milestones)]
+    fn deposit_milestones(
+        &self,
+        job_id: ManagedBuffer,
+        receiver: ManagedAddress,
+        poa_hash: ManagedBuffer,
+        milestones: MultiValueEncoded<MultiValue3<BigUint, TimestampSeconds, ManagedBuffer>>,
+    ) {
+        self.require_not_paused();
+        require!(!milestones.is_empty(), ERR_NO_MILESTONES);
+        require!(milestones.len() <= MAX_MILESTONES, ERR_TOO_MANY_MILESTONES);
+
+        let payment = self.call_value().egld_or_single_esdt();
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds();
+
+        let mut total = BigUint::zero();
+        let mut last_deadline = current_timestamp;
+        let mut milestone_mapper = self.escrow_milestones(&job_id);
+        for milestone in milestones {
+            let (amount, deadline, verification_job_id) = milestone.into_tuple();
+            require!(amount > 0u64, ERR_ZERO_MILESTONE);
+            require!(deadline > current_timestamp, ERR_DEADLINE_IN_PAST);
+
+            total += &amount;
+            if deadline > last_deadline {
+                last_deadline = deadline;
+            }
+            self.verification_job_escrows(&verification_job_id)
+                .insert(job_id.clone());
+            milestone_mapper.push(&Milestone {
+                amount,
+                deadline,
+                verification_job_id,
+                status: MilestoneStatus::Pending,
+            });
+        }
+        require!(total == payment.amount, ERR_MILESTONE_AMOUNT_MISMATCH);
+
+        self.create_escrow(
+            &job_id,
+            self.blockchain().get_caller(),
+            receiver,
+            poa_hash,
+            last_deadline,
+            &ManagedVec::from_single_item(payment),
+            None,
+            None,
+        );
+    }
+
+    /// Release one milestone to the receiver once its verification job is verified.
+    /// The employer can release immediately; anyone else after the challenge window.
+    #[endpoint(release_milestone)]
+    fn release_milestone(&self, job_id: ManagedBuffer, milestone_index: usize) {
+        self.require_not_paused();
+        let escrow_mapper = self.escrow_data(&job_id);
+        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
+        let mut escrow = escrow_mapper.get();
+        self.require_not_disputed(&escrow);
+
+        let mut milestone = self.get_pending_milestone(&job_id, milestone_index);
+        self.require_releasable(&escrow, &milestone.verification_job_id);
+
+        // Effects
+        milestone.status = MilestoneStatus::Released;
+        self.escrow_milestones(&job_id)
+            .set(milestone_index + 1, &milestone);
+        escrow.released_amount += &milestone.amount;
+        escrow.update_status();
+        escrow_mapper.set(&escrow);
+
+        // Interactions
+        let receiver = self.current_receiver(&escrow);
+        let net = self.pay_receiver(&job_id, &escrow, &receiver, &milestone.amount);
+
+        self.milestone_released_event(&job_id, milestone_index, &receiver, net);
+    }
+
+    /// Refund one unreached milestone to the employer after its deadline,
+    /// unless its verification job is verified. Anyone can call this.
+    #[endpoint(refund_milestone)]
+    fn refund_milestone(&self, job_id: ManagedBuffer, milestone_index: usize) {
+        let escrow_mapper = self.escrow_data(&job_id);
+        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
+        let mut escrow = escrow_mapper.get();
+        self.require_not_disputed(&escrow);
+
+        let mut milestone = self.get_pending_milestone(&job_id, milestone_index);
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds();
+        require!(
+            current_timestamp > milestone.deadline,
+            ERR_DEADLINE_NOT_PASSED
+        );
+        self.require_job_unverified(&milestone.verification_job_id, milestone.deadline);
+
+        // Effects
+        milestone.status = MilestoneStatus::Refunded;
+        self.escrow_milestones(&job_id)
+            .set(milestone_index + 1, &milestone);
+        escrow.refunded_amount += &milestone.amount;
+        escrow.update_status();
+        escrow_mapper.set(&escrow);
+
+        // Interactions
+        self.send_escrowed(&escrow, &escrow.employer, &milestone.amount);
+
+        self.milestone_refunded_event(&job_id, milestone_index, &escrow.employer, milestone.amount);
+    }
+
+    /// `milestone_index` is zero-based.
+    fn get_pending_milestone(
+        &self,
+        job_id: &ManagedBuffer,
+        milestone_index: usize,
+    ) -> Milestone<Self::Api> {
+        let milestone_mapper = self.escrow_milestones(job_id);
+        require!(
+            milestone_index < milestone_mapper.len(),
+            ERR_MILESTONE_NOT_FOUND
+        );
+        let milestone = milestone_mapper.get(milestone_index + 1);
+        require!(
+            milestone.status == MilestoneStatus::Pending,
+            ERR_MILESTONE_SETTLED
+        );
+        milestone
+    }
+}
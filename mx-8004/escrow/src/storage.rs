@@ -1,27 +1,57 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-/// Escrow settlement status.
+pub use common::structs::{EscrowData, EscrowStatus, SyncedJob};
+
+/// What happened to one job in `release_batch` / `refund_batch`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub enum BatchOutcome {
+    Settled,
+    /// Not eligible; the accompanying reason is the error the single-job
+    /// endpoint would have failed with.
+    Skipped,
+    /// Left untouched because the transaction ran low on gas.
+    NotProcessed,
+}
+
+/// Milestone settlement status.
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
-pub enum EscrowStatus {
-    Active,
+pub enum MilestoneStatus {
+    Pending,
     Released,
     Refunded,
+    Resolved,
 }
 
-/// On-chain escrow record.
+/// One stage of a milestone escrow. Released once `verification_job_id` is
+/// verified in the ValidationRegistry; refundable after `deadline`.
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
-pub struct EscrowData<M: ManagedTypeApi> {
-    pub employer: ManagedAddress<M>,
-    pub receiver: ManagedAddress<M>,
-    pub token_id: EgldOrEsdtTokenIdentifier<M>,
-    pub token_nonce: u64,
+pub struct Milestone<M: ManagedTypeApi> {
     pub amount: BigUint<M>,
-    pub poa_hash: ManagedBuffer<M>,
     pub deadline: TimestampSeconds,
-    pub status: EscrowStatus,
+    pub verification_job_id: ManagedBuffer<M>,
+    pub status: MilestoneStatus,
+}
+
+/// Open dispute on an escrow, raised by the employer or the receiver.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct EscrowDispute<M: ManagedTypeApi> {
+    pub opened_by: ManagedAddress<M>,
+    pub reason_uri: ManagedBuffer<M>,
+    pub opened_at: TimestampSeconds,
+}
+
+/// Vesting schedule of a streaming escrow: the amount vests linearly from
+/// `start` to `end`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct Stream {
+    pub start: TimestampSeconds,
+    pub end: TimestampSeconds,
 }
 
 #[multiversx_sc::module]
@@ -30,6 +60,73 @@ pub trait StorageModule {
     #[storage_mapper("escrowData")]
     fn escrow_data(&self, job_id: &ManagedBuffer) -> SingleValueMapper<EscrowData<Self::Api>>;
 
+    #[storage_mapper("employerEscrows")]
+    fn employer_escrows(&self, employer: &ManagedAddress) -> UnorderedSetMapper<ManagedBuffer>;
+
+    /// Keyed by the receiver recorded at deposit (the agent owner at that time
+    /// for agent-bound escrows).
+    #[storage_mapper("receiverEscrows")]
+    fn receiver_escrows(&self, receiver: &ManagedAddress) -> UnorderedSetMapper<ManagedBuffer>;
+
+    /// Funds currently held for escrows, per token.
+    #[view(get_total_locked)]
+    #[storage_mapper("totalLocked")]
+    fn total_locked(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("lockedTokens")]
+    fn locked_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
+    #[view(get_milestones)]
+    #[storage_mapper("escrowMilestones")]
+    fn escrow_milestones(&self, job_id: &ManagedBuffer) -> VecMapper<Milestone<Self::Api>>;
+
+    /// Milestone escrows released on `verification_job_id`; the validation
+    /// registry keeps that job while any of them still holds funds.
+    #[storage_mapper("verificationJobEscrows")]
+    fn verification_job_escrows(
+        &self,
+        verification_job_id: &ManagedBuffer,
+    ) -> UnorderedSetMapper<ManagedBuffer>;
+
+    #[view(get_stream)]
+    #[storage_mapper("escrowStream")]
+    fn escrow_stream(&self, job_id: &ManagedBuffer) -> SingleValueMapper<Stream>;
+
+    #[view(get_dispute)]
+    #[storage_mapper("escrowDispute")]
+    fn escrow_dispute(&self, job_id: &ManagedBuffer)
+    -> SingleValueMapper<EscrowDispute<Self::Api>>;
+
+    /// Party that asked for a mutual cancellation still awaiting the other side.
+    #[storage_mapper("cancelRequestedBy")]
+    fn cancel_requested_by(&self, job_id: &ManagedBuffer) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(get_arbiters)]
+    #[storage_mapper("arbiters")]
+    fn arbiters(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(get_challenge_window)]
+    #[storage_mapper("challengeWindow")]
+    fn challenge_window(&self) -> SingleValueMapper<DurationSeconds>;
+
+    /// Protocol fee in basis points, per payment token.
+    #[view(get_fee_bps)]
+    #[storage_mapper("feeBps")]
+    fn fee_bps(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<u32>;
+
+    #[view(get_treasury)]
+    #[storage_mapper("treasury")]
+    fn treasury(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Running total of fees sent to the treasury, per token.
+    #[view(get_collected_fees)]
+    #[storage_mapper("collectedFees")]
+    fn collected_fees(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+
+    #[view(get_fee_tokens)]
+    #[storage_mapper("feeTokens")]
+    fn fee_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+
     #[view(get_validation_contract_address)]
     #[storage_mapper("validationContractAddress")]
     fn validation_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
@@ -37,4 +134,9 @@ pub trait StorageModule {
     #[view(get_identity_contract_address)]
     #[storage_mapper("identityContractAddress")]
     fn identity_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Filled by `sync_job` when validation-registry is on another shard.
+    #[view(get_synced_job)]
+    #[storage_mapper("syncedJob")]
+    fn synced_job(&self, job_id: &ManagedBuffer) -> SingleValueMapper<SyncedJob<Self::Api>>;
 }
@@ -0,0 +1,175 @@
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::events::StreamStoppedEventData;
+use crate::storage::{EscrowData, EscrowStatus, Stream};
+
+/// Streaming escrows for subscription-style work: the deposit vests linearly
+/// between `start` and `end`, the receiver withdraws what has vested at any
+/// time, and the employer can stop the stream to take back the unvested rest.
+/// Vesting is frozen at the moment a dispute is opened.
+#[multiversx_sc::module]
+pub trait StreamsModule:
+    common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::utils::UtilsModule
+    + common::roles::RolesModule
+    + common::pause::PauseModule
+{
+    /// Deposit a single EGLD or ESDT payment that vests from `start` to `end`.
+    /// `end` is also the escrow deadline; a `start` in the past vests the
+    /// elapsed share right away.
+    #[payable("*")]
+    #[endpoint(deposit_stream)]
+    fn deposit_stream(
+        &self,
+        job_id: ManagedBuffer,
+        receiver: ManagedAddress,
+        poa_hash: ManagedBuffer,
+        start: TimestampSeconds,
+        end: TimestampSeconds,
+        arbiter: OptionalValue<ManagedAddress>,
+    ) {
+        self.require_not_paused();
+        require!(end > start, ERR_INVALID_STREAM_PERIOD);
+        let payment = self.call_value().egld_or_single_esdt();
+
+        self.escrow_stream(&job_id).set(Stream { start, end });
+        self.create_escrow(
+            &job_id,
+            self.blockchain().get_caller(),
+            receiver,
+            poa_hash,
+            end,
+            &ManagedVec::from_single_item(payment),
+            arbiter.into_option(),
+            None,
+        );
+    }
+
+    /// Receiver takes everything vested so far that was not withdrawn yet,
+    /// minus the protocol fee. Not while disputed.
+    #[endpoint(withdraw_vested)]
+    fn withdraw_vested(&self, job_id: ManagedBuffer) {
+        self.require_not_paused();
+        let (mut escrow, stream) = self.get_stream_escrow(&job_id);
+        self.require_not_disputed(&escrow);
+        require!(escrow.locked_amount() > 0u64, ERR_ALREADY_SETTLED);
+
+        let receiver = self.current_receiver(&escrow);
+        let caller = self.blockchain().get_caller();
+        require!(caller == receiver, ERR_NOT_RECEIVER);
+
+        let now = self.blockchain().get_block_timestamp_seconds();
+        let amount = self.vested_amount(&job_id, &escrow, &stream, now) - &escrow.released_amount;
+        require!(amount > 0u64, ERR_NOTHING_VESTED);
+
+        // Effects
+        escrow.released_amount += &amount;
+        escrow.update_status();
+        self.escrow_data(&job_id).set(&escrow);
+
+        // Interactions
+        let net = self.pay_receiver(&job_id, &escrow, &receiver, &amount);
+
+        self.stream_withdrawn_event(&job_id, &receiver, net);
+    }
+
+    /// Employer ends the stream now: whatever has vested but was not withdrawn
+    /// goes to the receiver (minus the protocol fee), the unvested rest back to
+    /// the employer. Not while disputed.
+    #[endpoint(stop_stream)]
+    fn stop_stream(&self, job_id: ManagedBuffer) {
+        self.require_not_paused();
+        let (mut escrow, stream) = self.get_stream_escrow(&job_id);
+        let caller = self.blockchain().get_caller();
+        require!(caller == escrow.employer, ERR_NOT_EMPLOYER);
+        self.require_not_disputed(&escrow);
+        require!(escrow.locked_amount() > 0u64, ERR_ALREADY_SETTLED);
+
+        let now = self.blockchain().get_block_timestamp_seconds();
+        let vested = self.vested_amount(&job_id, &escrow, &stream, now);
+        let receiver_amount = &vested - &escrow.released_amount;
+        let employer_amount = &escrow.amount - &vested;
+
+        // Effects
+        escrow.released_amount = vested;
+        escrow.refunded_amount = employer_amount.clone();
+        escrow.update_status();
+        self.escrow_data(&job_id).set(&escrow);
+        self.cancel_requested_by(&job_id).clear();
+
+        // Interactions
+        let receiver = self.current_receiver(&escrow);
+        let net = if receiver_amount > 0u64 {
+            self.pay_receiver(&job_id, &escrow, &receiver, &receiver_amount)
+        } else {
+            BigUint::zero()
+        };
+        if employer_amount > 0u64 {
+            self.send_escrowed(&escrow, &escrow.employer, &employer_amount);
+        }
+
+        self.stream_stopped_event(
+            &job_id,
+            &caller,
+            StreamStoppedEventData {
+                receiver_amount: net,
+                employer_amount,
+            },
+        );
+    }
+
+    /// Vested so far and what the receiver could withdraw right now, as
+    /// `(vested, withdrawable)`. Vesting is frozen and nothing is withdrawable
+    /// while a dispute is open; once settled, vested is what the receiver was paid.
+    #[view(get_vested_amount)]
+    fn get_vested_amount(&self, job_id: ManagedBuffer) -> MultiValue2<BigUint, BigUint> {
+        let (escrow, stream) = self.get_stream_escrow(&job_id);
+        if escrow.locked_amount() == 0u64 {
+            return (escrow.released_amount, BigUint::zero()).into();
+        }
+        let now = self.blockchain().get_block_timestamp_seconds();
+        let vested = self.vested_amount(&job_id, &escrow, &stream, now);
+        let withdrawable = if escrow.status == EscrowStatus::Disputed {
+            BigUint::zero()
+        } else {
+            &vested - &escrow.released_amount
+        };
+        (vested, withdrawable).into()
+    }
+
+    fn get_stream_escrow(&self, job_id: &ManagedBuffer) -> (EscrowData<Self::Api>, Stream) {
+        let escrow_mapper = self.escrow_data(job_id);
+        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_FOUND);
+        let stream_mapper = self.escrow_stream(job_id);
+        require!(!stream_mapper.is_empty(), ERR_NOT_STREAM);
+        (escrow_mapper.get(), stream_mapper.get())
+    }
+
+    /// Linear vesting at `now`, capped at `end` and, while disputed, at the
+    /// moment the dispute was opened. Rounds down.
+    fn vested_amount(
+        &self,
+        job_id: &ManagedBuffer,
+        escrow: &EscrowData<Self::Api>,
+        stream: &Stream,
+        now: TimestampSeconds,
+    ) -> BigUint {
+        let mut at = now;
+        if escrow.status == EscrowStatus::Disputed {
+            at = core::cmp::min(at, self.escrow_dispute(job_id).get().opened_at);
+        }
+        if at <= stream.start {
+            return BigUint::zero();
+        }
+        if at >= stream.end {
+            return escrow.amount.clone();
+        }
+        let elapsed = (at - stream.start).as_u64_seconds();
+        let duration = (stream.end - stream.start).as_u64_seconds();
+        &escrow.amount * elapsed / duration
+    }
+}
@@ -0,0 +1,49 @@
+multiversx_sc::imports!();
+
+use crate::errors::ERR_SAME_SHARD;
+use crate::storage::SyncedJob;
+
+/// Snapshots for registries deployed on another shard, where escrow cannot
+/// read their storage directly. Anyone can refresh them; checks that need a
+/// snapshot fail until one exists. See `common::cross_shard`.
+#[multiversx_sc::module]
+pub trait SyncModule:
+    common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+{
+    /// Caches the owner of an agent from identity-registry.
+    #[endpoint(sync_agent)]
+    fn sync_agent(&self, agent_nonce: u64) {
+        let identity_addr = self.identity_contract_address().get();
+        self.sync_agent_owner(identity_addr, agent_nonce);
+    }
+
+    /// Caches a job's employer, agent and status from validation-registry.
+    /// Refunds need a snapshot read after the deadline they check.
+    #[endpoint(sync_job)]
+    fn sync_job(&self, job_id: ManagedBuffer) {
+        let validation_addr = self.validation_contract_address().get();
+        require!(!self.is_same_shard(&validation_addr), ERR_SAME_SHARD);
+        self.tx()
+            .to(&validation_addr)
+            .raw_call("get_job_snapshot")
+            .argument(&job_id)
+            .callback(SyncModule::callbacks(self).job_synced(job_id))
+            .async_call_and_exit()
+    }
+
+    #[callback]
+    fn job_synced(
+        &self,
+        job_id: ManagedBuffer,
+        #[call_result] result: ManagedAsyncCallResult<SyncedJob<Self::Api>>,
+    ) {
+        // A failed read leaves the previous snapshot in place
+        if let ManagedAsyncCallResult::Ok(synced) = result {
+            self.job_synced_event(&job_id, &synced);
+            self.synced_job(&job_id).set(synced);
+        }
+    }
+}
@@ -0,0 +1,523 @@
+multiversx_sc::imports!();
+
+use common::structs::{JobSnapshot, JobStatus};
+
+use crate::errors::*;
+use crate::storage::{EscrowData, EscrowStatus, MilestoneStatus};
+
+pub const MAX_PAYMENTS: usize = 10;
+
+#[multiversx_sc::module]
+pub trait UtilsModule:
+    common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+{
+    /// EGLD or a single ESDT as one payment; a multi-ESDT transfer as a bundle.
+    fn received_payments(&self) -> ManagedVec<EgldOrEsdtTokenPayment> {
+        let transfers = self.call_value().all_transfers();
+        if transfers.len() > 1 {
+            require!(transfers.len() <= MAX_PAYMENTS, ERR_TOO_MANY_PAYMENTS);
+            return transfers.clone();
+        }
+        ManagedVec::from_single_item(self.call_value().egld_or_single_esdt())
+    }
+
+    /// Validates and stores a new escrow for the incoming payments.
+    fn create_escrow(
+        &self,
+        job_id: &ManagedBuffer,
+        employer: ManagedAddress,
+        receiver: ManagedAddress,
+        poa_hash: ManagedBuffer,
+        deadline: TimestampSeconds,
+        payments: &ManagedVec<EgldOrEsdtTokenPayment>,
+        arbiter: Option<ManagedAddress>,
+        agent_nonce: Option<u64>,
+    ) {
+        for payment in payments.iter() {
+            require!(payment.amount > 0u64, ERR_ZERO_DEPOSIT);
+        }
+        let payment = payments.get(0).clone();
+        let extra_payments = payments.slice(1, payments.len()).unwrap_or_default();
+
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds();
+        require!(deadline > current_timestamp, ERR_DEADLINE_IN_PAST);
+
+        let escrow_mapper = self.escrow_data(job_id);
+        require!(escrow_mapper.is_empty(), ERR_ESCROW_ALREADY_EXISTS);
+
+        if let Some(arbiter) = &arbiter {
+            require!(
+                arbiter != &employer && arbiter != &receiver,
+                ERR_INVALID_ARBITER
+            );
+            require!(self.arbiters().contains(arbiter), ERR_ARBITER_NOT_LISTED);
+        }
+
+        let escrow = EscrowData {
+            employer: employer.clone(),
+            receiver,
+            token_id: payment.token_identifier.clone(),
+            token_nonce: payment.token_nonce,
+            amount: payment.amount.clone(),
+            poa_hash,
+            deadline,
+            status: EscrowStatus::Active,
+            released_amount: BigUint::zero(),
+            refunded_amount: BigUint::zero(),
+            arbiter,
+            agent_nonce,
+            extra_payments,
+        };
+
+        // Effects: store and index escrow
+        escrow_mapper.set(&escrow);
+        self.employer_escrows(&employer).insert(job_id.clone());
+        self.receiver_escrows(&escrow.receiver)
+            .insert(job_id.clone());
+        for payment in payments.iter() {
+            self.add_locked(&payment.token_identifier, &payment.amount);
+        }
+
+        if escrow.is_bundle() {
+            self.bundle_deposited_event(job_id, &employer, payments.clone());
+        } else {
+            self.escrow_deposited_event(job_id, &employer, payment.amount);
+        }
+    }
+
+    /// Who gets paid: the agent's current owner for agent-bound escrows,
+    /// otherwise the address given at deposit. An agent that has since been
+    /// deregistered pays the owner recorded at deposit.
+    fn current_receiver(&self, escrow: &EscrowData<Self::Api>) -> ManagedAddress {
+        match self.read_receiver(escrow) {
+            Ok(receiver) => receiver,
+            Err(err) => sc_panic!(err),
+        }
+    }
+
+    /// `current_receiver` without failing: the reason the agent's owner cannot
+    /// be read, if it cannot.
+    fn read_receiver(
+        &self,
+        escrow: &EscrowData<Self::Api>,
+    ) -> Result<ManagedAddress, &'static str> {
+        let Some(agent_nonce) = escrow.agent_nonce else {
+            return Ok(escrow.receiver.clone());
+        };
+        let identity_addr = self.identity_contract_address().get();
+        Ok(self
+            .read_agent_owner(identity_addr, agent_nonce)?
+            .unwrap_or_else(|| escrow.receiver.clone()))
+    }
+
+    /// Cross-contract read: current owner of an agent in identity-registry.
+    fn agent_owner(&self, agent_nonce: u64) -> ManagedAddress {
+        let identity_addr = self.identity_contract_address().get();
+        match self.resolve_agent_owner(identity_addr, agent_nonce) {
+            Some(owner) => owner,
+            None => sc_panic!(ERR_AGENT_NOT_FOUND),
+        }
+    }
+
+    /// Cross-contract read: the job in validation-registry, or the `sync_job`
+    /// snapshot when it is on another shard. `None` if the job does not exist.
+    fn read_job(
+        &self,
+        job_id: &ManagedBuffer,
+    ) -> Result<Option<JobSnapshot<Self::Api>>, &'static str> {
+        let validation_addr = self.validation_contract_address().get();
+        if !self.is_same_shard(&validation_addr) {
+            let synced_mapper = self.synced_job(job_id);
+            if synced_mapper.is_empty() {
+                return Err(ERR_JOB_NOT_SYNCED);
+            }
+            return Ok(synced_mapper.get().job);
+        }
+
+        let job_mapper = self.external_job_data(validation_addr.clone(), job_id);
+        if job_mapper.is_empty() {
+            return Ok(None);
+        }
+        let job_data = job_mapper.get();
+        Ok(Some(JobSnapshot {
+            employer: job_data.employer,
+            agent_nonce: job_data.agent_nonce,
+            status: job_data.status,
+            verified_at: self.external_job_verified_at(validation_addr, job_id).get(),
+        }))
+    }
+
+    /// The validation-registry job must exist and belong to `agent_nonce` and `employer`.
+    fn require_job_matches(
+        &self,
+        job_id: &ManagedBuffer,
+        agent_nonce: u64,
+        employer: &ManagedAddress,
+    ) {
+        if let Err(err) = self.check_job_matches(job_id, agent_nonce, employer) {
+            sc_panic!(err);
+        }
+    }
+
+    fn check_job_matches(
+        &self,
+        job_id: &ManagedBuffer,
+        agent_nonce: u64,
+        employer: &ManagedAddress,
+    ) -> Result<(), &'static str> {
+        let Some(job_data) = self.read_job(job_id)? else {
+            return Err(ERR_JOB_NOT_IN_VALIDATION_REGISTRY);
+        };
+
+        if job_data.agent_nonce != agent_nonce {
+            return Err(ERR_JOB_AGENT_MISMATCH);
+        }
+        if &job_data.employer != employer {
+            return Err(ERR_JOB_EMPLOYER_MISMATCH);
+        }
+        Ok(())
+    }
+
+    fn require_not_disputed(&self, escrow: &EscrowData<Self::Api>) {
+        require!(escrow.status != EscrowStatus::Disputed, ERR_ESCROW_DISPUTED);
+    }
+
+    /// Refund gate: the job must not be verified. Across shards the snapshot
+    /// must be taken after `deadline`, so it reflects the job as of the
+    /// refund window rather than some earlier state.
+    fn require_job_unverified(&self, job_id: &ManagedBuffer, deadline: TimestampSeconds) {
+        if let Err(err) = self.check_job_unverified(job_id, deadline) {
+            sc_panic!(err);
+        }
+    }
+
+    fn check_job_unverified(
+        &self,
+        job_id: &ManagedBuffer,
+        deadline: TimestampSeconds,
+    ) -> Result<(), &'static str> {
+        let job = self.read_job(job_id)?;
+        let validation_addr = self.validation_contract_address().get();
+        if !self.is_same_shard(&validation_addr)
+            && self.synced_job(job_id).get().synced_at <= deadline
+        {
+            return Err(ERR_JOB_SNAPSHOT_STALE);
+        }
+        if job.is_some_and(|job| job.status == JobStatus::Verified) {
+            return Err(ERR_JOB_VERIFIED);
+        }
+        Ok(())
+    }
+
+    /// Release gate: the job must be verified. The employer can release right
+    /// away; anyone else only once the challenge window after verification has
+    /// elapsed. Jobs verified before `jobVerifiedAt` existed count as verified at 0.
+    fn require_releasable(&self, escrow: &EscrowData<Self::Api>, job_id: &ManagedBuffer) {
+        if let Err(err) = self.check_releasable(escrow, job_id) {
+            sc_panic!(err);
+        }
+    }
+
+    fn check_releasable(
+        &self,
+        escrow: &EscrowData<Self::Api>,
+        job_id: &ManagedBuffer,
+    ) -> Result<(), &'static str> {
+        let verified_at = match self.read_job(job_id)? {
+            Some(job) if job.status == JobStatus::Verified => job.verified_at,
+            _ => return Err(ERR_JOB_NOT_VERIFIED),
+        };
+
+        let caller = self.blockchain().get_caller();
+        if caller == escrow.employer {
+            return Ok(());
+        }
+
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds();
+        if current_timestamp < verified_at + self.challenge_window().get() {
+            return Err(ERR_CHALLENGE_WINDOW_OPEN);
+        }
+        Ok(())
+    }
+
+    /// Dispute gate: a job that is verified and past its challenge window can
+    /// be released by anyone, so it can no longer be frozen by a dispute. For
+    /// milestone escrows this holds for every pending milestone's job; streams
+    /// have no job to check.
+    fn require_disputable(&self, job_id: &ManagedBuffer) {
+        let milestone_mapper = self.escrow_milestones(job_id);
+        if milestone_mapper.is_empty() {
+            if self.escrow_stream(job_id).is_empty() {
+                self.require_challenge_window_open(job_id);
+            }
+            return;
+        }
+        for milestone in milestone_mapper.iter() {
+            if milestone.status == MilestoneStatus::Pending {
+                self.require_challenge_window_open(&milestone.verification_job_id);
+            }
+        }
+    }
+
+    fn require_challenge_window_open(&self, job_id: &ManagedBuffer) {
+        let job = match self.read_job(job_id) {
+            Ok(job) => job,
+            Err(err) => sc_panic!(err),
+        };
+        if let Some(job) = job.filter(|job| job.status == JobStatus::Verified) {
+            let current_timestamp = self.blockchain().get_block_timestamp_seconds();
+            require!(
+                current_timestamp < job.verified_at + self.challenge_window().get(),
+                ERR_DISPUTE_WINDOW_CLOSED
+            );
+        }
+    }
+
+    /// Everything `release` checks, without failing: the escrow to release or
+    /// the reason it cannot be released by the caller right now.
+    fn releasable_escrow(
+        &self,
+        job_id: &ManagedBuffer,
+    ) -> Result<EscrowData<Self::Api>, &'static str> {
+        let escrow_mapper = self.escrow_data(job_id);
+        if escrow_mapper.is_empty() {
+            return Err(ERR_ESCROW_NOT_FOUND);
+        }
+
+        let escrow = escrow_mapper.get();
+        if escrow.status == EscrowStatus::Disputed {
+            return Err(ERR_ESCROW_DISPUTED);
+        }
+        if escrow.status != EscrowStatus::Active {
+            return Err(ERR_ALREADY_SETTLED);
+        }
+        if !self.escrow_milestones(job_id).is_empty() {
+            return Err(ERR_HAS_MILESTONES);
+        }
+        if !self.escrow_stream(job_id).is_empty() {
+            return Err(ERR_IS_STREAM);
+        }
+        self.check_releasable(&escrow, job_id)?;
+        if let Some(agent_nonce) = escrow.agent_nonce {
+            self.check_job_matches(job_id, agent_nonce, &escrow.employer)?;
+        }
+        self.read_receiver(&escrow)?;
+        Ok(escrow)
+    }
+
+    /// Pays out a checked escrow in full and emits the release event.
+    fn execute_release(&self, job_id: &ManagedBuffer, mut escrow: EscrowData<Self::Api>) {
+        let receiver = self.current_receiver(&escrow);
+
+        // Effects: mark as released BEFORE interactions
+        escrow.released_amount = escrow.amount.clone();
+        escrow.status = EscrowStatus::Released;
+        self.escrow_data(job_id).set(&escrow);
+
+        // Interactions: transfer funds to receiver, minus the protocol fee
+        if escrow.is_bundle() {
+            let net_payments = self.pay_receiver_bundle(job_id, &escrow, &receiver);
+            self.bundle_released_event(job_id, &receiver, net_payments);
+            return;
+        }
+        let net = self.pay_receiver(job_id, &escrow, &receiver, &escrow.amount);
+
+        self.escrow_released_event(job_id, &receiver, net);
+    }
+
+    /// Everything `refund` checks, without failing: the escrow to refund or the
+    /// reason it cannot be refunded yet.
+    fn refundable_escrow(
+        &self,
+        job_id: &ManagedBuffer,
+    ) -> Result<EscrowData<Self::Api>, &'static str> {
+        let escrow_mapper = self.escrow_data(job_id);
+        if escrow_mapper.is_empty() {
+            return Err(ERR_ESCROW_NOT_FOUND);
+        }
+
+        let escrow = escrow_mapper.get();
+        if escrow.locked_amount() == 0u64 {
+            return Err(ERR_ALREADY_SETTLED);
+        }
+        if escrow.status == EscrowStatus::Disputed {
+            return Err(ERR_ESCROW_DISPUTED);
+        }
+        if !self.escrow_stream(job_id).is_empty() {
+            return Err(ERR_IS_STREAM);
+        }
+
+        let current_timestamp = self.blockchain().get_block_timestamp_seconds();
+        if current_timestamp <= escrow.deadline {
+            return Err(ERR_DEADLINE_NOT_PASSED);
+        }
+
+        let milestone_mapper = self.escrow_milestones(job_id);
+        if milestone_mapper.is_empty() {
+            self.check_job_unverified(job_id, escrow.deadline)?;
+        }
+        for milestone in milestone_mapper.iter() {
+            if milestone.status == MilestoneStatus::Pending {
+                self.check_job_unverified(&milestone.verification_job_id, escrow.deadline)?;
+            }
+        }
+        Ok(escrow)
+    }
+
+    /// Returns a checked escrow's locked funds and emits the refund event.
+    fn execute_refund(&self, job_id: &ManagedBuffer, mut escrow: EscrowData<Self::Api>) {
+        let amount = self.refund_locked(job_id, &mut escrow);
+
+        if escrow.is_bundle() {
+            self.bundle_refunded_event(job_id, &escrow.employer, escrow.payments());
+            return;
+        }
+        self.escrow_refunded_event(job_id, &escrow.employer, amount);
+    }
+
+    /// Pays a release to `receiver`, sending the protocol fee for the escrow's
+    /// token to the treasury first. The fee rounds down. Returns the net amount.
+    fn pay_receiver(
+        &self,
+        job_id: &ManagedBuffer,
+        escrow: &EscrowData<Self::Api>,
+        receiver: &ManagedAddress,
+        amount: &BigUint,
+    ) -> BigUint {
+        let fee_bps = self.fee_bps(&escrow.token_id).get();
+        let fee = amount * fee_bps / crate::config::BPS_DENOMINATOR;
+        let net = amount - &fee;
+
+        if fee > 0u64 {
+            let treasury = self.treasury().get();
+            self.collected_fees(&escrow.token_id)
+                .update(|collected| *collected += &fee);
+            self.fee_tokens().insert(escrow.token_id.clone());
+            self.send_escrowed(escrow, &treasury, &fee);
+            self.fee_collected_event(job_id, &treasury, &escrow.token_id, fee);
+        }
+        if net > 0u64 {
+            self.send_escrowed(escrow, receiver, &net);
+        }
+        net
+    }
+
+    /// Returns everything still locked to the employer: pending milestones are
+    /// marked refunded and the escrow stored BEFORE the transfer. Returns the
+    /// refunded amount of the first payment.
+    fn refund_locked(&self, job_id: &ManagedBuffer, escrow: &mut EscrowData<Self::Api>) -> BigUint {
+        let amount = escrow.locked_amount();
+
+        // Effects
+        let mut milestone_mapper = self.escrow_milestones(job_id);
+        for index in 1..=milestone_mapper.len() {
+            let mut milestone = milestone_mapper.get(index);
+            if milestone.status == MilestoneStatus::Pending {
+                milestone.status = MilestoneStatus::Refunded;
+                milestone_mapper.set(index, &milestone);
+            }
+        }
+        escrow.refunded_amount += &amount;
+        escrow.update_status();
+        self.escrow_data(job_id).set(&*escrow);
+        self.cancel_requested_by(job_id).clear();
+
+        // Interactions
+        if escrow.is_bundle() {
+            self.send_bundle(&escrow.employer, &escrow.payments());
+        } else {
+            self.send_escrowed(escrow, &escrow.employer, &amount);
+        }
+        amount
+    }
+
+    /// Pays a whole bundle to `receiver` in one multi-transfer, with the
+    /// per-token protocol fees going to the treasury in another. Returns the
+    /// net payments.
+    fn pay_receiver_bundle(
+        &self,
+        job_id: &ManagedBuffer,
+        escrow: &EscrowData<Self::Api>,
+        receiver: &ManagedAddress,
+    ) -> ManagedVec<EgldOrEsdtTokenPayment> {
+        let mut fees = ManagedVec::<Self::Api, EgldOrEsdtTokenPayment<Self::Api>>::new();
+        let mut net_payments = ManagedVec::new();
+        for payment in escrow.payments().iter() {
+            let fee_bps = self.fee_bps(&payment.token_identifier).get();
+            let fee = &payment.amount * fee_bps / crate::config::BPS_DENOMINATOR;
+            let net = &payment.amount - &fee;
+            if fee > 0u64 {
+                self.collected_fees(&payment.token_identifier)
+                    .update(|collected| *collected += &fee);
+                self.fee_tokens().insert(payment.token_identifier.clone());
+                fees.push(EgldOrEsdtTokenPayment::new(
+                    payment.token_identifier.clone(),
+                    payment.token_nonce,
+                    fee,
+                ));
+            }
+            if net > 0u64 {
+                net_payments.push(EgldOrEsdtTokenPayment::new(
+                    payment.token_identifier.clone(),
+                    payment.token_nonce,
+                    net,
+                ));
+            }
+        }
+
+        if !fees.is_empty() {
+            let treasury = self.treasury().get();
+            self.send_bundle(&treasury, &fees);
+            for fee in fees.iter() {
+                self.fee_collected_event(
+                    job_id,
+                    &treasury,
+                    &fee.token_identifier,
+                    fee.amount.clone(),
+                );
+            }
+        }
+        self.send_bundle(receiver, &net_payments);
+        net_payments
+    }
+
+    fn add_locked(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        self.total_locked(token_id).update(|total| *total += amount);
+        self.locked_tokens().insert(token_id.clone());
+    }
+
+    fn remove_locked(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        let total_mapper = self.total_locked(token_id);
+        let total = total_mapper.get() - amount;
+        if total == 0u64 {
+            total_mapper.clear();
+            self.locked_tokens().swap_remove(token_id);
+        } else {
+            total_mapper.set(&total);
+        }
+    }
+
+    /// Every payout of escrowed funds goes through `send_bundle` or
+    /// `send_escrowed`, which keep `totalLocked` in step.
+    fn send_bundle(&self, to: &ManagedAddress, payments: &ManagedVec<EgldOrEsdtTokenPayment>) {
+        if payments.is_empty() {
+            return;
+        }
+        for payment in payments.iter() {
+            self.remove_locked(&payment.token_identifier, &payment.amount);
+        }
+        self.tx().to(to).payment(payments).transfer();
+    }
+
+    /// Transfers part of an escrow's locked token to `to`.
+    fn send_escrowed(&self, escrow: &EscrowData<Self::Api>, to: &ManagedAddress, amount: &BigUint) {
+        self.remove_locked(&escrow.token_id, amount);
+        self.tx()
+            .to(to)
+            .egld_or_single_esdt(&escrow.token_id, escrow.token_nonce, amount)
+            .transfer();
+    }
+}
@@ -0,0 +1,99 @@
+multiversx_sc::imports!();
+
+use crate::storage::{EscrowData, EscrowStatus};
+
+pub const MAX_PAGE_SIZE: usize = 50;
+
+/// Enumeration views so wallets can list escrows without an indexer.
+/// Escrows created before the index existed are not listed.
+#[multiversx_sc::module]
+pub trait ViewsModule: crate::storage::StorageModule {
+    /// Escrows funded by `employer`, optionally only those in `status`.
+    /// `from` is zero-based over the matching escrows; `size` is capped at
+    /// `MAX_PAGE_SIZE`.
+    #[view(get_employer_escrows)]
+    fn get_employer_escrows(
+        &self,
+        employer: ManagedAddress,
+        from: usize,
+        size: usize,
+        status: OptionalValue<EscrowStatus>,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, EscrowData<Self::Api>>> {
+        self.page_of(
+            self.employer_escrows(&employer),
+            from,
+            size,
+            status.into_option(),
+        )
+    }
+
+    /// Escrows paying `receiver`, filtered and paginated like `get_employer_escrows`.
+    #[view(get_receiver_escrows)]
+    fn get_receiver_escrows(
+        &self,
+        receiver: ManagedAddress,
+        from: usize,
+        size: usize,
+        status: OptionalValue<EscrowStatus>,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, EscrowData<Self::Api>>> {
+        self.page_of(
+            self.receiver_escrows(&receiver),
+            from,
+            size,
+            status.into_option(),
+        )
+    }
+
+    #[view(get_employer_escrow_count)]
+    fn get_employer_escrow_count(&self, employer: ManagedAddress) -> usize {
+        self.employer_escrows(&employer).len()
+    }
+
+    #[view(get_receiver_escrow_count)]
+    fn get_receiver_escrow_count(&self, receiver: ManagedAddress) -> usize {
+        self.receiver_escrows(&receiver).len()
+    }
+
+    /// `(token_id, amount)` for every token currently held in escrow.
+    #[view(get_all_total_locked)]
+    fn get_all_total_locked(
+        &self,
+    ) -> MultiValueEncoded<MultiValue2<EgldOrEsdtTokenIdentifier, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for token_id in self.locked_tokens().iter() {
+            let total = self.total_locked(&token_id).get();
+            result.push((token_id, total).into());
+        }
+        result
+    }
+
+    fn page_of(
+        &self,
+        job_ids: UnorderedSetMapper<ManagedBuffer>,
+        from: usize,
+        size: usize,
+        status: Option<EscrowStatus>,
+    ) -> MultiValueEncoded<MultiValue2<ManagedBuffer, EscrowData<Self::Api>>> {
+        let size = core::cmp::min(size, MAX_PAGE_SIZE);
+        let mut result = MultiValueEncoded::new();
+        let mut skipped = 0;
+        for job_id in job_ids.iter() {
+            if result.len() == size {
+                break;
+            }
+            let escrow = self.escrow_data(&job_id).get();
+            if status
+                .as_ref()
+                .is_some_and(|status| status != &escrow.status)
+            {
+                continue;
+            }
+            if skipped < from {
+                skipped += 1;
+                continue;
+            }
+            result.push((job_id, escrow).into());
+        }
+        result
+    }
+}
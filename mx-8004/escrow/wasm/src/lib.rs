@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                            6
-// Async Callback (empty):               1
-// Total number of exported functions:   9
+// Endpoints:                           62
+// Async Callback:                       1
+// Total number of exported functions:  65
 
 #![no_std]
 
@@ -21,12 +21,68 @@ multiversx_sc_wasm_adapter::endpoints! {
         init => init
         upgrade => upgrade
         deposit => deposit
+        deposit_for_agent => deposit_for_agent
+        deposit_for_job => deposit_for_job
         release => release
         refund => refund
+        get_synced_agent => synced_agent
         get_escrow => escrow_data
+        get_total_locked => total_locked
+        get_milestones => escrow_milestones
+        get_stream => escrow_stream
+        get_dispute => escrow_dispute
+        get_arbiters => arbiters
+        get_challenge_window => challenge_window
+        get_fee_bps => fee_bps
+        get_treasury => treasury
+        get_collected_fees => collected_fees
+        get_fee_tokens => fee_tokens
         get_validation_contract_address => validation_contract_address
         get_identity_contract_address => identity_contract_address
+        get_synced_job => synced_job
+        deposit_milestones => deposit_milestones
+        release_milestone => release_milestone
+        refund_milestone => refund_milestone
+        add_arbiter => add_arbiter
+        remove_arbiter => remove_arbiter
+        open_dispute => open_dispute
+        resolve_dispute => resolve_dispute
+        set_challenge_window => set_challenge_window
+        set_treasury => set_treasury
+        set_fee_bps => set_fee_bps
+        get_all_collected_fees => get_all_collected_fees
+        top_up => top_up
+        extend_deadline => extend_deadline
+        mutual_cancel => mutual_cancel
+        get_cancel_request => get_cancel_request
+        get_employer_escrows => get_employer_escrows
+        get_receiver_escrows => get_receiver_escrows
+        get_employer_escrow_count => get_employer_escrow_count
+        get_receiver_escrow_count => get_receiver_escrow_count
+        get_all_total_locked => get_all_total_locked
+        release_batch => release_batch
+        refund_batch => refund_batch
+        deposit_stream => deposit_stream
+        withdraw_vested => withdraw_vested
+        stop_stream => stop_stream
+        get_vested_amount => get_vested_amount
+        migrate_escrows => migrate_escrows
+        sync_agent => sync_agent
+        sync_job => sync_job
+        grant_role => grant_role
+        revoke_role => revoke_role
+        transfer_ownership => transfer_ownership
+        accept_ownership => accept_ownership
+        get_owner => current_owner
+        get_pending_owner => get_pending_owner
+        has_role => has_role
+        get_role_members => role_members
+        pause => pause
+        unpause => unpause
+        is_paused => paused
+        get_storage_version => storage_version
+        get_error_codes => get_error_codes
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { escrow }
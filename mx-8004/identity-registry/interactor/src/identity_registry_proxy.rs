@@ -62,6 +62,8 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
+    /// Brings storage up to `STORAGE_VERSION`. Agent records kept their 
+    /// layout, so there is nothing to rewrite. 
     pub fn upgrade(
         self,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
@@ -97,6 +99,7 @@ where
     }
 
     /// Register a new agent with name, URI, public key, optional metadata, and optional service configs. 
+    /// The call must pay the registration fee plus bond, if any are set. 
     pub fn register_agent<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -110,9 +113,8 @@ where
         public_key: Arg2,
         metadata: Arg3,
         services: Arg4,
-    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
-            .payment(NotPayable)
             .raw_call("register_agent")
             .argument(&name)
             .argument(&uri)
@@ -147,6 +149,16 @@ where
             .original_result()
     }
 
+    /// Deregister an agent by sending its NFT in. The NFT is burned, the 
+    /// agent's data removed and its bond, unless slashed, returned. 
+    pub fn deregister_agent(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deregister_agent")
+            .original_result()
+    }
+
     /// Set or update metadata entries for an agent. O(1) per entry via MapMapper. 
     pub fn set_metadata<
         Arg0: ProxyArg<u64>,
@@ -215,6 +227,25 @@ where
             .original_result()
     }
 
+    /// Make services lock their payment in escrow: `init_job` then deposits 
+    /// it with a deadline `duration` seconds out instead of paying the agent 
+    /// owner. A zero duration switches the service back to direct payment. 
+    pub fn set_service_escrow_durations<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<u32, DurationSeconds>>>,
+    >(
+        self,
+        nonce: Arg0,
+        durations: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_service_escrow_durations")
+            .argument(&nonce)
+            .argument(&durations)
+            .original_result()
+    }
+
     pub fn agent_token_id(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenIdentifier<Env::Api>> {
@@ -272,6 +303,16 @@ where
             .original_result()
     }
 
+    /// Receives registration fees and slashed bonds. 
+    pub fn treasury(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_treasury")
+            .original_result()
+    }
+
     pub fn get_agent<
         Arg0: ProxyArg<u64>,
     >(
@@ -298,6 +339,21 @@ where
             .original_result()
     }
 
+    /// The agent's owner, or `None` if it is not registered, stamped with the 
+    /// time of the read; read by `sync_agent` on contracts on other shards. 
+    pub fn get_agent_snapshot<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common::structs::SyncedAgent<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_snapshot")
+            .argument(&nonce)
+            .original_result()
+    }
+
     pub fn get_metadata<
         Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -329,4 +385,271 @@ where
             .argument(&service_id)
             .original_result()
     }
+
+    /// How long `init_job` locks this service's payment in escrow, if it does. 
+    pub fn get_service_escrow_duration<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        nonce: Arg0,
+        service_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<DurationSeconds>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_service_escrow_duration")
+            .argument(&nonce)
+            .argument(&service_id)
+            .original_result()
+    }
+
+    /// Fee `register_agent` sends to the treasury, if one is set. 
+    pub fn get_registration_fee(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<EgldOrEsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_registration_fee")
+            .original_result()
+    }
+
+    /// Bond `register_agent` locks until deregistration, if one is set. 
+    pub fn get_registration_bond(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<EgldOrEsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_registration_bond")
+            .original_result()
+    }
+
+    /// Bond currently held for an agent. 
+    pub fn get_agent_bond<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<EgldOrEsdtTokenPayment<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_agent_bond")
+            .argument(&nonce)
+            .original_result()
+    }
+
+    /// Address that receives registration fees and slashed bonds. 
+    pub fn set_treasury<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        treasury: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_treasury")
+            .argument(&treasury)
+            .original_result()
+    }
+
+    /// Fee charged per registration. Zero removes it. 
+    pub fn set_registration_fee<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_registration_fee")
+            .argument(&token_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Bond locked per registration and returned on deregistration. Zero 
+    /// removes it; bonds already held are unaffected. 
+    pub fn set_registration_bond<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_registration_bond")
+            .argument(&token_id)
+            .argument(&amount)
+            .original_result()
+    }
+
+    /// Sends an agent's bond to the treasury, e.g. for a spam registration. 
+    /// The agent stays registered but gets nothing back when it deregisters. 
+    pub fn slash_bond<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("slash_bond")
+            .argument(&nonce)
+            .original_result()
+    }
+
+    pub fn grant_role<
+        Arg0: ProxyArg<common::roles::Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grant_role")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn revoke_role<
+        Arg0: ProxyArg<common::roles::Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revoke_role")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    /// First step of an ownership transfer. Calling it again replaces the 
+    /// pending owner. 
+    pub fn transfer_ownership<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_owner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("transfer_ownership")
+            .argument(&new_owner)
+            .original_result()
+    }
+
+    /// Second step: the pending owner takes over. The protocol owner must 
+    /// already have been changed to the caller. 
+    pub fn accept_ownership(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("accept_ownership")
+            .original_result()
+    }
+
+    pub fn current_owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_owner")
+            .original_result()
+    }
+
+    pub fn get_pending_owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_pending_owner")
+            .original_result()
+    }
+
+    /// Whether `address` may act as `role`: the owner and admins always can. 
+    pub fn has_role<
+        Arg0: ProxyArg<common::roles::Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("has_role")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn role_members<
+        Arg0: ProxyArg<common::roles::Role>,
+    >(
+        self,
+        role: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_role_members")
+            .argument(&role)
+            .original_result()
+    }
+
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_paused")
+            .original_result()
+    }
+
+    pub fn storage_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_storage_version")
+            .original_result()
+    }
+
+    /// Every `(code, description)` pair the MX-8004 contracts can fail with. 
+    pub fn get_error_codes(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, ManagedBuffer<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_error_codes")
+            .original_result()
+    }
 }
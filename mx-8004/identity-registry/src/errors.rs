@@ -1,7 +1,7 @@
-pub use common::errors::ERR_AGENT_NOT_FOUND;
 pub use common::errors::ERR_NOT_AGENT_OWNER as ERR_NOT_OWNER;
-
-pub const ERR_TOKEN_ALREADY_ISSUED: &str = "Token already issued";
-pub const ERR_TOKEN_NOT_ISSUED: &str = "Token not issued";
-pub const ERR_AGENT_ALREADY_REGISTERED: &str = "Agent already registered for this address";
-pub const ERR_INVALID_NFT: &str = "Invalid NFT sent";
+pub use common::errors::{
+    ERR_AGENT_ALREADY_REGISTERED, ERR_AGENT_NOT_FOUND, ERR_INVALID_NFT,
+    ERR_INVALID_REGISTRATION_TOKEN, ERR_INVALID_SERVICE_TOKEN, ERR_NO_BOND,
+    ERR_REGISTRATION_PAYMENT_MISMATCH, ERR_SERVICE_NOT_FOUND, ERR_TOKEN_ALREADY_ISSUED,
+    ERR_TOKEN_NOT_ISSUED, ERR_TREASURY_NOT_SET,
+};
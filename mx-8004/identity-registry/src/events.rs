@@ -20,4 +20,10 @@ pub trait EventsModule {
 
     #[event("serviceConfigsUpdated")]
     fn service_configs_updated_event(&self, #[indexed] nonce: u64);
+
+    #[event("agentDeregistered")]
+    fn agent_deregistered_event(&self, #[indexed] owner: &ManagedAddress, #[indexed] nonce: u64);
+
+    #[event("bondSlashed")]
+    fn bond_slashed_event(&self, #[indexed] nonce: u64, bond: &EgldOrEsdtTokenPayment);
 }
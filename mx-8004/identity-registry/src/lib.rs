@@ -5,6 +5,7 @@ multiversx_sc::derive_imports!();
 
 pub mod errors;
 pub mod events;
+pub mod registration;
 pub mod storage;
 pub mod structs;
 pub mod utils;
@@ -12,8 +13,12 @@ pub mod views;
 
 pub use structs::*;
 
+use common::roles::Role;
 use errors::*;
 
+/// Layout version written by this code; see `common::migration`.
+pub const STORAGE_VERSION: u32 = 1;
+
 #[multiversx_sc::contract]
 pub trait IdentityRegistry:
     multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
@@ -21,17 +26,28 @@ pub trait IdentityRegistry:
     + views::ViewsModule
     + events::EventsModule
     + utils::UtilsModule
+    + registration::RegistrationModule
+    + common::roles::RolesModule
+    + common::pause::PauseModule
+    + common::migration::StorageVersionModule
+    + common::errors::ErrorCatalogModule
 {
     #[init]
-    fn init(&self) {}
+    fn init(&self) {
+        self.storage_version().set(STORAGE_VERSION);
+    }
 
+    /// Brings storage up to `STORAGE_VERSION`. Agent records kept their
+    /// layout, so there is nothing to rewrite.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.set_storage_version(STORAGE_VERSION);
+    }
 
-    #[only_owner]
     #[payable("EGLD")]
     #[endpoint(issue_token)]
     fn issue_token(&self, token_display_name: ManagedBuffer, token_ticker: ManagedBuffer) {
+        self.require_role(Role::Admin);
         require!(self.agent_token_id().is_empty(), ERR_TOKEN_ALREADY_ISSUED);
         let issue_cost = self.call_value().egld().clone_value();
 
@@ -46,6 +62,8 @@ pub trait IdentityRegistry:
     }
 
     /// Register a new agent with name, URI, public key, optional metadata, and optional service configs.
+    /// The call must pay the registration fee plus bond, if any are set.
+    #[payable("*")]
     #[allow_multiple_var_args]
     #[endpoint(register_agent)]
     fn register_agent(
@@ -56,6 +74,7 @@ pub trait IdentityRegistry:
         metadata: MultiValueEncodedCounted<MetadataEntry<Self::Api>>,
         services: MultiValueEncodedCounted<ServiceConfigInput<Self::Api>>,
     ) {
+        self.require_not_paused();
         require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
 
         let caller = self.blockchain().get_caller();
@@ -63,6 +82,7 @@ pub trait IdentityRegistry:
             !self.agents().contains_value(&caller),
             ERR_AGENT_ALREADY_REGISTERED
         );
+        let bond = self.collect_registration_payment();
 
         let details = AgentDetails {
             name: name.clone(),
@@ -83,6 +103,9 @@ pub trait IdentityRegistry:
         // Store all data in storage mappers
         self.agents().insert(nonce, caller.clone());
         self.agent_details(nonce).set(&details);
+        if let Some(bond) = bond {
+            self.agent_bond(nonce).set(bond);
+        }
 
         // Store metadata if provided
         self.sync_metadata(nonce, metadata);
@@ -121,6 +144,7 @@ pub trait IdentityRegistry:
         metadata: OptionalValue<MultiValueEncodedCounted<MetadataEntry<Self::Api>>>,
         services: OptionalValue<MultiValueEncodedCounted<ServiceConfigInput<Self::Api>>>,
     ) {
+        self.require_not_paused();
         require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
 
         let payment = self.call_value().single_esdt();
@@ -158,6 +182,40 @@ pub trait IdentityRegistry:
         self.agent_updated_event(nonce);
     }
 
+    /// Deregister an agent by sending its NFT in. The NFT is burned, the
+    /// agent's data removed and its bond, unless slashed, returned.
+    #[payable("*")]
+    #[endpoint(deregister_agent)]
+    fn deregister_agent(&self) {
+        self.require_not_paused();
+        require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
+
+        let payment = self.call_value().single_esdt();
+        let token_id = self.agent_token_id().get_token_id();
+        require!(payment.token_identifier == token_id, ERR_INVALID_NFT);
+
+        let nonce = payment.token_nonce;
+        let caller = self.require_agent_owner(nonce);
+
+        self.send()
+            .esdt_local_burn(&token_id, nonce, &BigUint::from(1u64));
+        self.agents().remove_by_id(&nonce);
+        self.agent_details(nonce).clear();
+        self.agent_metadata(nonce).clear();
+        self.agent_service_config(nonce).clear();
+        self.agent_service_escrow_duration(nonce).clear();
+
+        if !self.agent_bond(nonce).is_empty() {
+            let bond = self.agent_bond(nonce).take();
+            self.tx()
+                .to(&caller)
+                .egld_or_single_esdt(&bond.token_identifier, bond.token_nonce, &bond.amount)
+                .transfer();
+        }
+
+        self.agent_deregistered_event(&caller, nonce);
+    }
+
     /// Set or update metadata entries for an agent. O(1) per entry via MapMapper.
     #[endpoint(set_metadata)]
     fn set_metadata(
@@ -165,6 +223,7 @@ pub trait IdentityRegistry:
         nonce: u64,
         entries: MultiValueEncodedCounted<MetadataEntry<Self::Api>>,
     ) {
+        self.require_not_paused();
         require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
         self.require_agent_owner(nonce);
         self.sync_metadata(nonce, entries);
@@ -178,6 +237,7 @@ pub trait IdentityRegistry:
         nonce: u64,
         configs: MultiValueEncodedCounted<ServiceConfigInput<Self::Api>>,
     ) {
+        self.require_not_paused();
         require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
         self.require_agent_owner(nonce);
         self.sync_service_configs(nonce, configs);
@@ -187,6 +247,7 @@ pub trait IdentityRegistry:
     /// Remove metadata entries by key.
     #[endpoint(remove_metadata)]
     fn remove_metadata(&self, nonce: u64, keys: MultiValueEncoded<ManagedBuffer>) {
+        self.require_not_paused();
         require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
         self.require_agent_owner(nonce);
         let mut mapper = self.agent_metadata(nonce);
@@ -199,11 +260,40 @@ pub trait IdentityRegistry:
     /// Remove service configurations by service ID.
     #[endpoint(remove_service_configs)]
     fn remove_service_configs(&self, nonce: u64, service_ids: MultiValueEncoded<u32>) {
+        self.require_not_paused();
         require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
         self.require_agent_owner(nonce);
         let mut mapper = self.agent_service_config(nonce);
+        let mut durations = self.agent_service_escrow_duration(nonce);
         for sid in service_ids {
             mapper.remove(&sid);
+            durations.remove(&sid);
+        }
+        self.service_configs_updated_event(nonce);
+    }
+
+    /// Make services lock their payment in escrow: `init_job` then deposits
+    /// it with a deadline `duration` seconds out instead of paying the agent
+    /// owner. A zero duration switches the service back to direct payment.
+    #[endpoint(set_service_escrow_durations)]
+    fn set_service_escrow_durations(
+        &self,
+        nonce: u64,
+        durations: MultiValueEncoded<MultiValue2<u32, DurationSeconds>>,
+    ) {
+        self.require_not_paused();
+        require!(!self.agent_token_id().is_empty(), ERR_TOKEN_NOT_ISSUED);
+        self.require_agent_owner(nonce);
+        let configs = self.agent_service_config(nonce);
+        let mut mapper = self.agent_service_escrow_duration(nonce);
+        for entry in durations {
+            let (service_id, duration) = entry.into_tuple();
+            if duration == DurationSeconds::zero() {
+                mapper.remove(&service_id);
+            } else {
+                require!(configs.contains_key(&service_id), ERR_SERVICE_NOT_FOUND);
+                mapper.insert(service_id, duration);
+            }
         }
         self.service_configs_updated_event(nonce);
     }
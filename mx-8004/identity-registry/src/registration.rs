@@ -0,0 +1,112 @@
+multiversx_sc::imports!();
+
+use crate::errors::{
+    ERR_INVALID_REGISTRATION_TOKEN, ERR_NO_BOND, ERR_REGISTRATION_PAYMENT_MISMATCH,
+    ERR_TREASURY_NOT_SET,
+};
+use common::roles::Role;
+
+/// Anti-spam pricing for `register_agent`: a fee sent to the treasury and a
+/// bond held until the agent deregisters. Either can be unset; changes only
+/// apply to registrations made afterwards.
+#[multiversx_sc::module]
+pub trait RegistrationModule:
+    crate::storage::StorageModule + crate::events::EventsModule + common::roles::RolesModule
+{
+    /// Address that receives registration fees and slashed bonds.
+    #[endpoint(set_treasury)]
+    fn set_treasury(&self, treasury: ManagedAddress) {
+        self.require_role(Role::ConfigManager);
+        self.treasury().set(&treasury);
+    }
+
+    /// Fee charged per registration. Zero removes it.
+    #[endpoint(set_registration_fee)]
+    fn set_registration_fee(&self, token_id: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        self.require_role(Role::ConfigManager);
+        if amount == 0u64 {
+            self.registration_fee().clear();
+            return;
+        }
+        require!(token_id.is_valid(), ERR_INVALID_REGISTRATION_TOKEN);
+        require!(!self.treasury().is_empty(), ERR_TREASURY_NOT_SET);
+        self.registration_fee()
+            .set(EgldOrEsdtTokenPayment::new(token_id, 0, amount));
+    }
+
+    /// Bond locked per registration and returned on deregistration. Zero
+    /// removes it; bonds already held are unaffected.
+    #[endpoint(set_registration_bond)]
+    fn set_registration_bond(&self, token_id: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        self.require_role(Role::ConfigManager);
+        if amount == 0u64 {
+            self.registration_bond().clear();
+            return;
+        }
+        require!(token_id.is_valid(), ERR_INVALID_REGISTRATION_TOKEN);
+        self.registration_bond()
+            .set(EgldOrEsdtTokenPayment::new(token_id, 0, amount));
+    }
+
+    /// Sends an agent's bond to the treasury, e.g. for a spam registration.
+    /// The agent stays registered but gets nothing back when it deregisters.
+    #[endpoint(slash_bond)]
+    fn slash_bond(&self, nonce: u64) {
+        self.require_role(Role::Admin);
+        require!(!self.agent_bond(nonce).is_empty(), ERR_NO_BOND);
+        require!(!self.treasury().is_empty(), ERR_TREASURY_NOT_SET);
+        let bond = self.agent_bond(nonce).take();
+        self.tx()
+            .to(self.treasury().get())
+            .egld_or_single_esdt(&bond.token_identifier, bond.token_nonce, &bond.amount)
+            .transfer();
+        self.bond_slashed_event(nonce, &bond);
+    }
+
+    /// Checks the call pays exactly the current fee plus bond (merged when
+    /// both use the same token), forwards the fee to the treasury and
+    /// returns the bond to hold for the new agent.
+    fn collect_registration_payment(&self) -> Option<EgldOrEsdtTokenPayment> {
+        let fee = (!self.registration_fee().is_empty()).then(|| self.registration_fee().get());
+        let bond = (!self.registration_bond().is_empty()).then(|| self.registration_bond().get());
+
+        let mut required = ManagedVec::<Self::Api, EgldOrEsdtTokenPayment>::new();
+        for part in [&fee, &bond].into_iter().flatten() {
+            match required
+                .iter()
+                .position(|r| r.token_identifier == part.token_identifier)
+            {
+                Some(index) => {
+                    let mut merged = required.get(index).clone();
+                    merged.amount += &part.amount;
+                    let _ = required.set(index, merged);
+                }
+                None => required.push(part.clone()),
+            }
+        }
+
+        let payments = self.call_value().all_transfers();
+        require!(
+            payments.len() == required.len(),
+            ERR_REGISTRATION_PAYMENT_MISMATCH
+        );
+        for expected in required.iter() {
+            require!(
+                payments
+                    .iter()
+                    .any(|p| p.token_identifier == expected.token_identifier
+                        && p.token_nonce == 0
+                        && p.amount == expected.amount),
+                ERR_REGISTRATION_PAYMENT_MISMATCH
+            );
+        }
+
+        if let Some(fee) = fee {
+            self.tx()
+                .to(self.treasury().get())
+                .egld_or_single_esdt(&fee.token_identifier, 0, &fee.amount)
+                .transfer();
+        }
+        bond
+    }
+}
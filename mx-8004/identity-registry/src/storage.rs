@@ -24,4 +24,24 @@ pub trait StorageModule {
     #[view(get_agent_service)]
     #[storage_mapper("agentServiceConfigs")]
     fn agent_service_config(&self, nonce: u64) -> MapMapper<u32, Payment<Self::Api>>;
+
+    /// Services listed here have `init_job` lock their payment in escrow for
+    /// this long instead of paying the agent owner directly.
+    #[storage_mapper("agentServiceEscrowDurations")]
+    fn agent_service_escrow_duration(&self, nonce: u64) -> MapMapper<u32, DurationSeconds>;
+
+    /// Receives registration fees and slashed bonds.
+    #[view(get_treasury)]
+    #[storage_mapper("treasury")]
+    fn treasury(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("registrationFee")]
+    fn registration_fee(&self) -> SingleValueMapper<EgldOrEsdtTokenPayment<Self::Api>>;
+
+    #[storage_mapper("registrationBond")]
+    fn registration_bond(&self) -> SingleValueMapper<EgldOrEsdtTokenPayment<Self::Api>>;
+
+    /// Bond paid at registration, held until the agent deregisters.
+    #[storage_mapper("agentBond")]
+    fn agent_bond(&self, nonce: u64) -> SingleValueMapper<EgldOrEsdtTokenPayment<Self::Api>>;
 }
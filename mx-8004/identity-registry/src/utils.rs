@@ -28,6 +28,13 @@ pub trait UtilsModule: crate::storage::StorageModule {
         configs: MultiValueEncodedCounted<ServiceConfigInput<Self::Api>>,
     ) {
         for config in configs {
+            // EGLD has no nonce; `TokenId` decodes the legacy "EGLD" to EGLD-000000
+            let valid_token = if config.token.is_native() {
+                config.nonce == 0
+            } else {
+                config.token.is_valid()
+            };
+            require!(valid_token, ERR_INVALID_SERVICE_TOKEN);
             let amount = NonZeroBigUint::new_or_panic(config.price);
             let payment = Payment::new(config.token, config.nonce, amount);
             self.agent_service_config(nonce)
@@ -2,6 +2,7 @@ multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
 use crate::structs::AgentDetails;
+use common::structs::SyncedAgent;
 
 #[multiversx_sc::module]
 pub trait ViewsModule: crate::storage::StorageModule {
@@ -15,6 +16,17 @@ pub trait ViewsModule: crate::storage::StorageModule {
         self.agents().get_value(&nonce)
     }
 
+    /// The agent's owner, or `None` if it is not registered, stamped with the
+    /// time of the read; read by `sync_agent` on contracts on other shards.
+    #[view(get_agent_snapshot)]
+    fn get_agent_snapshot(&self, nonce: u64) -> SyncedAgent<Self::Api> {
+        let agents = self.agents();
+        SyncedAgent {
+            owner: agents.contains_id(&nonce).then(|| agents.get_value(&nonce)),
+            synced_at: self.blockchain().get_block_timestamp_seconds(),
+        }
+    }
+
     #[view(get_metadata)]
     fn get_metadata(&self, nonce: u64, key: ManagedBuffer) -> OptionalValue<ManagedBuffer> {
         let mapper = self.agent_metadata(nonce);
@@ -42,4 +54,37 @@ pub trait ViewsModule: crate::storage::StorageModule {
             OptionalValue::None
         }
     }
+
+    /// How long `init_job` locks this service's payment in escrow, if it does.
+    #[view(get_service_escrow_duration)]
+    fn get_service_escrow_duration(
+        &self,
+        nonce: u64,
+        service_id: u32,
+    ) -> OptionalValue<DurationSeconds> {
+        self.agent_service_escrow_duration(nonce)
+            .get(&service_id)
+            .into()
+    }
+
+    /// Fee `register_agent` sends to the treasury, if one is set.
+    #[view(get_registration_fee)]
+    fn get_registration_fee(&self) -> OptionalValue<EgldOrEsdtTokenPayment> {
+        let mapper = self.registration_fee();
+        (!mapper.is_empty()).then(|| mapper.get()).into()
+    }
+
+    /// Bond `register_agent` locks until deregistration, if one is set.
+    #[view(get_registration_bond)]
+    fn get_registration_bond(&self) -> OptionalValue<EgldOrEsdtTokenPayment> {
+        let mapper = self.registration_bond();
+        (!mapper.is_empty()).then(|| mapper.get()).into()
+    }
+
+    /// Bond currently held for an agent.
+    #[view(get_agent_bond)]
+    fn get_agent_bond(&self, nonce: u64) -> OptionalValue<EgldOrEsdtTokenPayment> {
+        let mapper = self.agent_bond(nonce);
+        (!mapper.is_empty()).then(|| mapper.get()).into()
+    }
 }
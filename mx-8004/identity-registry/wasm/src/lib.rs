@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           16
+// Endpoints:                           41
 // Async Callback:                       1
-// Total number of exported functions:  19
+// Total number of exported functions:  44
 
 #![no_std]
 
@@ -23,19 +23,44 @@ multiversx_sc_wasm_adapter::endpoints! {
         issue_token => issue_token
         register_agent => register_agent
         update_agent => update_agent
+        deregister_agent => deregister_agent
         set_metadata => set_metadata
         set_service_configs => set_service_configs_endpoint
         remove_metadata => remove_metadata
         remove_service_configs => remove_service_configs
+        set_service_escrow_durations => set_service_escrow_durations
         get_agent_token_id => agent_token_id
         get_agent_id => agents
         get_agent_details => agent_details
         get_agent_metadata => agent_metadata
         get_agent_service => agent_service_config
+        get_treasury => treasury
         get_agent => get_agent
         get_agent_owner => get_agent_owner
+        get_agent_snapshot => get_agent_snapshot
         get_metadata => get_metadata
         get_agent_service_config => get_agent_service_config
+        get_service_escrow_duration => get_service_escrow_duration
+        get_registration_fee => get_registration_fee
+        get_registration_bond => get_registration_bond
+        get_agent_bond => get_agent_bond
+        set_treasury => set_treasury
+        set_registration_fee => set_registration_fee
+        set_registration_bond => set_registration_bond
+        slash_bond => slash_bond
+        grant_role => grant_role
+        revoke_role => revoke_role
+        transfer_ownership => transfer_ownership
+        accept_ownership => accept_ownership
+        get_owner => current_owner
+        get_pending_owner => get_pending_owner
+        has_role => has_role
+        get_role_members => role_members
+        pause => pause
+        unpause => unpause
+        is_paused => paused
+        get_storage_version => storage_version
+        get_error_codes => get_error_codes
     )
 }
 
@@ -0,0 +1,16 @@
+pub use common::errors::ErrorCode;
+
+/// Maps a failed transaction's message back to the catalog entry it was
+/// raised with. The code is looked up wherever it appears, so messages the
+/// gateway or simulator wraps in extra context still decode. Returns `None`
+/// for failures that did not come from an MX-8004 error (out of gas, VM
+/// errors, ...).
+pub fn decode_error(message: &str) -> Option<ErrorCode> {
+    message.match_indices('E').find_map(|(start, _)| {
+        let (digits, _) = message[start + 1..].split_once(": ")?;
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        ErrorCode::from_code(digits.parse().ok()?)
+    })
+}
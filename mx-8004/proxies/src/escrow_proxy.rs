@@ -69,12 +69,18 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
-    pub fn upgrade(
+    /// Brings storage up to `STORAGE_VERSION`. The escrows of `job_ids` are
+    /// migrated right away; larger sets go through `migrate_escrows` in batches.
+    pub fn upgrade<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
         self,
+        job_ids: Arg0,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_upgrade()
+            .argument(&job_ids)
             .original_result()
     }
 }
@@ -93,12 +99,14 @@ where
         Arg1: ProxyArg<ManagedAddress<Env::Api>>,
         Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
     >(
         self,
         job_id: Arg0,
         receiver: Arg1,
         poa_hash: Arg2,
         deadline: Arg3,
+        arbiter: Arg4,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("deposit")
@@ -106,6 +114,52 @@ where
             .argument(&receiver)
             .argument(&poa_hash)
             .argument(&deadline)
+            .argument(&arbiter)
+            .original_result()
+    }
+
+    pub fn deposit_for_agent<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        job_id: Arg0,
+        agent_nonce: Arg1,
+        poa_hash: Arg2,
+        deadline: Arg3,
+        arbiter: Arg4,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit_for_agent")
+            .argument(&job_id)
+            .argument(&agent_nonce)
+            .argument(&poa_hash)
+            .argument(&deadline)
+            .argument(&arbiter)
+            .original_result()
+    }
+
+    pub fn deposit_for_job<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<TimestampSeconds>,
+    >(
+        self,
+        job_id: Arg0,
+        agent_nonce: Arg1,
+        employer: Arg2,
+        deadline: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit_for_job")
+            .argument(&job_id)
+            .argument(&agent_nonce)
+            .argument(&employer)
+            .argument(&deadline)
             .original_result()
     }
 
@@ -135,6 +189,718 @@ where
             .original_result()
     }
 
+    pub fn deposit_milestones<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<MultiValueEncoded<Env::Api, MultiValue3<BigUint<Env::Api>, TimestampSeconds, ManagedBuffer<Env::Api>>>>,
+    >(
+        self,
+        job_id: Arg0,
+        receiver: Arg1,
+        poa_hash: Arg2,
+        milestones: Arg3,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit_milestones")
+            .argument(&job_id)
+            .argument(&receiver)
+            .argument(&poa_hash)
+            .argument(&milestones)
+            .original_result()
+    }
+
+    pub fn release_milestone<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        job_id: Arg0,
+        milestone_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("release_milestone")
+            .argument(&job_id)
+            .argument(&milestone_index)
+            .original_result()
+    }
+
+    pub fn refund_milestone<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        job_id: Arg0,
+        milestone_index: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refund_milestone")
+            .argument(&job_id)
+            .argument(&milestone_index)
+            .original_result()
+    }
+
+    pub fn get_milestones<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, escrow::storage::Milestone<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_milestones")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn add_arbiter<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        arbiter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("add_arbiter")
+            .argument(&arbiter)
+            .original_result()
+    }
+
+    pub fn remove_arbiter<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        arbiter: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("remove_arbiter")
+            .argument(&arbiter)
+            .original_result()
+    }
+
+    pub fn open_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+        reason_uri: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("open_dispute")
+            .argument(&job_id)
+            .argument(&reason_uri)
+            .original_result()
+    }
+
+    pub fn resolve_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+        receiver_amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("resolve_dispute")
+            .argument(&job_id)
+            .argument(&receiver_amount)
+            .original_result()
+    }
+
+    pub fn get_stream<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, escrow::storage::Stream> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_stream")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, escrow::storage::EscrowDispute<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_dispute")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_arbiters(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_arbiters")
+            .original_result()
+    }
+
+    pub fn set_challenge_window<
+        Arg0: ProxyArg<DurationSeconds>,
+    >(
+        self,
+        window: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_challenge_window")
+            .argument(&window)
+            .original_result()
+    }
+
+    pub fn get_challenge_window(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_challenge_window")
+            .original_result()
+    }
+
+    pub fn set_treasury<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        treasury: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_treasury")
+            .argument(&treasury)
+            .original_result()
+    }
+
+    pub fn set_fee_bps<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        token_id: Arg0,
+        fee_bps: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_fee_bps")
+            .argument(&token_id)
+            .argument(&fee_bps)
+            .original_result()
+    }
+
+    pub fn get_fee_bps<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_fee_bps")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn get_treasury(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_treasury")
+            .original_result()
+    }
+
+    pub fn get_collected_fees<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_collected_fees")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn get_fee_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, EgldOrEsdtTokenIdentifier<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_fee_tokens")
+            .original_result()
+    }
+
+    pub fn get_all_collected_fees(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_all_collected_fees")
+            .original_result()
+    }
+
+    pub fn top_up<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("top_up")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn extend_deadline<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<TimestampSeconds>,
+    >(
+        self,
+        job_id: Arg0,
+        new_deadline: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("extend_deadline")
+            .argument(&job_id)
+            .argument(&new_deadline)
+            .original_result()
+    }
+
+    pub fn mutual_cancel<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("mutual_cancel")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_cancel_request<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_cancel_request")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_employer_escrows<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<OptionalValue<escrow::storage::EscrowStatus>>,
+    >(
+        self,
+        employer: Arg0,
+        from: Arg1,
+        size: Arg2,
+        status: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedBuffer<Env::Api>, escrow::storage::EscrowData<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_employer_escrows")
+            .argument(&employer)
+            .argument(&from)
+            .argument(&size)
+            .argument(&status)
+            .original_result()
+    }
+
+    pub fn get_receiver_escrows<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<usize>,
+        Arg2: ProxyArg<usize>,
+        Arg3: ProxyArg<OptionalValue<escrow::storage::EscrowStatus>>,
+    >(
+        self,
+        receiver: Arg0,
+        from: Arg1,
+        size: Arg2,
+        status: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<ManagedBuffer<Env::Api>, escrow::storage::EscrowData<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_receiver_escrows")
+            .argument(&receiver)
+            .argument(&from)
+            .argument(&size)
+            .argument(&status)
+            .original_result()
+    }
+
+    pub fn get_employer_escrow_count<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        employer: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_employer_escrow_count")
+            .argument(&employer)
+            .original_result()
+    }
+
+    pub fn get_receiver_escrow_count<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        receiver: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_receiver_escrow_count")
+            .argument(&receiver)
+            .original_result()
+    }
+
+    pub fn get_total_locked<
+        Arg0: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+    >(
+        self,
+        token_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_total_locked")
+            .argument(&token_id)
+            .original_result()
+    }
+
+    pub fn get_all_total_locked(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<EgldOrEsdtTokenIdentifier<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_all_total_locked")
+            .original_result()
+    }
+
+    pub fn release_batch<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        job_ids: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<ManagedBuffer<Env::Api>, escrow::storage::BatchOutcome, ManagedBuffer<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("release_batch")
+            .argument(&job_ids)
+            .original_result()
+    }
+
+    pub fn refund_batch<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        job_ids: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue3<ManagedBuffer<Env::Api>, escrow::storage::BatchOutcome, ManagedBuffer<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refund_batch")
+            .argument(&job_ids)
+            .original_result()
+    }
+
+    pub fn deposit_stream<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg3: ProxyArg<TimestampSeconds>,
+        Arg4: ProxyArg<TimestampSeconds>,
+        Arg5: ProxyArg<OptionalValue<ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        job_id: Arg0,
+        receiver: Arg1,
+        poa_hash: Arg2,
+        start: Arg3,
+        end: Arg4,
+        arbiter: Arg5,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("deposit_stream")
+            .argument(&job_id)
+            .argument(&receiver)
+            .argument(&poa_hash)
+            .argument(&start)
+            .argument(&end)
+            .argument(&arbiter)
+            .original_result()
+    }
+
+    pub fn withdraw_vested<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdraw_vested")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn stop_stream<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("stop_stream")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_vested_amount<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValue2<BigUint<Env::Api>, BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_vested_amount")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn migrate_escrows<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        job_ids: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("migrate_escrows")
+            .argument(&job_ids)
+            .original_result()
+    }
+
+    pub fn sync_agent<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("sync_agent")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    pub fn sync_job<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("sync_job")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_synced_job<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common::structs::SyncedJob<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_synced_job")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_synced_agent<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common::structs::SyncedAgent<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_synced_agent")
+            .argument(&nonce)
+            .original_result()
+    }
+
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn is_paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_paused")
+            .original_result()
+    }
+
+    pub fn get_storage_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_storage_version")
+            .original_result()
+    }
+
+    pub fn get_error_codes(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, ManagedBuffer<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_error_codes")
+            .original_result()
+    }
+
+    pub fn grant_role<
+        Arg0: ProxyArg<common::roles::Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grant_role")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn revoke_role<
+        Arg0: ProxyArg<common::roles::Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revoke_role")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn transfer_ownership<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_owner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("transfer_ownership")
+            .argument(&new_owner)
+            .original_result()
+    }
+
+    pub fn accept_ownership(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("accept_ownership")
+            .original_result()
+    }
+
+    pub fn get_owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_owner")
+            .original_result()
+    }
+
+    pub fn get_pending_owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_pending_owner")
+            .original_result()
+    }
+
+    pub fn has_role<
+        Arg0: ProxyArg<common::roles::Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("has_role")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn get_role_members<
+        Arg0: ProxyArg<common::roles::Role>,
+    >(
+        self,
+        role: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_role_members")
+            .argument(&role)
+            .original_result()
+    }
+
     pub fn get_escrow<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
@@ -1,5 +1,6 @@
 #![no_std]
 
+pub mod errors;
 pub mod escrow_proxy;
 pub mod identity_registry_proxy;
 pub mod reputation_registry_proxy;
@@ -69,6 +69,8 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
+    /// Brings storage up to `STORAGE_VERSION`. Feedback records kept their 
+    /// layout, so only contract-level state needs updating. 
     pub fn upgrade(
         self,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
@@ -89,7 +91,9 @@ where
     Gas: TxGas<Env>,
 {
     /// Simple feedback for a job. Caller must be the employer who created the job. 
-    /// Computes a cumulative moving average on-chain. 
+    /// Keeps the average rating on-chain, both overall and for the 
+    /// service the job was created for (if any), and re-ranks the agent on the 
+    /// matching leaderboards. 
     pub fn give_feedback_simple<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<u64>,
@@ -111,6 +115,8 @@ where
 
     /// ERC-8004: Anyone can give feedback (except the agent owner). 
     /// Stores raw signals — no on-chain scoring. Off-chain aggregation expected. 
+    /// An agent-signed `feedback_auth` is checked when given, and required when 
+    /// `feedbackAuthRequired` is set. 
     pub fn give_feedback<
         Arg0: ProxyArg<u64>,
         Arg1: ProxyArg<i64>,
@@ -120,6 +126,7 @@ where
         Arg5: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg6: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg7: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg8: ProxyArg<OptionalValue<FeedbackAuth<Env::Api>>>,
     >(
         self,
         agent_nonce: Arg0,
@@ -130,6 +137,7 @@ where
         endpoint: Arg5,
         feedback_uri: Arg6,
         feedback_hash: Arg7,
+        feedback_auth: Arg8,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
@@ -142,6 +150,7 @@ where
             .argument(&endpoint)
             .argument(&feedback_uri)
             .argument(&feedback_hash)
+            .argument(&feedback_auth)
             .original_result()
     }
 
@@ -181,8 +190,36 @@ where
             .original_result()
     }
 
-    /// ERC-8004: Anyone can append a response to feedback (e.g., agent showing refund, 
-    /// data aggregator tagging feedback as spam). 
+    /// ERC-8004: Anyone can append a response to a feedback entry (e.g., agent showing 
+    /// refund, data aggregator tagging feedback as spam). Responses are append-only and 
+    /// record who wrote them. 
+    pub fn append_feedback_response<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg4: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        client_address: Arg1,
+        feedback_index: Arg2,
+        response_uri: Arg3,
+        response_hash: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("appendResponse")
+            .argument(&agent_nonce)
+            .argument(&client_address)
+            .argument(&feedback_index)
+            .argument(&response_uri)
+            .argument(&response_hash)
+            .original_result()
+    }
+
+    /// Legacy job-keyed response slot; superseded by `appendResponse`. 
+    /// Anyone can set it and later calls overwrite earlier ones. 
     pub fn append_response<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -199,6 +236,36 @@ where
             .original_result()
     }
 
+    /// Clears the per-job feedback records of jobs the validation registry 
+    /// has pruned, so a reused job id starts clean. Jobs with a pending 
+    /// feedback dispute are kept until a moderator decides. Scores are not 
+    /// affected. Anyone can call this. 
+    pub fn clean_job_feedback<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        job_ids: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("clean_job_feedback")
+            .argument(&job_ids)
+            .original_result()
+    }
+
+    pub fn synced_agent<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common::structs::SyncedAgent<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_synced_agent")
+            .argument(&nonce)
+            .original_result()
+    }
+
     pub fn reputation_score<
         Arg0: ProxyArg<u64>,
     >(
@@ -225,6 +292,69 @@ where
             .original_result()
     }
 
+    pub fn service_reputation_score<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        service_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_service_reputation_score")
+            .argument(&agent_nonce)
+            .argument(&service_id)
+            .original_result()
+    }
+
+    pub fn service_total_jobs<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        service_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_service_total_jobs")
+            .argument(&agent_nonce)
+            .argument(&service_id)
+            .original_result()
+    }
+
+    pub fn rated_services<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, u32>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_rated_services")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    pub fn leaderboard_size(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_leaderboard_size")
+            .original_result()
+    }
+
+    pub fn leaderboard_min_jobs(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_leaderboard_min_jobs")
+            .original_result()
+    }
+
     pub fn validation_contract_address(
         self,
     ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
@@ -256,6 +386,51 @@ where
             .original_result()
     }
 
+    pub fn job_feedback<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, JobFeedback<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_feedback")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn feedback_dispute<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, FeedbackDispute<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_feedback_dispute")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn client_feedback_dispute<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        client: Arg1,
+        index: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, FeedbackDispute<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_client_feedback_dispute")
+            .argument(&agent_nonce)
+            .argument(&client)
+            .argument(&index)
+            .original_result()
+    }
+
     pub fn agent_response<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
@@ -298,6 +473,15 @@ where
             .original_result()
     }
 
+    pub fn feedback_auth_required(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_feedback_auth_required")
+            .original_result()
+    }
+
     pub fn set_identity_contract_address<
         Arg0: ProxyArg<ManagedAddress<Env::Api>>,
     >(
@@ -323,6 +507,427 @@ where
             .argument(&address)
             .original_result()
     }
+
+    /// When enabled, `giveFeedback` rejects calls without a valid `feedback_auth`. 
+    pub fn set_feedback_auth_required<
+        Arg0: ProxyArg<bool>,
+    >(
+        self,
+        required: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_feedback_auth_required")
+            .argument(&required)
+            .original_result()
+    }
+
+    /// Number of agents kept on each leaderboard and the job count an agent 
+    /// needs before it is ranked. Takes effect on the next feedback per board; 
+    /// views apply it immediately. 
+    pub fn set_leaderboard_config<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        size: Arg0,
+        min_jobs: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_leaderboard_config")
+            .argument(&size)
+            .argument(&min_jobs)
+            .original_result()
+    }
+
+    /// Per-service reputation for an agent. Services that never received 
+    /// feedback return a zero score and zero jobs. 
+    pub fn get_service_reputation<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        service_id: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ServiceReputation<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_service_reputation")
+            .argument(&agent_nonce)
+            .argument(&service_id)
+            .original_result()
+    }
+
+    /// All rated services of an agent, best score first. 
+    /// Ties are broken by job count (more jobs first). 
+    pub fn get_ranked_services<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, ServiceReputation<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_ranked_services")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    /// Best `count` agents by overall score (capped at `leaderboardSize`). 
+    pub fn get_top_agents<
+        Arg0: ProxyArg<u32>,
+    >(
+        self,
+        count: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, LeaderboardEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_top_agents")
+            .argument(&count)
+            .original_result()
+    }
+
+    /// Best `count` agents by score for a single service id. 
+    pub fn get_top_agents_for_service<
+        Arg0: ProxyArg<u32>,
+        Arg1: ProxyArg<u32>,
+    >(
+        self,
+        service_id: Arg0,
+        count: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedVec<Env::Api, LeaderboardEntry<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_top_agents_for_service")
+            .argument(&service_id)
+            .argument(&count)
+            .original_result()
+    }
+
+    /// ERC-8004: Number of responses appended to a feedback entry. 
+    pub fn get_response_count<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        client_address: Arg1,
+        feedback_index: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getResponseCount")
+            .argument(&agent_nonce)
+            .argument(&client_address)
+            .argument(&feedback_index)
+            .original_result()
+    }
+
+    /// ERC-8004: Page of responses for a feedback entry, oldest first. 
+    /// `from` is a zero-based offset; at most `size` entries are returned. 
+    pub fn read_responses<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        client_address: Arg1,
+        feedback_index: Arg2,
+        from: Arg3,
+        size: Arg4,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, FeedbackResponse<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("readResponses")
+            .argument(&agent_nonce)
+            .argument(&client_address)
+            .argument(&feedback_index)
+            .argument(&from)
+            .argument(&size)
+            .original_result()
+    }
+
+    /// Agent owner disputes the feedback left on one of the agent's jobs. 
+    /// Each job's feedback can be disputed once. 
+    pub fn flag_feedback<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+        evidence_uri: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("flag_feedback")
+            .argument(&job_id)
+            .argument(&evidence_uri)
+            .original_result()
+    }
+
+    /// Moderator rejects the dispute; the rating stands. 
+    pub fn uphold_feedback<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("uphold_feedback")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Moderator accepts the dispute; the rating is removed from the overall and 
+    /// per-service scores, which are recomputed and re-ranked. 
+    pub fn strike_feedback<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("strike_feedback")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    /// Agent owner disputes an ERC-8004 `giveFeedback` entry about the agent. 
+    /// Each entry can be disputed once; revoked entries cannot be disputed. 
+    pub fn flag_client_feedback<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+        Arg3: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        client_address: Arg1,
+        feedback_index: Arg2,
+        evidence_uri: Arg3,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("flag_client_feedback")
+            .argument(&agent_nonce)
+            .argument(&client_address)
+            .argument(&feedback_index)
+            .argument(&evidence_uri)
+            .original_result()
+    }
+
+    /// Moderator rejects the dispute; the entry stands. 
+    pub fn uphold_client_feedback<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        client_address: Arg1,
+        feedback_index: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("uphold_client_feedback")
+            .argument(&agent_nonce)
+            .argument(&client_address)
+            .argument(&feedback_index)
+            .original_result()
+    }
+
+    /// Moderator accepts the dispute; the entry is marked revoked, as if its 
+    /// client had revoked it. 
+    pub fn strike_client_feedback<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+        client_address: Arg1,
+        feedback_index: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("strike_client_feedback")
+            .argument(&agent_nonce)
+            .argument(&client_address)
+            .argument(&feedback_index)
+            .original_result()
+    }
+
+    pub fn grant_role<
+        Arg0: ProxyArg<common::roles::Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grant_role")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn revoke_role<
+        Arg0: ProxyArg<common::roles::Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revoke_role")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    /// First step of an ownership transfer. Calling it again replaces the 
+    /// pending owner. 
+    pub fn transfer_ownership<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_owner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("transfer_ownership")
+            .argument(&new_owner)
+            .original_result()
+    }
+
+    /// Second step: the pending owner takes over. 
+    pub fn accept_ownership(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("accept_ownership")
+            .original_result()
+    }
+
+    pub fn current_owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_owner")
+            .original_result()
+    }
+
+    pub fn get_pending_owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_pending_owner")
+            .original_result()
+    }
+
+    /// Whether `address` may act as `role`: the owner and admins always can. 
+    pub fn has_role<
+        Arg0: ProxyArg<common::roles::Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("has_role")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn role_members<
+        Arg0: ProxyArg<common::roles::Role>,
+    >(
+        self,
+        role: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_role_members")
+            .argument(&role)
+            .original_result()
+    }
+
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_paused")
+            .original_result()
+    }
+
+    pub fn storage_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_storage_version")
+            .original_result()
+    }
+
+    /// Every `(code, description)` pair the MX-8004 contracts can fail with. 
+    pub fn get_error_codes(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, ManagedBuffer<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_error_codes")
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct FeedbackAuth<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub agent_nonce: u64,
+    pub client_address: ManagedAddress<Api>,
+    pub index_limit: u64,
+    pub expiry: TimestampSeconds,
+    pub signer: ManagedAddress<Api>,
+    pub signature: ManagedBuffer<Api>,
 }
 
 #[type_abi]
@@ -338,6 +943,37 @@ where
     pub is_revoked: bool,
 }
 
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct JobFeedback<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub agent_nonce: u64,
+    pub rating: BigUint<Api>,
+    pub service_id: Option<u32>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct FeedbackDispute<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub agent_nonce: u64,
+    pub evidence_uri: ManagedBuffer<Api>,
+    pub status: DisputeStatus,
+    pub flagged_at: TimestampSeconds,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum DisputeStatus {
+    Pending,
+    Upheld,
+    Struck,
+}
+
 #[type_abi]
 #[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
 pub struct NewFeedbackEventData<Api>
@@ -353,3 +989,48 @@ where
     pub feedback_uri: ManagedBuffer<Api>,
     pub feedback_hash: ManagedBuffer<Api>,
 }
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ResponseAppendedEventData<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub response_index: u64,
+    pub response_uri: ManagedBuffer<Api>,
+    pub response_hash: ManagedBuffer<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug)]
+pub struct ServiceReputation<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub service_id: u32,
+    pub score: BigUint<Api>,
+    pub total_jobs: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug)]
+pub struct LeaderboardEntry<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub agent_nonce: u64,
+    pub score: BigUint<Api>,
+    pub total_jobs: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct FeedbackResponse<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub responder: ManagedAddress<Api>,
+    pub response_uri: ManagedBuffer<Api>,
+    pub response_hash: ManagedBuffer<Api>,
+    pub timestamp: TimestampSeconds,
+}
@@ -66,12 +66,18 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
-    pub fn upgrade(
+    /// Brings storage up to `STORAGE_VERSION`. `job_ids` are migrated right 
+    /// away; larger sets go through `migrate_jobs` in batches. 
+    pub fn upgrade<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
         self,
+        job_ids: Arg0,
     ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
         self.wrapped_tx
             .payment(NotPayable)
             .raw_upgrade()
+            .argument(&job_ids)
             .original_result()
     }
 }
@@ -85,21 +91,27 @@ where
     To: TxTo<Env>,
     Gas: TxGas<Env>,
 {
+    /// Creates a job. Pass `accept_quote = true` (after `service_id`) to take 
+    /// up the agent's quote for `job_id`; without it that quote is dropped 
+    /// and the service config prices the job. 
     pub fn init_job<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
         Arg1: ProxyArg<u64>,
         Arg2: ProxyArg<OptionalValue<u32>>,
+        Arg3: ProxyArg<OptionalValue<bool>>,
     >(
         self,
         job_id: Arg0,
         agent_nonce: Arg1,
         service_id: Arg2,
+        accept_quote: Arg3,
     ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
         self.wrapped_tx
             .raw_call("init_job")
             .argument(&job_id)
             .argument(&agent_nonce)
             .argument(&service_id)
+            .argument(&accept_quote)
             .original_result()
     }
 
@@ -136,6 +148,21 @@ where
             .original_result()
     }
 
+    /// Caches the owner of an agent when identity-registry is on another 
+    /// shard, so `validation_request` can check it. Anyone can call this. 
+    pub fn sync_agent<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        agent_nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("sync_agent")
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
     /// ERC-8004: Agent requests validation from a specific validator. 
     /// MUST be called by the owner of the agent (agentId). 
     pub fn validation_request<
@@ -188,6 +215,10 @@ where
             .original_result()
     }
 
+    /// Prunes jobs older than the retention period, together with their 
+    /// validation requests: verified jobs, and `New` / `Pending` jobs that 
+    /// expired without being verified. Jobs awaiting a validator and jobs an 
+    /// escrow still holds funds for are kept. Anyone can call this. 
     pub fn clean_old_jobs<
         Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
     >(
@@ -201,6 +232,19 @@ where
             .original_result()
     }
 
+    pub fn synced_agent<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        nonce: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common::structs::SyncedAgent<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_synced_agent")
+            .argument(&nonce)
+            .original_result()
+    }
+
     pub fn is_job_verified<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
@@ -214,6 +258,38 @@ where
             .original_result()
     }
 
+    /// Timestamp of the first verifying response, or 0 if the job is not verified. 
+    pub fn get_job_verified_at<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TimestampSeconds> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_verified_at")
+            .argument(&job_id)
+            .original_result()
+    }
+
+    pub fn get_escrow_contract_address(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_escrow_contract_address")
+            .original_result()
+    }
+
+    pub fn get_job_retention(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, DurationMillis> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_retention")
+            .original_result()
+    }
+
     pub fn get_job_data<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
     >(
@@ -227,6 +303,39 @@ where
             .original_result()
     }
 
+    /// The agent's open quote for a job id, if it posted one. 
+    pub fn get_job_quote<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        job_id: Arg0,
+        agent_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<common::structs::JobQuote<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_quote")
+            .argument(&job_id)
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    /// What escrow needs to know about a job, stamped with the time of the 
+    /// read; read by escrow's `sync_job` when the two contracts are on 
+    /// different shards. 
+    pub fn get_job_snapshot<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+    >(
+        self,
+        job_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, common::structs::SyncedJob<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_job_snapshot")
+            .argument(&job_id)
+            .original_result()
+    }
+
     /// ERC-8004: Returns validation status for a request hash. 
     pub fn get_validation_status<
         Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
@@ -267,4 +376,249 @@ where
             .argument(&address)
             .original_result()
     }
+
+    pub fn set_escrow_contract_address<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        address: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_escrow_contract_address")
+            .argument(&address)
+            .original_result()
+    }
+
+    /// Minimum age of a verified job before `clean_old_jobs` may prune it. 
+    pub fn set_job_retention<
+        Arg0: ProxyArg<DurationMillis>,
+    >(
+        self,
+        retention: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("set_job_retention")
+            .argument(&retention)
+            .original_result()
+    }
+
+    /// Returns how many jobs were rewritten. 
+    pub fn migrate_jobs<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedBuffer<Env::Api>>>,
+    >(
+        self,
+        job_ids: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("migrate_jobs")
+            .argument(&job_ids)
+            .original_result()
+    }
+
+    /// Posts or replaces the agent's quote for `job_id`. Only the agent owner 
+    /// can quote, and only for a job that has not been initialized yet. 
+    pub fn post_quote<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg3: ProxyArg<TokenId<Env::Api>>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<BigUint<Env::Api>>,
+        Arg6: ProxyArg<TimestampSeconds>,
+    >(
+        self,
+        job_id: Arg0,
+        agent_nonce: Arg1,
+        employer: Arg2,
+        token: Arg3,
+        token_nonce: Arg4,
+        price: Arg5,
+        expires_at: Arg6,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("post_quote")
+            .argument(&job_id)
+            .argument(&agent_nonce)
+            .argument(&employer)
+            .argument(&token)
+            .argument(&token_nonce)
+            .argument(&price)
+            .argument(&expires_at)
+            .original_result()
+    }
+
+    /// Withdraws the agent's quote for `job_id`. Expired quotes, and quotes 
+    /// left over after the employer took another agent's, can be cancelled 
+    /// too. 
+    pub fn cancel_quote<
+        Arg0: ProxyArg<ManagedBuffer<Env::Api>>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        job_id: Arg0,
+        agent_nonce: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancel_quote")
+            .argument(&job_id)
+            .argument(&agent_nonce)
+            .original_result()
+    }
+
+    pub fn grant_role<
+        Arg0: ProxyArg<common::roles::Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grant_role")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn revoke_role<
+        Arg0: ProxyArg<common::roles::Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revoke_role")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    /// First step of an ownership transfer. Calling it again replaces the 
+    /// pending owner. 
+    pub fn transfer_ownership<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_owner: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("transfer_ownership")
+            .argument(&new_owner)
+            .original_result()
+    }
+
+    /// Second step: the pending owner takes over. The protocol owner must 
+    /// already have been changed to the caller. 
+    pub fn accept_ownership(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("accept_ownership")
+            .original_result()
+    }
+
+    pub fn current_owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_owner")
+            .original_result()
+    }
+
+    pub fn get_pending_owner(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_pending_owner")
+            .original_result()
+    }
+
+    /// Whether `address` may act as `role`: the owner and admins always can. 
+    pub fn has_role<
+        Arg0: ProxyArg<common::roles::Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("has_role")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn role_members<
+        Arg0: ProxyArg<common::roles::Role>,
+    >(
+        self,
+        role: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_role_members")
+            .argument(&role)
+            .original_result()
+    }
+
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    pub fn paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("is_paused")
+            .original_result()
+    }
+
+    pub fn storage_version(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u32> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_storage_version")
+            .original_result()
+    }
+
+    /// Every `(code, description)` pair the MX-8004 contracts can fail with. 
+    pub fn get_error_codes(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<u32, ManagedBuffer<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("get_error_codes")
+            .original_result()
+    }
 }
@@ -181,6 +181,8 @@ impl ContractInteract {
         let endpoint = ManagedBuffer::new_from_bytes(&b""[..]);
         let feedback_uri = ManagedBuffer::new_from_bytes(&b""[..]);
         let feedback_hash = ManagedBuffer::new_from_bytes(&b""[..]);
+        let feedback_auth =
+            OptionalValue::<reputation_registry_proxy::FeedbackAuth<StaticApi>>::None;
 
         let response = self
             .interactor
@@ -198,6 +200,7 @@ impl ContractInteract {
                 endpoint,
                 feedback_uri,
                 feedback_hash,
+                feedback_auth,
             )
             .returns(ReturnsResultUnmanaged)
             .run()
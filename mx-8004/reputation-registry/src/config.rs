@@ -1,18 +1,47 @@
 multiversx_sc::imports!();
 
+use crate::errors::ERR_INVALID_LEADERBOARD_SIZE;
+use common::roles::Role;
+
+pub const DEFAULT_LEADERBOARD_SIZE: u32 = 20;
+pub const MAX_LEADERBOARD_SIZE: u32 = 100;
+
 #[multiversx_sc::module]
 pub trait ConfigModule:
-    common::cross_contract::CrossContractModule + crate::storage::StorageModule
+    common::cross_contract::CrossContractModule
+    + crate::storage::StorageModule
+    + common::roles::RolesModule
 {
-    #[only_owner]
     #[endpoint(set_identity_contract_address)]
     fn set_identity_contract_address(&self, address: ManagedAddress) {
+        self.require_role(Role::ConfigManager);
         self.identity_contract_address().set(&address);
     }
 
-    #[only_owner]
     #[endpoint(set_validation_contract_address)]
     fn set_validation_contract_address(&self, address: ManagedAddress) {
+        self.require_role(Role::ConfigManager);
         self.validation_contract_address().set(&address);
     }
+
+    /// When enabled, `giveFeedback` rejects calls without a valid `feedback_auth`.
+    #[endpoint(set_feedback_auth_required)]
+    fn set_feedback_auth_required(&self, required: bool) {
+        self.require_role(Role::ConfigManager);
+        self.feedback_auth_required().set(required);
+    }
+
+    /// Number of agents kept on each leaderboard and the job count an agent
+    /// needs before it is ranked. Takes effect on the next feedback per board;
+    /// views apply it immediately.
+    #[endpoint(set_leaderboard_config)]
+    fn set_leaderboard_config(&self, size: u32, min_jobs: u64) {
+        self.require_role(Role::ConfigManager);
+        require!(
+            size > 0 && size <= MAX_LEADERBOARD_SIZE,
+            ERR_INVALID_LEADERBOARD_SIZE
+        );
+        self.leaderboard_size().set(size);
+        self.leaderboard_min_jobs().set(min_jobs);
+    }
 }
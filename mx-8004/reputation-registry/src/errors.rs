@@ -1,9 +1,9 @@
-pub use common::errors::ERR_JOB_NOT_FOUND;
-
-pub const ERR_NOT_EMPLOYER: &str = "Only the employer can provide feedback";
-pub const ERR_FEEDBACK_ALREADY_PROVIDED: &str = "Feedback already provided for this job";
-pub const ERR_AGENT_OWNER_CANNOT_SELF_REVIEW: &str =
-    "Agent owner cannot give feedback to own agent";
-pub const ERR_INVALID_VALUE_DECIMALS: &str = "Value decimals must be 0-18";
-pub const ERR_FEEDBACK_NOT_FOUND: &str = "Feedback not found";
-pub const ERR_FEEDBACK_ALREADY_REVOKED: &str = "Feedback already revoked";
+pub use common::errors::{
+    ERR_AGENT_OWNER_CANNOT_SELF_REVIEW, ERR_DISPUTE_ALREADY_RESOLVED, ERR_DISPUTE_NOT_FOUND,
+    ERR_FEEDBACK_AGENT_MISMATCH, ERR_FEEDBACK_ALREADY_DISPUTED, ERR_FEEDBACK_ALREADY_PROVIDED,
+    ERR_FEEDBACK_ALREADY_REVOKED, ERR_FEEDBACK_AUTH_AGENT_MISMATCH,
+    ERR_FEEDBACK_AUTH_CLIENT_MISMATCH, ERR_FEEDBACK_AUTH_EXPIRED, ERR_FEEDBACK_AUTH_INDEX_LIMIT,
+    ERR_FEEDBACK_AUTH_INVALID_SIGNER, ERR_FEEDBACK_AUTH_REQUIRED, ERR_FEEDBACK_NOT_FOUND,
+    ERR_INVALID_LEADERBOARD_SIZE, ERR_INVALID_VALUE_DECIMALS, ERR_JOB_NOT_FOUND,
+    ERR_NOT_DISPUTING_AGENT_OWNER, ERR_NOT_FEEDBACK_EMPLOYER, ERR_NOT_MODERATOR,
+};
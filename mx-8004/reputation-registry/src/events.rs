@@ -15,11 +15,28 @@ pub struct NewFeedbackEventData<M: ManagedTypeApi> {
     pub feedback_hash: ManagedBuffer<M>,
 }
 
+/// ERC-8004 response appended event data — packed as a single data argument.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ResponseAppendedEventData<M: ManagedTypeApi> {
+    pub response_index: u64,
+    pub response_uri: ManagedBuffer<M>,
+    pub response_hash: ManagedBuffer<M>,
+}
+
 #[multiversx_sc::module]
 pub trait EventsModule {
     #[event("reputationUpdated")]
     fn reputation_updated_event(&self, #[indexed] agent_nonce: u64, new_score: BigUint);
 
+    #[event("serviceReputationUpdated")]
+    fn service_reputation_updated_event(
+        &self,
+        #[indexed] agent_nonce: u64,
+        #[indexed] service_id: u32,
+        new_score: BigUint,
+    );
+
     #[event("newFeedback")]
     fn new_feedback_event(
         &self,
@@ -42,5 +59,59 @@ pub trait EventsModule {
         #[indexed] agent_nonce: u64,
         #[indexed] client_address: ManagedAddress,
         #[indexed] feedback_index: u64,
+        #[indexed] responder: ManagedAddress,
+        data: ResponseAppendedEventData<Self::Api>,
+    );
+
+    #[event("feedbackFlagged")]
+    fn feedback_flagged_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] agent_nonce: u64,
+        evidence_uri: ManagedBuffer,
+    );
+
+    #[event("feedbackUpheld")]
+    fn feedback_upheld_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] moderator: &ManagedAddress,
+    );
+
+    #[event("feedbackStruck")]
+    fn feedback_struck_event(
+        &self,
+        #[indexed] job_id: &ManagedBuffer,
+        #[indexed] moderator: &ManagedAddress,
+    );
+
+    #[event("clientFeedbackFlagged")]
+    fn client_feedback_flagged_event(
+        &self,
+        #[indexed] agent_nonce: u64,
+        #[indexed] client_address: &ManagedAddress,
+        #[indexed] feedback_index: u64,
+        evidence_uri: ManagedBuffer,
     );
+
+    #[event("clientFeedbackUpheld")]
+    fn client_feedback_upheld_event(
+        &self,
+        #[indexed] agent_nonce: u64,
+        #[indexed] client_address: &ManagedAddress,
+        #[indexed] feedback_index: u64,
+        #[indexed] moderator: &ManagedAddress,
+    );
+
+    #[event("clientFeedbackStruck")]
+    fn client_feedback_struck_event(
+        &self,
+        #[indexed] agent_nonce: u64,
+        #[indexed] client_address: &ManagedAddress,
+        #[indexed] feedback_index: u64,
+        #[indexed] moderator: &ManagedAddress,
+    );
+
+    #[event("jobFeedbackCleaned")]
+    fn job_feedback_cleaned_event(&self, #[indexed] job_id: &ManagedBuffer);
 }
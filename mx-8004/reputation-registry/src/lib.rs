@@ -6,21 +6,34 @@ multiversx_sc::derive_imports!();
 pub mod config;
 mod errors;
 mod events;
+pub mod moderation;
 pub mod storage;
 pub mod structs;
 mod utils;
+pub mod views;
 
+use common::roles::Role;
 use errors::*;
-use events::NewFeedbackEventData;
-use structs::FeedbackData;
+use events::{NewFeedbackEventData, ResponseAppendedEventData};
+use structs::{DisputeStatus, FeedbackAuth, FeedbackData, FeedbackResponse, JobFeedback};
+
+/// Layout version written by this code; see `common::migration`.
+pub const STORAGE_VERSION: u32 = 1;
 
 #[multiversx_sc::contract]
 pub trait ReputationRegistry:
     common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
     + storage::StorageModule
     + events::EventsModule
     + config::ConfigModule
     + utils::UtilsModule
+    + views::ViewsModule
+    + moderation::ModerationModule
+    + common::roles::RolesModule
+    + common::pause::PauseModule
+    + common::migration::StorageVersionModule
+    + common::errors::ErrorCatalogModule
 {
     #[init]
     fn init(
@@ -32,19 +45,39 @@ pub trait ReputationRegistry:
             .set(&validation_contract_address);
         self.identity_contract_address()
             .set(&identity_contract_address);
+        self.leaderboard_size()
+            .set(config::DEFAULT_LEADERBOARD_SIZE);
+        self.storage_version().set(STORAGE_VERSION);
     }
 
+    /// Brings storage up to `STORAGE_VERSION`. Feedback records kept their
+    /// layout, so only contract-level state needs updating.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        self.leaderboard_size()
+            .set_if_empty(config::DEFAULT_LEADERBOARD_SIZE);
+
+        let mut moderators = self.role_members(Role::Moderator);
+        for moderator in self.moderators().iter() {
+            moderators.insert(moderator);
+        }
+        self.moderators().clear();
+
+        self.set_storage_version(STORAGE_VERSION);
+    }
 
     // ── giveFeedbackSimple (MX-8004 original — on-chain scoring) ──
 
     /// Simple feedback for a job. Caller must be the employer who created the job.
-    /// Computes a cumulative moving average on-chain.
+    /// Keeps the average rating on-chain, both overall and for the
+    /// service the job was created for (if any), and re-ranks the agent on the
+    /// matching leaderboards.
     #[endpoint(giveFeedbackSimple)]
     fn give_feedback_simple(&self, job_id: ManagedBuffer, agent_nonce: u64, rating: BigUint) {
+        self.require_not_paused();
         let caller = self.blockchain().get_caller();
         let validation_addr = self.validation_contract_address().get();
+        self.require_same_shard(&validation_addr);
 
         // 1. Authenticity: Read job data directly from validation-registry storage
         let job_mapper = self.external_job_data(validation_addr, &job_id);
@@ -52,7 +85,11 @@ pub trait ReputationRegistry:
         let job_data = job_mapper.get();
 
         // 2. Frontrunning Protection: Verify caller is the employer
-        require!(caller == job_data.employer, ERR_NOT_EMPLOYER);
+        require!(caller == job_data.employer, ERR_NOT_FEEDBACK_EMPLOYER);
+        require!(
+            agent_nonce == job_data.agent_nonce,
+            ERR_FEEDBACK_AGENT_MISMATCH
+        );
 
         // 3. Duplicate Prevention
         require!(
@@ -60,18 +97,30 @@ pub trait ReputationRegistry:
             ERR_FEEDBACK_ALREADY_PROVIDED
         );
 
-        let new_score = self.calculate_new_score(agent_nonce, rating);
-
-        self.reputation_score(agent_nonce).set(&new_score);
-        self.has_given_feedback(job_id).set(true);
+        let new_score = self.calculate_new_score(agent_nonce, &rating);
+        self.has_given_feedback(job_id.clone()).set(true);
+        self.job_feedback(&job_id).set(JobFeedback {
+            agent_nonce,
+            rating: rating.clone(),
+            service_id: job_data.service_id,
+        });
 
         self.reputation_updated_event(agent_nonce, new_score);
+
+        if let Some(service_id) = job_data.service_id {
+            let new_service_score =
+                self.calculate_new_service_score(agent_nonce, service_id, &rating);
+            self.service_reputation_updated_event(agent_nonce, service_id, new_service_score);
+        }
+        self.refresh_leaderboards(agent_nonce, job_data.service_id);
     }
 
     // ── giveFeedback (ERC-8004 compliant — raw signals) ──
 
     /// ERC-8004: Anyone can give feedback (except the agent owner).
     /// Stores raw signals — no on-chain scoring. Off-chain aggregation expected.
+    /// An agent-signed `feedback_auth` is checked when given, and required when
+    /// `feedbackAuthRequired` is set.
     #[endpoint(giveFeedback)]
     fn give_feedback(
         &self,
@@ -83,11 +132,14 @@ pub trait ReputationRegistry:
         endpoint: ManagedBuffer,
         feedback_uri: ManagedBuffer,
         feedback_hash: ManagedBuffer,
+        feedback_auth: OptionalValue<FeedbackAuth<Self::Api>>,
     ) {
+        self.require_not_paused();
         let caller = self.blockchain().get_caller();
 
         // 1. Caller MUST NOT be the agent owner
         let identity_addr = self.identity_contract_address().get();
+        self.require_same_shard(&identity_addr);
         let agent_mapper = self.external_agents(identity_addr);
         let opt_owner_nonce = agent_mapper.get_id(&caller);
         if opt_owner_nonce != 0 {
@@ -108,10 +160,19 @@ pub trait ReputationRegistry:
                 *idx
             });
 
-        // 4. Track client
+        // 4. Feedback authorization (index limit, expiry, agent signature)
+        match feedback_auth.into_option() {
+            Some(auth) => self.verify_feedback_auth(agent_nonce, &caller, new_index, &auth),
+            None => require!(
+                !self.feedback_auth_required().get(),
+                ERR_FEEDBACK_AUTH_REQUIRED
+            ),
+        }
+
+        // 5. Track client
         self.feedback_clients(agent_nonce).insert(caller.clone());
 
-        // 5. Store feedback data
+        // 6. Store feedback data
         let data = FeedbackData {
             value,
             value_decimals,
@@ -122,7 +183,7 @@ pub trait ReputationRegistry:
         self.feedback_data(agent_nonce, &caller, new_index)
             .set(data);
 
-        // 6. Emit event
+        // 7. Emit event
         let event_data = NewFeedbackEventData {
             feedback_index: new_index,
             value,
@@ -141,6 +202,7 @@ pub trait ReputationRegistry:
     /// ERC-8004: Only the original feedback author can revoke their feedback.
     #[endpoint(revokeFeedback)]
     fn revoke_feedback(&self, agent_nonce: u64, feedback_index: u64) {
+        self.require_not_paused();
         let caller = self.blockchain().get_caller();
 
         let mapper = self.feedback_data(agent_nonce, &caller, feedback_index);
@@ -168,17 +230,99 @@ pub trait ReputationRegistry:
         mapper.get()
     }
 
+    // ── appendResponse (ERC-8004) ──
+
+    /// ERC-8004: Anyone can append a response to a feedback entry (e.g., agent showing
+    /// refund, data aggregator tagging feedback as spam). Responses are append-only and
+    /// record who wrote them.
+    #[endpoint(appendResponse)]
+    fn append_feedback_response(
+        &self,
+        agent_nonce: u64,
+        client_address: ManagedAddress,
+        feedback_index: u64,
+        response_uri: ManagedBuffer,
+        response_hash: ManagedBuffer,
+    ) {
+        self.require_not_paused();
+        require!(
+            !self
+                .feedback_data(agent_nonce, &client_address, feedback_index)
+                .is_empty(),
+            ERR_FEEDBACK_NOT_FOUND
+        );
+
+        let responder = self.blockchain().get_caller();
+        let mut responses = self.feedback_responses(agent_nonce, &client_address, feedback_index);
+        let response_index = responses.push(&FeedbackResponse {
+            responder: responder.clone(),
+            response_uri: response_uri.clone(),
+            response_hash: response_hash.clone(),
+            timestamp: self.blockchain().get_block_timestamp_seconds(),
+        }) as u64;
+
+        self.response_appended_event(
+            agent_nonce,
+            client_address,
+            feedback_index,
+            responder,
+            ResponseAppendedEventData {
+                response_index,
+                response_uri,
+                response_hash,
+            },
+        );
+    }
+
     // ── append_response (legacy, kept for backwards compat) ──
 
-    /// ERC-8004: Anyone can append a response to feedback (e.g., agent showing refund,
-    /// data aggregator tagging feedback as spam).
+    /// Legacy job-keyed response slot; superseded by `appendResponse`.
+    /// Anyone can set it and later calls overwrite earlier ones.
     #[endpoint(append_response)]
     fn append_response(&self, job_id: ManagedBuffer, response_uri: ManagedBuffer) {
+        self.require_not_paused();
         let validation_addr = self.validation_contract_address().get();
+        self.require_same_shard(&validation_addr);
         let job_mapper = self.external_job_data(validation_addr, &job_id);
         require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
 
         // Per ERC-8004: anyone can append responses — no caller check
         self.agent_response(job_id).set(response_uri);
     }
+
+    // ── Cleanup ──
+
+    /// Clears the per-job feedback records of jobs the validation registry
+    /// has pruned, so a reused job id starts clean. Jobs with a pending
+    /// feedback dispute are kept until a moderator decides. Scores are not
+    /// affected. Anyone can call this.
+    #[endpoint(clean_job_feedback)]
+    fn clean_job_feedback(&self, job_ids: MultiValueEncoded<ManagedBuffer>) {
+        self.require_not_paused();
+        let validation_addr = self.validation_contract_address().get();
+        self.require_same_shard(&validation_addr);
+        for job_id in job_ids {
+            if !self
+                .external_job_data(validation_addr.clone(), &job_id)
+                .is_empty()
+            {
+                continue;
+            }
+            let dispute_mapper = self.feedback_dispute(&job_id);
+            if !dispute_mapper.is_empty() && dispute_mapper.get().status == DisputeStatus::Pending {
+                continue;
+            }
+            let feedback_mapper = self.has_given_feedback(job_id.clone());
+            if feedback_mapper.is_empty() && self.agent_response(job_id.clone()).is_empty() {
+                continue;
+            }
+
+            feedback_mapper.clear();
+            self.job_feedback(&job_id).clear();
+            dispute_mapper.clear();
+            self.agent_response(job_id.clone()).clear();
+
+            self.job_feedback_cleaned_event(&job_id);
+        }
+    }
 }
@@ -0,0 +1,189 @@
+multiversx_sc::imports!();
+
+use crate::errors::*;
+use crate::structs::{DisputeStatus, FeedbackDispute};
+use common::roles::Role;
+
+/// Disputes over feedback. The agent owner flags a `giveFeedbackSimple` rating
+/// (by job) or an ERC-8004 `giveFeedback` entry (by agent, client and index)
+/// with evidence; a holder of the `Moderator` role upholds it or strikes it.
+/// A struck rating leaves the agent's scores and leaderboards; a struck
+/// `giveFeedback` entry is marked revoked.
+#[multiversx_sc::module]
+pub trait ModerationModule:
+    common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
+    + crate::storage::StorageModule
+    + crate::events::EventsModule
+    + crate::utils::UtilsModule
+    + common::roles::RolesModule
+    + common::pause::PauseModule
+{
+    /// Agent owner disputes the feedback left on one of the agent's jobs.
+    /// Each job's feedback can be disputed once.
+    #[endpoint(flag_feedback)]
+    fn flag_feedback(&self, job_id: ManagedBuffer, evidence_uri: ManagedBuffer) {
+        self.require_not_paused();
+        let feedback_mapper = self.job_feedback(&job_id);
+        require!(!feedback_mapper.is_empty(), ERR_FEEDBACK_NOT_FOUND);
+        let agent_nonce = feedback_mapper.get().agent_nonce;
+
+        self.open_dispute(self.feedback_dispute(&job_id), agent_nonce, &evidence_uri);
+
+        self.feedback_flagged_event(&job_id, agent_nonce, evidence_uri);
+    }
+
+    /// Moderator rejects the dispute; the rating stands.
+    #[endpoint(uphold_feedback)]
+    fn uphold_feedback(&self, job_id: ManagedBuffer) {
+        self.require_not_paused();
+        let moderator = self.require_moderator();
+        self.resolve_dispute(self.feedback_dispute(&job_id), DisputeStatus::Upheld);
+
+        self.feedback_upheld_event(&job_id, &moderator);
+    }
+
+    /// Moderator accepts the dispute; the rating is removed from the overall and
+    /// per-service scores, which are recomputed and re-ranked.
+    #[endpoint(strike_feedback)]
+    fn strike_feedback(&self, job_id: ManagedBuffer) {
+        self.require_not_paused();
+        let moderator = self.require_moderator();
+        self.resolve_dispute(self.feedback_dispute(&job_id), DisputeStatus::Struck);
+
+        let feedback = self.job_feedback(&job_id).get();
+        let agent_nonce = feedback.agent_nonce;
+
+        let new_score = self.remove_rating(agent_nonce, &feedback.rating);
+        self.reputation_updated_event(agent_nonce, new_score);
+
+        if let Some(service_id) = feedback.service_id {
+            let new_service_score =
+                self.remove_service_rating(agent_nonce, service_id, &feedback.rating);
+            self.service_reputation_updated_event(agent_nonce, service_id, new_service_score);
+        }
+        self.refresh_leaderboards(agent_nonce, feedback.service_id);
+
+        self.feedback_struck_event(&job_id, &moderator);
+    }
+
+    /// Agent owner disputes an ERC-8004 `giveFeedback` entry about the agent.
+    /// Each entry can be disputed once; revoked entries cannot be disputed.
+    #[endpoint(flag_client_feedback)]
+    fn flag_client_feedback(
+        &self,
+        agent_nonce: u64,
+        client_address: ManagedAddress,
+        feedback_index: u64,
+        evidence_uri: ManagedBuffer,
+    ) {
+        self.require_not_paused();
+        let feedback_mapper = self.feedback_data(agent_nonce, &client_address, feedback_index);
+        require!(!feedback_mapper.is_empty(), ERR_FEEDBACK_NOT_FOUND);
+        require!(
+            !feedback_mapper.get().is_revoked,
+            ERR_FEEDBACK_ALREADY_REVOKED
+        );
+
+        self.open_dispute(
+            self.client_feedback_dispute(agent_nonce, &client_address, feedback_index),
+            agent_nonce,
+            &evidence_uri,
+        );
+
+        self.client_feedback_flagged_event(
+            agent_nonce,
+            &client_address,
+            feedback_index,
+            evidence_uri,
+        );
+    }
+
+    /// Moderator rejects the dispute; the entry stands.
+    #[endpoint(uphold_client_feedback)]
+    fn uphold_client_feedback(
+        &self,
+        agent_nonce: u64,
+        client_address: ManagedAddress,
+        feedback_index: u64,
+    ) {
+        self.require_not_paused();
+        let moderator = self.require_moderator();
+        self.resolve_dispute(
+            self.client_feedback_dispute(agent_nonce, &client_address, feedback_index),
+            DisputeStatus::Upheld,
+        );
+
+        self.client_feedback_upheld_event(agent_nonce, &client_address, feedback_index, &moderator);
+    }
+
+    /// Moderator accepts the dispute; the entry is marked revoked, as if its
+    /// client had revoked it.
+    #[endpoint(strike_client_feedback)]
+    fn strike_client_feedback(
+        &self,
+        agent_nonce: u64,
+        client_address: ManagedAddress,
+        feedback_index: u64,
+    ) {
+        self.require_not_paused();
+        let moderator = self.require_moderator();
+        self.resolve_dispute(
+            self.client_feedback_dispute(agent_nonce, &client_address, feedback_index),
+            DisputeStatus::Struck,
+        );
+
+        self.feedback_data(agent_nonce, &client_address, feedback_index)
+            .update(|data| data.is_revoked = true);
+
+        self.client_feedback_struck_event(agent_nonce, &client_address, feedback_index, &moderator);
+    }
+
+    /// Records a pending dispute; only the agent's owner may open it.
+    fn open_dispute(
+        &self,
+        dispute_mapper: SingleValueMapper<FeedbackDispute<Self::Api>>,
+        agent_nonce: u64,
+        evidence_uri: &ManagedBuffer,
+    ) {
+        let identity_addr = self.identity_contract_address().get();
+        self.require_same_shard(&identity_addr);
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.external_agents(identity_addr).get_id(&caller) == agent_nonce,
+            ERR_NOT_DISPUTING_AGENT_OWNER
+        );
+
+        require!(dispute_mapper.is_empty(), ERR_FEEDBACK_ALREADY_DISPUTED);
+        dispute_mapper.set(FeedbackDispute {
+            agent_nonce,
+            evidence_uri: evidence_uri.clone(),
+            status: DisputeStatus::Pending,
+            flagged_at: self.blockchain().get_block_timestamp_seconds(),
+        });
+    }
+
+    fn require_moderator(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.has_role(Role::Moderator, caller.clone()),
+            ERR_NOT_MODERATOR
+        );
+        caller
+    }
+
+    fn resolve_dispute(
+        &self,
+        dispute_mapper: SingleValueMapper<FeedbackDispute<Self::Api>>,
+        outcome: DisputeStatus,
+    ) {
+        require!(!dispute_mapper.is_empty(), ERR_DISPUTE_NOT_FOUND);
+        dispute_mapper.update(|dispute| {
+            require!(
+                dispute.status == DisputeStatus::Pending,
+                ERR_DISPUTE_ALREADY_RESOLVED
+            );
+            dispute.status = outcome;
+        });
+    }
+}
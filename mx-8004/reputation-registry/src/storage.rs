@@ -1,7 +1,9 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::structs::FeedbackData;
+use crate::structs::{
+    FeedbackData, FeedbackDispute, FeedbackResponse, JobFeedback, LeaderboardEntry,
+};
 pub use common::structs::{JobData, JobStatus};
 
 #[multiversx_sc::module]
@@ -16,6 +18,50 @@ pub trait StorageModule: common::cross_contract::CrossContractModule {
     #[storage_mapper("totalJobs")]
     fn total_jobs(&self, agent_nonce: u64) -> SingleValueMapper<u64>;
 
+    /// Exact sum of the agent's counted ratings; the score is `sum / totalJobs`.
+    #[storage_mapper("ratingSum")]
+    fn rating_sum(&self, agent_nonce: u64) -> SingleValueMapper<BigUint>;
+
+    // ── Per-service scoring (keyed by the service id carried in JobData) ──
+
+    #[view(get_service_reputation_score)]
+    #[storage_mapper("serviceReputationScore")]
+    fn service_reputation_score(
+        &self,
+        agent_nonce: u64,
+        service_id: u32,
+    ) -> SingleValueMapper<BigUint>;
+
+    #[view(get_service_total_jobs)]
+    #[storage_mapper("serviceTotalJobs")]
+    fn service_total_jobs(&self, agent_nonce: u64, service_id: u32) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("serviceRatingSum")]
+    fn service_rating_sum(&self, agent_nonce: u64, service_id: u32) -> SingleValueMapper<BigUint>;
+
+    #[view(get_rated_services)]
+    #[storage_mapper("ratedServices")]
+    fn rated_services(&self, agent_nonce: u64) -> UnorderedSetMapper<u32>;
+
+    // ── Leaderboards (bounded, sorted best first) ──
+
+    #[view(get_leaderboard_size)]
+    #[storage_mapper("leaderboardSize")]
+    fn leaderboard_size(&self) -> SingleValueMapper<u32>;
+
+    #[view(get_leaderboard_min_jobs)]
+    #[storage_mapper("leaderboardMinJobs")]
+    fn leaderboard_min_jobs(&self) -> SingleValueMapper<u64>;
+
+    #[storage_mapper("leaderboard")]
+    fn leaderboard(&self) -> SingleValueMapper<ManagedVec<LeaderboardEntry<Self::Api>>>;
+
+    #[storage_mapper("serviceLeaderboard")]
+    fn service_leaderboard(
+        &self,
+        service_id: u32,
+    ) -> SingleValueMapper<ManagedVec<LeaderboardEntry<Self::Api>>>;
+
     #[view(get_validation_contract_address)]
     #[storage_mapper("validationContractAddress")]
     fn validation_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
@@ -28,6 +74,33 @@ pub trait StorageModule: common::cross_contract::CrossContractModule {
     #[storage_mapper("hasGivenFeedback")]
     fn has_given_feedback(&self, job_id: ManagedBuffer) -> SingleValueMapper<bool>;
 
+    #[view(get_job_feedback)]
+    #[storage_mapper("jobFeedback")]
+    fn job_feedback(&self, job_id: &ManagedBuffer) -> SingleValueMapper<JobFeedback<Self::Api>>;
+
+    // ── Feedback disputes ──
+
+    #[view(get_feedback_dispute)]
+    #[storage_mapper("feedbackDispute")]
+    fn feedback_dispute(
+        &self,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<FeedbackDispute<Self::Api>>;
+
+    #[view(get_client_feedback_dispute)]
+    #[storage_mapper("clientFeedbackDispute")]
+    fn client_feedback_dispute(
+        &self,
+        agent_nonce: u64,
+        client: &ManagedAddress,
+        index: u64,
+    ) -> SingleValueMapper<FeedbackDispute<Self::Api>>;
+
+    /// Moderators appointed before roles existed; moved into the `Moderator`
+    /// role on upgrade.
+    #[storage_mapper("moderators")]
+    fn moderators(&self) -> UnorderedSetMapper<ManagedAddress>;
+
     #[view(get_agent_response)]
     #[storage_mapper("agentResponse")]
     fn agent_response(&self, job_id: ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
@@ -53,4 +126,16 @@ pub trait StorageModule: common::cross_contract::CrossContractModule {
     #[view(getClients)]
     #[storage_mapper("feedbackClients")]
     fn feedback_clients(&self, agent_nonce: u64) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(is_feedback_auth_required)]
+    #[storage_mapper("feedbackAuthRequired")]
+    fn feedback_auth_required(&self) -> SingleValueMapper<bool>;
+
+    #[storage_mapper("feedbackResponses")]
+    fn feedback_responses(
+        &self,
+        agent_nonce: u64,
+        client: &ManagedAddress,
+        index: u64,
+    ) -> VecMapper<FeedbackResponse<Self::Api>>;
 }
@@ -11,3 +11,100 @@ pub struct FeedbackData<M: ManagedTypeApi> {
     pub tag2: ManagedBuffer<M>,
     pub is_revoked: bool,
 }
+
+/// Rating recorded by `giveFeedbackSimple`, kept so a struck entry can be taken
+/// back out of the agent's aggregates.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct JobFeedback<M: ManagedTypeApi> {
+    pub agent_nonce: u64,
+    pub rating: BigUint<M>,
+    pub service_id: Option<u32>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum DisputeStatus {
+    Pending,
+    Upheld,
+    Struck,
+}
+
+/// Agent owner's dispute of a job's feedback, resolved by a moderator.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct FeedbackDispute<M: ManagedTypeApi> {
+    pub agent_nonce: u64,
+    pub evidence_uri: ManagedBuffer<M>,
+    pub status: DisputeStatus,
+    pub flagged_at: TimestampSeconds,
+}
+
+/// ERC-8004 feedback authorization, signed off-chain by the agent owner with the
+/// agent's registered ed25519 `public_key`. Lets `client_address` submit feedback
+/// up to (and including) `index_limit` until `expiry`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct FeedbackAuth<M: ManagedTypeApi> {
+    pub agent_nonce: u64,
+    pub client_address: ManagedAddress<M>,
+    pub index_limit: u64,
+    pub expiry: TimestampSeconds,
+    pub signer: ManagedAddress<M>,
+    pub signature: ManagedBuffer<M>,
+}
+
+/// ERC-8004 response appended to a feedback entry (e.g. agent showing a refund,
+/// aggregator tagging spam). Responses form an append-only thread per feedback.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct FeedbackResponse<M: ManagedTypeApi> {
+    pub responder: ManagedAddress<M>,
+    pub response_uri: ManagedBuffer<M>,
+    pub response_hash: ManagedBuffer<M>,
+    pub timestamp: TimestampSeconds,
+}
+
+/// Aggregated on-chain score for one of an agent's services.
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug,
+)]
+pub struct ServiceReputation<M: ManagedTypeApi> {
+    pub service_id: u32,
+    pub score: BigUint<M>,
+    pub total_jobs: u64,
+}
+
+/// Ranking shared by per-service scores and leaderboard rows.
+pub trait Ranked<M: ManagedTypeApi> {
+    fn rank_key(&self) -> (&BigUint<M>, u64);
+
+    /// Higher score ranks first; equal scores are ordered by job count.
+    fn ranks_above(&self, other: &Self) -> bool {
+        self.rank_key() > other.rank_key()
+    }
+}
+
+impl<M: ManagedTypeApi> Ranked<M> for ServiceReputation<M> {
+    fn rank_key(&self) -> (&BigUint<M>, u64) {
+        (&self.score, self.total_jobs)
+    }
+}
+
+/// One row of the on-chain leaderboard (overall or per service).
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Debug,
+)]
+pub struct LeaderboardEntry<M: ManagedTypeApi> {
+    pub agent_nonce: u64,
+    pub score: BigUint<M>,
+    pub total_jobs: u64,
+}
+
+impl<M: ManagedTypeApi> Ranked<M> for LeaderboardEntry<M> {
+    fn rank_key(&self) -> (&BigUint<M>, u64) {
+        (&self.score, self.total_jobs)
+    }
+}
@@ -1,21 +1,254 @@
 multiversx_sc::imports!();
 
+use crate::errors::*;
+use crate::structs::{FeedbackAuth, LeaderboardEntry, Ranked};
+
 #[multiversx_sc::module]
 pub trait UtilsModule:
-    common::cross_contract::CrossContractModule + crate::storage::StorageModule
+    common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
+    + crate::storage::StorageModule
 {
-    /// Cumulative moving average: new_score = (current * (n-1) + rating) / n
-    fn calculate_new_score(&self, agent_nonce: u64, rating: BigUint) -> BigUint {
-        let total_jobs = self.total_jobs(agent_nonce).update(|n| {
+    fn calculate_new_score(&self, agent_nonce: u64, rating: &BigUint) -> BigUint {
+        self.add_to_average(
+            self.reputation_score(agent_nonce),
+            self.total_jobs(agent_nonce),
+            self.rating_sum(agent_nonce),
+            rating,
+        )
+    }
+
+    fn calculate_new_service_score(
+        &self,
+        agent_nonce: u64,
+        service_id: u32,
+        rating: &BigUint,
+    ) -> BigUint {
+        self.rated_services(agent_nonce).insert(service_id);
+        self.add_to_average(
+            self.service_reputation_score(agent_nonce, service_id),
+            self.service_total_jobs(agent_nonce, service_id),
+            self.service_rating_sum(agent_nonce, service_id),
+            rating,
+        )
+    }
+
+    /// Takes a struck rating back out of the overall average.
+    fn remove_rating(&self, agent_nonce: u64, rating: &BigUint) -> BigUint {
+        self.remove_from_average(
+            self.reputation_score(agent_nonce),
+            self.total_jobs(agent_nonce),
+            self.rating_sum(agent_nonce),
+            rating,
+        )
+    }
+
+    fn remove_service_rating(
+        &self,
+        agent_nonce: u64,
+        service_id: u32,
+        rating: &BigUint,
+    ) -> BigUint {
+        let new_score = self.remove_from_average(
+            self.service_reputation_score(agent_nonce, service_id),
+            self.service_total_jobs(agent_nonce, service_id),
+            self.service_rating_sum(agent_nonce, service_id),
+            rating,
+        );
+        if self.service_total_jobs(agent_nonce, service_id).get() == 0 {
+            self.rated_services(agent_nonce).swap_remove(&service_id);
+        }
+        new_score
+    }
+
+    /// Pushes the agent's current scores onto the overall board and, if given,
+    /// the service board.
+    fn refresh_leaderboards(&self, agent_nonce: u64, service_id: Option<u32>) {
+        self.update_leaderboard(
+            self.leaderboard(),
+            LeaderboardEntry {
+                agent_nonce,
+                score: self.reputation_score(agent_nonce).get(),
+                total_jobs: self.total_jobs(agent_nonce).get(),
+            },
+        );
+        if let Some(service_id) = service_id {
+            self.update_leaderboard(
+                self.service_leaderboard(service_id),
+                LeaderboardEntry {
+                    agent_nonce,
+                    score: self.service_reputation_score(agent_nonce, service_id).get(),
+                    total_jobs: self.service_total_jobs(agent_nonce, service_id).get(),
+                },
+            );
+        }
+    }
+
+    /// Re-ranks `entry` on a bounded leaderboard: drops its previous row, inserts
+    /// it at its sorted position if it has jobs and meets the minimum job count,
+    /// and trims the board to `leaderboardSize` rows, also after the size was
+    /// lowered. Agents evicted from a full board re-enter on their next feedback.
+    fn update_leaderboard(
+        &self,
+        board: SingleValueMapper<ManagedVec<LeaderboardEntry<Self::Api>>>,
+        entry: LeaderboardEntry<Self::Api>,
+    ) {
+        let size = self.leaderboard_size().get() as usize;
+        let min_jobs = self.leaderboard_min_jobs().get();
+
+        let mut placed = entry.total_jobs == 0 || entry.total_jobs < min_jobs;
+        let mut next = ManagedVec::<Self::Api, LeaderboardEntry<Self::Api>>::new();
+        for other in board.get().iter() {
+            if next.len() >= size {
+                break;
+            }
+            if other.agent_nonce == entry.agent_nonce || other.total_jobs < min_jobs {
+                continue;
+            }
+            if !placed && entry.ranks_above(&other) {
+                next.push(entry.clone());
+                placed = true;
+                if next.len() >= size {
+                    break;
+                }
+            }
+            next.push(other.clone());
+        }
+        if !placed && next.len() < size {
+            next.push(entry);
+        }
+        board.set(next);
+    }
+
+    /// Adds a rating to an average kept as an exact sum and job count;
+    /// the stored score is `sum / n`.
+    fn add_to_average(
+        &self,
+        score_mapper: SingleValueMapper<BigUint>,
+        jobs_mapper: SingleValueMapper<u64>,
+        sum_mapper: SingleValueMapper<BigUint>,
+        rating: &BigUint,
+    ) -> BigUint {
+        let sum = self.current_rating_sum(&score_mapper, &jobs_mapper, &sum_mapper) + rating;
+        let total_jobs = jobs_mapper.update(|n| {
             *n += 1;
             *n
         });
+        self.store_average(score_mapper, sum_mapper, sum, total_jobs)
+    }
+
+    /// Checks an agent-signed `FeedbackAuth` for the feedback about to be stored
+    /// at `feedback_index`. The signature must come from the agent's registered
+    /// ed25519 `public_key` over `feedback_auth_message`.
+    fn verify_feedback_auth(
+        &self,
+        agent_nonce: u64,
+        client: &ManagedAddress,
+        feedback_index: u64,
+        auth: &FeedbackAuth<Self::Api>,
+    ) {
+        require!(
+            auth.agent_nonce == agent_nonce,
+            ERR_FEEDBACK_AUTH_AGENT_MISMATCH
+        );
+        require!(
+            &auth.client_address == client,
+            ERR_FEEDBACK_AUTH_CLIENT_MISMATCH
+        );
+        require!(
+            feedback_index <= auth.index_limit,
+            ERR_FEEDBACK_AUTH_INDEX_LIMIT
+        );
+        require!(
+            self.blockchain().get_block_timestamp_seconds() < auth.expiry,
+            ERR_FEEDBACK_AUTH_EXPIRED
+        );
+
+        let identity_addr = self.identity_contract_address().get();
+        self.require_same_shard(&identity_addr);
+        let agents = self.external_agents(identity_addr.clone());
+        require!(
+            agents.contains_id(&agent_nonce) && agents.get_value(&agent_nonce) == auth.signer,
+            ERR_FEEDBACK_AUTH_INVALID_SIGNER
+        );
 
-        let current_score = self.reputation_score(agent_nonce).get();
-        let total_big = BigUint::from(total_jobs);
-        let prev_total = &total_big - 1u32;
-        let weighted_score = current_score * prev_total;
+        let public_key = self
+            .external_agent_details(identity_addr, agent_nonce)
+            .get()
+            .public_key;
+        let message = self.feedback_auth_message(auth);
+        self.crypto()
+            .verify_ed25519(&public_key, &message, &auth.signature);
+    }
+
+    /// Signed payload: this contract's address, then `agent_nonce`, `client_address`,
+    /// `index_limit`, `expiry` and `signer`, integers as 8-byte big-endian.
+    fn feedback_auth_message(&self, auth: &FeedbackAuth<Self::Api>) -> ManagedBuffer {
+        let mut message = self
+            .blockchain()
+            .get_sc_address()
+            .as_managed_buffer()
+            .clone();
+        message.append_bytes(&auth.agent_nonce.to_be_bytes());
+        message.append(auth.client_address.as_managed_buffer());
+        message.append_bytes(&auth.index_limit.to_be_bytes());
+        message.append_bytes(&auth.expiry.as_u64_seconds().to_be_bytes());
+        message.append(auth.signer.as_managed_buffer());
+        message
+    }
+
+    /// Takes a rating back out of the sum and recomputes the score, so no
+    /// rounding from earlier averages is left behind. A sum seeded from a
+    /// legacy score can still fall short of `rating`; it then floors at zero.
+    fn remove_from_average(
+        &self,
+        score_mapper: SingleValueMapper<BigUint>,
+        jobs_mapper: SingleValueMapper<u64>,
+        sum_mapper: SingleValueMapper<BigUint>,
+        rating: &BigUint,
+    ) -> BigUint {
+        let sum = self.current_rating_sum(&score_mapper, &jobs_mapper, &sum_mapper);
+        let sum = if &sum > rating {
+            sum - rating
+        } else {
+            BigUint::zero()
+        };
+        let remaining = jobs_mapper.update(|n| {
+            *n -= 1;
+            *n
+        });
+        self.store_average(score_mapper, sum_mapper, sum, remaining)
+    }
+
+    /// Scores recorded before sums were kept have no sum yet; theirs starts
+    /// from `score * n`.
+    fn current_rating_sum(
+        &self,
+        score_mapper: &SingleValueMapper<BigUint>,
+        jobs_mapper: &SingleValueMapper<u64>,
+        sum_mapper: &SingleValueMapper<BigUint>,
+    ) -> BigUint {
+        if sum_mapper.is_empty() {
+            score_mapper.get() * jobs_mapper.get()
+        } else {
+            sum_mapper.get()
+        }
+    }
 
-        (weighted_score + rating) / total_big
+    fn store_average(
+        &self,
+        score_mapper: SingleValueMapper<BigUint>,
+        sum_mapper: SingleValueMapper<BigUint>,
+        sum: BigUint,
+        total_jobs: u64,
+    ) -> BigUint {
+        let score = if total_jobs == 0 {
+            BigUint::zero()
+        } else {
+            &sum / total_jobs
+        };
+        score_mapper.set(&score);
+        sum_mapper.set(sum);
+        score
     }
 }
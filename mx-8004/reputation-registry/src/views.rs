@@ -0,0 +1,118 @@
+multiversx_sc::imports!();
+
+use crate::structs::{FeedbackResponse, LeaderboardEntry, Ranked, ServiceReputation};
+
+#[multiversx_sc::module]
+pub trait ViewsModule:
+    common::cross_contract::CrossContractModule + crate::storage::StorageModule
+{
+    /// Per-service reputation for an agent. Services that never received
+    /// feedback return a zero score and zero jobs.
+    #[view(get_service_reputation)]
+    fn get_service_reputation(
+        &self,
+        agent_nonce: u64,
+        service_id: u32,
+    ) -> ServiceReputation<Self::Api> {
+        ServiceReputation {
+            service_id,
+            score: self.service_reputation_score(agent_nonce, service_id).get(),
+            total_jobs: self.service_total_jobs(agent_nonce, service_id).get(),
+        }
+    }
+
+    /// All rated services of an agent, best score first.
+    /// Ties are broken by job count (more jobs first).
+    #[view(get_ranked_services)]
+    fn get_ranked_services(&self, agent_nonce: u64) -> ManagedVec<ServiceReputation<Self::Api>> {
+        let mut services = ManagedVec::<Self::Api, ServiceReputation<Self::Api>>::new();
+        let mut order = ManagedVec::<Self::Api, usize>::new();
+        for service_id in self.rated_services(agent_nonce).iter() {
+            order.push(services.len());
+            services.push(self.get_service_reputation(agent_nonce, service_id));
+        }
+        order.sort_unstable_by(|&a, &b| {
+            let (first, second) = (services.get(a), services.get(b));
+            second.rank_key().cmp(&first.rank_key()).then(a.cmp(&b))
+        });
+
+        let mut ranked = ManagedVec::new();
+        for index in order.iter() {
+            ranked.push(services.get(index).clone());
+        }
+        ranked
+    }
+
+    /// Best `count` agents by overall score (capped at `leaderboardSize`).
+    #[view(get_top_agents)]
+    fn get_top_agents(&self, count: u32) -> ManagedVec<LeaderboardEntry<Self::Api>> {
+        self.top_of(self.leaderboard().get(), count)
+    }
+
+    /// Best `count` agents by score for a single service id.
+    #[view(get_top_agents_for_service)]
+    fn get_top_agents_for_service(
+        &self,
+        service_id: u32,
+        count: u32,
+    ) -> ManagedVec<LeaderboardEntry<Self::Api>> {
+        self.top_of(self.service_leaderboard(service_id).get(), count)
+    }
+
+    /// Applies the current leaderboard config to a stored board, which may
+    /// predate the last `set_leaderboard_config`.
+    fn top_of(
+        &self,
+        board: ManagedVec<LeaderboardEntry<Self::Api>>,
+        count: u32,
+    ) -> ManagedVec<LeaderboardEntry<Self::Api>> {
+        let limit = core::cmp::min(count, self.leaderboard_size().get()) as usize;
+        let min_jobs = self.leaderboard_min_jobs().get();
+
+        let mut top = ManagedVec::new();
+        for entry in board.iter() {
+            if top.len() >= limit {
+                break;
+            }
+            if entry.total_jobs >= min_jobs {
+                top.push(entry.clone());
+            }
+        }
+        top
+    }
+
+    /// ERC-8004: Number of responses appended to a feedback entry.
+    #[view(getResponseCount)]
+    fn get_response_count(
+        &self,
+        agent_nonce: u64,
+        client_address: ManagedAddress,
+        feedback_index: u64,
+    ) -> u64 {
+        self.feedback_responses(agent_nonce, &client_address, feedback_index)
+            .len() as u64
+    }
+
+    /// ERC-8004: Page of responses for a feedback entry, oldest first.
+    /// `from` is a zero-based offset; at most `size` entries are returned.
+    #[view(readResponses)]
+    fn read_responses(
+        &self,
+        agent_nonce: u64,
+        client_address: ManagedAddress,
+        feedback_index: u64,
+        from: u64,
+        size: u64,
+    ) -> MultiValueEncoded<FeedbackResponse<Self::Api>> {
+        let responses = self.feedback_responses(agent_nonce, &client_address, feedback_index);
+        let total = responses.len() as u64;
+        let end = core::cmp::min(total, from.saturating_add(size));
+
+        let mut page = MultiValueEncoded::new();
+        for index in from..end {
+            // VecMapper is 1-indexed
+            page.push(responses.get(index as usize + 1));
+        }
+        page
+    }
+}
@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           15
-// Async Callback (empty):               1
-// Total number of exported functions:  18
+// Endpoints:                           54
+// Async Callback:                       1
+// Total number of exported functions:  57
 
 #![no_std]
 
@@ -24,18 +24,57 @@ multiversx_sc_wasm_adapter::endpoints! {
         giveFeedback => give_feedback
         revokeFeedback => revoke_feedback
         readFeedback => read_feedback
+        appendResponse => append_feedback_response
         append_response => append_response
+        clean_job_feedback => clean_job_feedback
+        get_synced_agent => synced_agent
         get_reputation_score => reputation_score
         get_total_jobs => total_jobs
+        get_service_reputation_score => service_reputation_score
+        get_service_total_jobs => service_total_jobs
+        get_rated_services => rated_services
+        get_leaderboard_size => leaderboard_size
+        get_leaderboard_min_jobs => leaderboard_min_jobs
         get_validation_contract_address => validation_contract_address
         get_identity_contract_address => identity_contract_address
         has_given_feedback => has_given_feedback
+        get_job_feedback => job_feedback
+        get_feedback_dispute => feedback_dispute
+        get_client_feedback_dispute => client_feedback_dispute
         get_agent_response => agent_response
         getLastIndex => last_feedback_index
         getClients => feedback_clients
+        is_feedback_auth_required => feedback_auth_required
         set_identity_contract_address => set_identity_contract_address
         set_validation_contract_address => set_validation_contract_address
+        set_feedback_auth_required => set_feedback_auth_required
+        set_leaderboard_config => set_leaderboard_config
+        get_service_reputation => get_service_reputation
+        get_ranked_services => get_ranked_services
+        get_top_agents => get_top_agents
+        get_top_agents_for_service => get_top_agents_for_service
+        getResponseCount => get_response_count
+        readResponses => read_responses
+        flag_feedback => flag_feedback
+        uphold_feedback => uphold_feedback
+        strike_feedback => strike_feedback
+        flag_client_feedback => flag_client_feedback
+        uphold_client_feedback => uphold_client_feedback
+        strike_client_feedback => strike_client_feedback
+        grant_role => grant_role
+        revoke_role => revoke_role
+        transfer_ownership => transfer_ownership
+        accept_ownership => accept_ownership
+        get_owner => current_owner
+        get_pending_owner => get_pending_owner
+        has_role => has_role
+        get_role_members => role_members
+        pause => pause
+        unpause => unpause
+        is_paused => paused
+        get_storage_version => storage_version
+        get_error_codes => get_error_codes
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { reputation_registry }
@@ -8,6 +8,7 @@ pub const CLIENT: TestAddress = TestAddress::new("client");
 pub const WORKER: TestAddress = TestAddress::new("worker");
 pub const VALIDATOR: TestAddress = TestAddress::new("validator");
 pub const EMPLOYER: TestAddress = TestAddress::new("employer");
+pub const TREASURY: TestAddress = TestAddress::new("treasury");
 
 // ── SC Addresses ──
 pub const IDENTITY_SC_ADDRESS: TestSCAddress = TestSCAddress::new("identity-registry");
@@ -15,10 +16,20 @@ pub const VALIDATION_SC_ADDRESS: TestSCAddress = TestSCAddress::new("validation-
 pub const REPUTATION_SC_ADDRESS: TestSCAddress = TestSCAddress::new("reputation-registry");
 pub const ESCROW_SC_ADDRESS: TestSCAddress = TestSCAddress::new("escrow");
 
+// Shard = last address byte % 3. The names above are padded with `_` (shard 2);
+// these fill all 22 bytes so they end in `1` and land on shard 1.
+pub const VALIDATION_CROSS_SHARD_SC_ADDRESS: TestSCAddress =
+    TestSCAddress::new("validation-on-shard-01");
+pub const ESCROW_CROSS_SHARD_SC_ADDRESS: TestSCAddress =
+    TestSCAddress::new("escrow-on-shard-one-01");
+
 // ── Tokens ──
 pub const AGENT_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("AGENT-abcdef");
 pub const PAYMENT_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("USDC-abcdef");
 pub const WRONG_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("WRONG-abcdef");
+pub const PROJECT_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("PROJ-abcdef");
+pub const META_TOKEN: TestTokenIdentifier = TestTokenIdentifier::new("META-abcdef");
+pub const META_TOKEN_NONCE: u64 = 3;
 
 // ── NFT Roles ──
 pub static NFT_ROLES: &[EsdtLocalRole] = &[
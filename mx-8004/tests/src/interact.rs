@@ -1,8 +1,9 @@
 use common::structs::{MetadataEntry, ServiceConfigInput};
 use multiversx_sc::types::{BigUint, ManagedAddress, ManagedArgBuffer, ManagedBuffer, TokenId};
-use multiversx_sc_scenario::imports::ExpectError;
+use multiversx_sc_scenario::imports::{ReturnsMessage, ReturnsStatus};
 use multiversx_sc_snippets::imports::*;
 use proxies::{
+    errors::{ErrorCode, decode_error},
     identity_registry_proxy::IdentityRegistryProxy,
     reputation_registry_proxy::ReputationRegistryProxy,
     validation_registry_proxy::ValidationRegistryProxy,
@@ -222,6 +223,7 @@ impl CsInteract {
                 ManagedBuffer::<StaticApi>::from(job_id),
                 agent_nonce,
                 OptionalValue::<u32>::None,
+                OptionalValue::<bool>::None,
             )
             .returns(ReturnsResultUnmanaged)
             .run()
@@ -247,6 +249,7 @@ impl CsInteract {
                 ManagedBuffer::<StaticApi>::from(job_id),
                 agent_nonce,
                 OptionalValue::Some(service_id),
+                OptionalValue::<bool>::None,
             )
             .payment((
                 EsdtTokenIdentifier::from(token),
@@ -276,6 +279,7 @@ impl CsInteract {
                 ManagedBuffer::<StaticApi>::from(job_id),
                 agent_nonce,
                 OptionalValue::Some(service_id),
+                OptionalValue::<bool>::None,
             )
             .returns(ReturnsResultUnmanaged)
             .run()
@@ -329,11 +333,11 @@ impl CsInteract {
         job_id: &[u8],
         request_uri: &[u8],
         request_hash: &[u8],
-        err_code: u64,
-        err_msg: &str,
+        expected: ErrorCode,
     ) {
         let validator = self.owner.clone();
-        self.interactor
+        let (status, message) = self
+            .interactor
             .tx()
             .from(from)
             .to(&self.validation_addr)
@@ -345,9 +349,11 @@ impl CsInteract {
                 ManagedBuffer::<StaticApi>::from(request_uri),
                 ManagedBuffer::<StaticApi>::from(request_hash),
             )
-            .returns(ExpectError(err_code, err_msg))
+            .returns(ReturnsStatus)
+            .returns(ReturnsMessage)
             .run()
             .await;
+        assert_contract_error(status, &message, expected);
     }
 
     pub async fn validation_response(
@@ -379,7 +385,6 @@ impl CsInteract {
 
     // ── Reputation Registry ──
 
-
     pub async fn give_feedback_simple(
         &mut self,
         from: &Address,
@@ -485,8 +490,7 @@ impl CsInteract {
         name: &[u8],
         uri: &[u8],
         pubkey: &[u8],
-        err_code: u64,
-        err_msg: &str,
+        expected: ErrorCode,
     ) {
         let mut args = ManagedArgBuffer::<StaticApi>::new();
         args.push_arg(ManagedBuffer::<StaticApi>::from(name));
@@ -495,28 +499,30 @@ impl CsInteract {
         args.push_arg(0u32); // metadata count
         args.push_arg(0u32); // services count
 
-        self.interactor
+        let (status, message) = self
+            .interactor
             .tx()
             .from(from)
             .to(&self.identity_addr)
             .gas(30_000_000u64)
             .raw_call("register_agent")
             .arguments_raw(args)
-            .returns(ExpectError(err_code, err_msg))
+            .returns(ReturnsStatus)
+            .returns(ReturnsMessage)
             .run()
             .await;
+        assert_contract_error(status, &message, expected);
     }
 
-
     pub async fn submit_proof_expect_err(
         &mut self,
         from: &Address,
         job_id: &[u8],
         proof: &[u8],
-        err_code: u64,
-        err_msg: &str,
+        expected: ErrorCode,
     ) {
-        self.interactor
+        let (status, message) = self
+            .interactor
             .tx()
             .from(from)
             .to(&self.validation_addr)
@@ -526,9 +532,11 @@ impl CsInteract {
                 ManagedBuffer::<StaticApi>::from(job_id),
                 ManagedBuffer::<StaticApi>::from(proof),
             )
-            .returns(ExpectError(err_code, err_msg))
+            .returns(ReturnsStatus)
+            .returns(ReturnsMessage)
             .run()
             .await;
+        assert_contract_error(status, &message, expected);
     }
 
     pub async fn init_job_expect_err(
@@ -536,10 +544,10 @@ impl CsInteract {
         from: &Address,
         job_id: &[u8],
         agent_nonce: u64,
-        err_code: u64,
-        err_msg: &str,
+        expected: ErrorCode,
     ) {
-        self.interactor
+        let (status, message) = self
+            .interactor
             .tx()
             .from(from)
             .to(&self.validation_addr)
@@ -549,10 +557,13 @@ impl CsInteract {
                 ManagedBuffer::<StaticApi>::from(job_id),
                 agent_nonce,
                 OptionalValue::<u32>::None,
+                OptionalValue::<bool>::None,
             )
-            .returns(ExpectError(err_code, err_msg))
+            .returns(ReturnsStatus)
+            .returns(ReturnsMessage)
             .run()
             .await;
+        assert_contract_error(status, &message, expected);
     }
 
     /// Calls init_job with a service_id but NO payment — expects error.
@@ -562,10 +573,10 @@ impl CsInteract {
         job_id: &[u8],
         agent_nonce: u64,
         service_id: u32,
-        err_code: u64,
-        err_msg: &str,
+        expected: ErrorCode,
     ) {
-        self.interactor
+        let (status, message) = self
+            .interactor
             .tx()
             .from(from)
             .to(&self.validation_addr)
@@ -575,14 +586,18 @@ impl CsInteract {
                 ManagedBuffer::<StaticApi>::from(job_id),
                 agent_nonce,
                 OptionalValue::Some(service_id),
+                OptionalValue::<bool>::None,
             )
-            .returns(ExpectError(err_code, err_msg))
+            .returns(ReturnsStatus)
+            .returns(ReturnsMessage)
             .run()
             .await;
+        assert_contract_error(status, &message, expected);
     }
 
-    pub async fn issue_token_expect_err(&mut self, err_code: u64, err_msg: &str) {
-        self.interactor
+    pub async fn issue_token_expect_err(&mut self, expected: ErrorCode) {
+        let (status, message) = self
+            .interactor
             .tx()
             .from(&self.owner)
             .to(&self.identity_addr)
@@ -593,8 +608,20 @@ impl CsInteract {
                 ManagedBuffer::<StaticApi>::from("AGENT"),
             )
             .egld(BigUint::<StaticApi>::from(50_000_000_000_000_000u64))
-            .returns(ExpectError(err_code, err_msg))
+            .returns(ReturnsStatus)
+            .returns(ReturnsMessage)
             .run()
             .await;
+        assert_contract_error(status, &message, expected);
     }
 }
+
+/// Checks a failed call against the catalog entry it should have raised.
+fn assert_contract_error(status: u64, message: &str, expected: ErrorCode) {
+    assert_eq!(status, 4, "unexpected status for `{message}`");
+    assert_eq!(
+        decode_error(message),
+        Some(expected),
+        "unexpected failure `{message}`"
+    );
+}
@@ -1,23 +1,37 @@
 use crate::constants::*;
-use common::structs::{AgentDetails, JobData, MetadataEntry, ServiceConfigInput};
+use common::roles::Role;
+use common::structs::{
+    AgentDetails, JobData, JobQuote, MetadataEntry, ServiceConfigInput, SyncedAgent, SyncedJob,
+    ValidationRequestData,
+};
 use identity_registry::storage::StorageModule;
+use multiversx_sc::contract_base::ContractBase;
 use multiversx_sc::proxy_imports::MultiValue2;
 use multiversx_sc::proxy_imports::OptionalValue;
 use multiversx_sc::types::{
-    BigUint, EgldOrEsdtTokenPayment, EsdtTokenIdentifier, ManagedAddress, ManagedArgBuffer,
-    ManagedBuffer, MultiValueEncoded, ReturnsNewManagedAddress, ReturnsResult, TestEsdtTransfer,
-    TokenId,
+    BigUint, DurationMillis, DurationSeconds, EgldOrEsdtTokenIdentifier, EgldOrEsdtTokenPayment,
+    EsdtTokenIdentifier, EsdtTokenPayment, ManagedAddress, ManagedArgBuffer, ManagedBuffer,
+    ManagedVec, MultiEsdtPayment, MultiValueEncoded, RHListItem, RHListItemExec,
+    ReturnsNewManagedAddress, ReturnsResult, TestEsdtTransfer, TimestampSeconds, TokenId, TxEnv,
 };
 use multiversx_sc_scenario::{
-    ScenarioTxRun, ScenarioTxWhitebox, ScenarioWorld, api::StaticApi, imports::ExpectMessage,
+    ScenarioTxRun, ScenarioTxWhitebox, ScenarioWorld,
+    api::StaticApi,
+    imports::{ExpectMessage, TestTokenIdentifier},
+    scenario_model::{TxExpect, TxResponse},
 };
 use proxies::{
     identity_registry_proxy::IdentityRegistryProxy,
-    reputation_registry_proxy::ReputationRegistryProxy,
+    reputation_registry_proxy::{
+        FeedbackAuth, FeedbackData, FeedbackDispute, FeedbackResponse, LeaderboardEntry,
+        ReputationRegistryProxy, ServiceReputation,
+    },
     validation_registry_proxy::ValidationRegistryProxy,
 };
 use validation_registry::storage::ExternalStorageModule;
 
+use ed25519_dalek::{Signer, SigningKey};
+
 pub fn world() -> ScenarioWorld {
     let mut blockchain = ScenarioWorld::new();
     blockchain.register_contract(IDENTITY_CODE, identity_registry::ContractBuilder);
@@ -26,6 +40,259 @@ pub fn world() -> ScenarioWorld {
     blockchain
 }
 
+/// Result handler for helpers that cover both outcomes of a call: with `None`
+/// the transaction must succeed, with `Some(message)` it must fail with it.
+pub struct ExpectOutcome<'a>(pub Option<&'a str>);
+
+impl<Env, Original> RHListItem<Env, Original> for ExpectOutcome<'_>
+where
+    Env: TxEnv,
+{
+    type Returns = ();
+}
+
+impl<Env, Original> RHListItemExec<TxResponse, Env, Original> for ExpectOutcome<'_>
+where
+    Env: TxEnv<RHExpect = TxExpect>,
+{
+    fn item_preprocessing(&self, prev: TxExpect) -> TxExpect {
+        match self.0 {
+            Some(err_msg) => {
+                <ExpectMessage as RHListItemExec<TxResponse, Env, Original>>::item_preprocessing(
+                    &ExpectMessage(err_msg),
+                    prev,
+                )
+            }
+            None => prev,
+        }
+    }
+
+    fn item_process_result(self, _: &TxResponse) -> Self::Returns {}
+}
+
+// Pause and role endpoints come from `common::pause` / `common::roles` and are
+// identical on every contract, so these helpers take the contract address.
+
+pub fn pause(
+    world: &mut ScenarioWorld,
+    from: &multiversx_sc::types::TestAddress,
+    contract: multiversx_sc::types::TestSCAddress,
+    expected_err: Option<&str>,
+) {
+    world
+        .tx()
+        .from(*from)
+        .to(contract)
+        .raw_call("pause")
+        .returns(ExpectOutcome(expected_err))
+        .run();
+}
+
+pub fn unpause(
+    world: &mut ScenarioWorld,
+    from: &multiversx_sc::types::TestAddress,
+    contract: multiversx_sc::types::TestSCAddress,
+    expected_err: Option<&str>,
+) {
+    world
+        .tx()
+        .from(*from)
+        .to(contract)
+        .raw_call("unpause")
+        .returns(ExpectOutcome(expected_err))
+        .run();
+}
+
+pub fn query_is_paused(
+    world: &mut ScenarioWorld,
+    contract: multiversx_sc::types::TestSCAddress,
+) -> bool {
+    world
+        .query()
+        .to(contract)
+        .raw_call("is_paused")
+        .original_result::<bool>()
+        .returns(ReturnsResult)
+        .run()
+}
+
+pub fn grant_role(
+    world: &mut ScenarioWorld,
+    from: &multiversx_sc::types::TestAddress,
+    contract: multiversx_sc::types::TestSCAddress,
+    role: Role,
+    address: &multiversx_sc::types::TestAddress,
+    expected_err: Option<&str>,
+) {
+    world
+        .tx()
+        .from(*from)
+        .to(contract)
+        .raw_call("grant_role")
+        .argument(&role)
+        .argument(&address.to_managed_address::<StaticApi>())
+        .returns(ExpectOutcome(expected_err))
+        .run();
+}
+
+pub fn revoke_role(
+    world: &mut ScenarioWorld,
+    from: &multiversx_sc::types::TestAddress,
+    contract: multiversx_sc::types::TestSCAddress,
+    role: Role,
+    address: &multiversx_sc::types::TestAddress,
+) {
+    world
+        .tx()
+        .from(*from)
+        .to(contract)
+        .raw_call("revoke_role")
+        .argument(&role)
+        .argument(&address.to_managed_address::<StaticApi>())
+        .run();
+}
+
+pub fn query_has_role(
+    world: &mut ScenarioWorld,
+    contract: multiversx_sc::types::TestSCAddress,
+    role: Role,
+    address: &multiversx_sc::types::TestAddress,
+) -> bool {
+    world
+        .query()
+        .to(contract)
+        .raw_call("has_role")
+        .argument(&role)
+        .argument(&address.to_managed_address::<StaticApi>())
+        .original_result::<bool>()
+        .returns(ReturnsResult)
+        .run()
+}
+
+pub fn transfer_ownership(
+    world: &mut ScenarioWorld,
+    from: &multiversx_sc::types::TestAddress,
+    contract: multiversx_sc::types::TestSCAddress,
+    new_owner: &multiversx_sc::types::TestAddress,
+    expected_err: Option<&str>,
+) {
+    world
+        .tx()
+        .from(*from)
+        .to(contract)
+        .raw_call("transfer_ownership")
+        .argument(&new_owner.to_managed_address::<StaticApi>())
+        .returns(ExpectOutcome(expected_err))
+        .run();
+}
+
+pub fn accept_ownership(
+    world: &mut ScenarioWorld,
+    from: &multiversx_sc::types::TestAddress,
+    contract: multiversx_sc::types::TestSCAddress,
+    expected_err: Option<&str>,
+) {
+    world
+        .tx()
+        .from(*from)
+        .to(contract)
+        .raw_call("accept_ownership")
+        .returns(ExpectOutcome(expected_err))
+        .run();
+}
+
+/// Moves the native contract owner (upgrade rights) with the
+/// `ChangeOwnerAddress` built-in call.
+pub fn change_owner_address(
+    world: &mut ScenarioWorld,
+    from: &multiversx_sc::types::TestAddress,
+    contract: multiversx_sc::types::TestSCAddress,
+    new_owner: &multiversx_sc::types::TestAddress,
+) {
+    world
+        .tx()
+        .from(*from)
+        .to(contract)
+        .typed(multiversx_sc::types::system_proxy::UserBuiltinProxy)
+        .change_owner_address(&new_owner.to_managed_address())
+        .run();
+}
+
+pub fn query_owner(
+    world: &mut ScenarioWorld,
+    contract: multiversx_sc::types::TestSCAddress,
+) -> ManagedAddress<StaticApi> {
+    world
+        .query()
+        .to(contract)
+        .raw_call("get_owner")
+        .original_result::<ManagedAddress<StaticApi>>()
+        .returns(ReturnsResult)
+        .run()
+}
+
+pub fn query_storage_version(
+    world: &mut ScenarioWorld,
+    contract: multiversx_sc::types::TestSCAddress,
+) -> u32 {
+    world
+        .query()
+        .to(contract)
+        .raw_call("get_storage_version")
+        .original_result::<u32>()
+        .returns(ReturnsResult)
+        .run()
+}
+
+pub fn query_error_codes(
+    world: &mut ScenarioWorld,
+    contract: multiversx_sc::types::TestSCAddress,
+) -> Vec<(u32, String)> {
+    world
+        .query()
+        .to(contract)
+        .raw_call("get_error_codes")
+        .original_result::<MultiValueEncoded<StaticApi, MultiValue2<u32, ManagedBuffer<StaticApi>>>>()
+        .returns(ReturnsResult)
+        .run()
+        .into_iter()
+        .map(|entry| {
+            let (code, description) = entry.into_tuple();
+            (code, description.to_string())
+        })
+        .collect()
+}
+
+/// Builds a `FeedbackAuth` signed with `signing_key` over the payload the
+/// reputation registry reconstructs in `feedback_auth_message`.
+pub fn sign_feedback_auth(
+    signing_key: &SigningKey,
+    agent_nonce: u64,
+    client: &multiversx_sc::types::TestAddress,
+    index_limit: u64,
+    expiry: u64,
+    signer: &multiversx_sc::types::TestAddress,
+) -> FeedbackAuth<StaticApi> {
+    let mut message = Vec::new();
+    message.extend_from_slice(REPUTATION_SC_ADDRESS.to_address().as_bytes());
+    message.extend_from_slice(&agent_nonce.to_be_bytes());
+    message.extend_from_slice(client.to_address().as_bytes());
+    message.extend_from_slice(&index_limit.to_be_bytes());
+    message.extend_from_slice(&expiry.to_be_bytes());
+    message.extend_from_slice(signer.to_address().as_bytes());
+
+    let signature = signing_key.sign(&message);
+
+    FeedbackAuth {
+        agent_nonce,
+        client_address: client.to_managed_address(),
+        index_limit,
+        expiry: TimestampSeconds::new(expiry),
+        signer: signer.to_managed_address(),
+        signature: ManagedBuffer::from(&signature.to_bytes()[..]),
+    }
+}
+
 pub struct AgentTestState {
     pub world: ScenarioWorld,
     pub identity_sc: ManagedAddress<StaticApi>,
@@ -95,7 +362,8 @@ impl AgentTestState {
             .nonce(1)
             .balance(1_000_000u64)
             .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64)
-            .esdt_balance(WRONG_TOKEN, 1_000_000_000u64);
+            .esdt_balance(WRONG_TOKEN, 1_000_000_000u64)
+            .esdt_nft_balance(META_TOKEN, META_TOKEN_NONCE, 1_000_000u64, ());
         world.account(WORKER).nonce(1).balance(1_000_000u64);
         world.account(VALIDATOR).nonce(1).balance(1_000_000u64);
 
@@ -339,10 +607,16 @@ impl AgentTestState {
             .from(*from)
             .to(VALIDATION_SC_ADDRESS)
             .typed(ValidationRegistryProxy)
-            .init_job(ManagedBuffer::from(job_id), agent_nonce, svc)
+            .init_job(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                svc,
+                OptionalValue::<bool>::None,
+            )
             .run();
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn init_job_with_payment(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -362,6 +636,7 @@ impl AgentTestState {
                 ManagedBuffer::from(job_id),
                 agent_nonce,
                 OptionalValue::Some(service_id),
+                OptionalValue::<bool>::None,
             )
             .esdt(TestEsdtTransfer(
                 multiversx_sc_scenario::imports::TestTokenIdentifier::new(token),
@@ -371,6 +646,7 @@ impl AgentTestState {
             .run();
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn init_job_with_payment_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -391,6 +667,7 @@ impl AgentTestState {
                 ManagedBuffer::from(job_id),
                 agent_nonce,
                 OptionalValue::Some(service_id),
+                OptionalValue::<bool>::None,
             )
             .esdt(TestEsdtTransfer(
                 multiversx_sc_scenario::imports::TestTokenIdentifier::new(token),
@@ -401,6 +678,132 @@ impl AgentTestState {
             .run();
     }
 
+    pub fn init_job_with_egld(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        service_id: u32,
+        amount: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init_job(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                OptionalValue::Some(service_id),
+                OptionalValue::<bool>::None,
+            )
+            .egld(amount)
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    /// `init_job` accepting the open quote; EGLD is sent as call value and
+    /// nothing is sent for a zero amount.
+    pub fn init_quoted_job(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        service_id: u32,
+        payment: (&str, u64, u64),
+        expected_err: Option<&str>,
+    ) {
+        let (token, token_nonce, amount) = payment;
+        let tx = self
+            .world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init_job(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                OptionalValue::Some(service_id),
+                OptionalValue::Some(true),
+            );
+        if amount == 0 {
+            tx.returns(ExpectOutcome(expected_err)).run();
+        } else if token == "EGLD-000000" {
+            tx.egld(amount).returns(ExpectOutcome(expected_err)).run();
+        } else {
+            tx.esdt(TestEsdtTransfer(
+                TestTokenIdentifier::new(token),
+                token_nonce,
+                amount,
+            ))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn post_quote(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        employer: &multiversx_sc::types::TestAddress,
+        price: (&[u8], u64, u64),
+        expires_at: u64,
+        expected_err: Option<&str>,
+    ) {
+        let (token, token_nonce, amount) = price;
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .post_quote(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                employer.to_managed_address(),
+                TokenId::from(token),
+                token_nonce,
+                BigUint::from(amount),
+                TimestampSeconds::new(expires_at),
+            )
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn cancel_quote(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .cancel_quote(ManagedBuffer::from(job_id), agent_nonce)
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn query_job_quote(
+        &mut self,
+        job_id: &[u8],
+        agent_nonce: u64,
+    ) -> Option<JobQuote<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_job_quote(ManagedBuffer::from(job_id), agent_nonce)
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+    }
+
     pub fn submit_proof(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -532,6 +935,7 @@ impl AgentTestState {
             .run();
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn validation_response_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -572,8 +976,74 @@ impl AgentTestState {
             .run();
     }
 
-    // ── Reputation Registry ──
+    pub fn set_job_retention(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        retention_ms: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_job_retention(DurationMillis::new(retention_ms))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn query_job_retention(&mut self) -> u64 {
+        let retention = self
+            .world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_job_retention()
+            .returns(ReturnsResult)
+            .run();
+        retention.as_u64_millis()
+    }
+
+    pub fn query_validation_status(
+        &mut self,
+        request_hash: &[u8],
+    ) -> OptionalValue<ValidationRequestData<StaticApi>> {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_validation_status(ManagedBuffer::from(request_hash))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_agent_validations(&mut self, agent_nonce: u64) -> usize {
+        let validations = self
+            .world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .get_agent_validations(agent_nonce)
+            .returns(ReturnsResult)
+            .run();
+        validations.len()
+    }
+
+    pub fn clean_job_feedback(&mut self, job_ids: Vec<&[u8]>) {
+        let mut ids_encoded = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for id in &job_ids {
+            ids_encoded.push(ManagedBuffer::from(*id));
+        }
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .clean_job_feedback(ids_encoded)
+            .run();
+    }
 
+    // ── Reputation Registry ──
 
     pub fn give_feedback_simple(
         &mut self,
@@ -635,6 +1105,89 @@ impl AgentTestState {
             .run();
     }
 
+    pub fn give_feedback(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        value: i64,
+        tag1: &[u8],
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .give_feedback(
+                agent_nonce,
+                value,
+                0u8,
+                ManagedBuffer::from(tag1),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                OptionalValue::<FeedbackAuth<StaticApi>>::None,
+            )
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn give_feedback_with_auth(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        value: i64,
+        feedback_auth: FeedbackAuth<StaticApi>,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .give_feedback(
+                agent_nonce,
+                value,
+                0u8,
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                ManagedBuffer::new(),
+                OptionalValue::Some(feedback_auth),
+            )
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn append_feedback_response(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        client: &multiversx_sc::types::TestAddress,
+        feedback_index: u64,
+        response_uri: &[u8],
+        response_hash: &[u8],
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .append_feedback_response(
+                agent_nonce,
+                client.to_managed_address(),
+                feedback_index,
+                ManagedBuffer::from(response_uri),
+                ManagedBuffer::from(response_hash),
+            )
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
     // ── Queries ──
 
     pub fn query_agent_details(&mut self, nonce: u64) -> AgentDetails<StaticApi> {
@@ -725,48 +1278,209 @@ impl AgentTestState {
             .run()
     }
 
-    pub fn query_has_given_feedback(&mut self, job_id: &[u8]) -> bool {
+    pub fn query_service_reputation(
+        &mut self,
+        agent_nonce: u64,
+        service_id: u32,
+    ) -> ServiceReputation<StaticApi> {
         self.world
             .query()
             .to(REPUTATION_SC_ADDRESS)
             .typed(ReputationRegistryProxy)
-            .has_given_feedback(ManagedBuffer::from(job_id))
+            .get_service_reputation(agent_nonce, service_id)
             .returns(ReturnsResult)
             .run()
     }
 
-    // query_is_feedback_authorized removed — ERC-8004 compliance
-
-    pub fn query_agent_response(&mut self, job_id: &[u8]) -> ManagedBuffer<StaticApi> {
+    pub fn query_ranked_services(
+        &mut self,
+        agent_nonce: u64,
+    ) -> ManagedVec<StaticApi, ServiceReputation<StaticApi>> {
         self.world
             .query()
             .to(REPUTATION_SC_ADDRESS)
             .typed(ReputationRegistryProxy)
-            .agent_response(ManagedBuffer::from(job_id))
+            .get_ranked_services(agent_nonce)
             .returns(ReturnsResult)
             .run()
     }
 
-    // ── Upgrade helpers ──
+    pub fn query_top_agents(
+        &mut self,
+        count: u32,
+    ) -> ManagedVec<StaticApi, LeaderboardEntry<StaticApi>> {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_top_agents(count)
+            .returns(ReturnsResult)
+            .run()
+    }
 
-    pub fn upgrade_identity(&mut self) {
+    pub fn query_top_agents_for_service(
+        &mut self,
+        service_id: u32,
+        count: u32,
+    ) -> ManagedVec<StaticApi, LeaderboardEntry<StaticApi>> {
         self.world
-            .tx()
-            .from(OWNER_ADDRESS)
-            .to(IDENTITY_SC_ADDRESS)
-            .typed(IdentityRegistryProxy)
-            .upgrade()
-            .code(IDENTITY_CODE)
-            .run();
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_top_agents_for_service(service_id, count)
+            .returns(ReturnsResult)
+            .run()
     }
 
-    pub fn upgrade_validation(&mut self) {
+    /// Agent nonces of the stored overall board, without the view's trimming.
+    pub fn stored_leaderboard(&mut self) -> Vec<u64> {
+        let mut nonces = Vec::new();
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(REPUTATION_SC_ADDRESS)
+            .whitebox(reputation_registry::contract_obj, |sc| {
+                use reputation_registry::storage::StorageModule as _;
+                nonces = sc
+                    .leaderboard()
+                    .get()
+                    .iter()
+                    .map(|entry| entry.agent_nonce)
+                    .collect();
+            });
+        nonces
+    }
+
+    pub fn query_feedback_dispute(&mut self, job_id: &[u8]) -> FeedbackDispute<StaticApi> {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .feedback_dispute(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_client_feedback_dispute(
+        &mut self,
+        agent_nonce: u64,
+        client: &multiversx_sc::types::TestAddress,
+        feedback_index: u64,
+    ) -> FeedbackDispute<StaticApi> {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .client_feedback_dispute(agent_nonce, client.to_managed_address(), feedback_index)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_read_feedback(
+        &mut self,
+        agent_nonce: u64,
+        client: &multiversx_sc::types::TestAddress,
+        feedback_index: u64,
+    ) -> FeedbackData<StaticApi> {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .read_feedback(agent_nonce, client.to_managed_address(), feedback_index)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_response_count(
+        &mut self,
+        agent_nonce: u64,
+        client: &multiversx_sc::types::TestAddress,
+        feedback_index: u64,
+    ) -> u64 {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .get_response_count(agent_nonce, client.to_managed_address(), feedback_index)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_responses(
+        &mut self,
+        agent_nonce: u64,
+        client: &multiversx_sc::types::TestAddress,
+        feedback_index: u64,
+        from: u64,
+        size: u64,
+    ) -> Vec<FeedbackResponse<StaticApi>> {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .read_responses(
+                agent_nonce,
+                client.to_managed_address(),
+                feedback_index,
+                from,
+                size,
+            )
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect()
+    }
+
+    pub fn query_has_given_feedback(&mut self, job_id: &[u8]) -> bool {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .has_given_feedback(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    // query_is_feedback_authorized removed — ERC-8004 compliance
+
+    pub fn query_agent_response(&mut self, job_id: &[u8]) -> ManagedBuffer<StaticApi> {
+        self.world
+            .query()
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .agent_response(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    // ── Upgrade helpers ──
+
+    pub fn upgrade_identity(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .upgrade()
+            .code(IDENTITY_CODE)
+            .run();
+    }
+
+    pub fn upgrade_validation(&mut self) {
+        self.upgrade_validation_with_jobs(&[]);
+    }
+
+    pub fn upgrade_validation_with_jobs(&mut self, job_ids: &[&[u8]]) {
+        let mut ids = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for id in job_ids {
+            ids.push(ManagedBuffer::from(*id));
+        }
         self.world
             .tx()
             .from(OWNER_ADDRESS)
             .to(VALIDATION_SC_ADDRESS)
             .typed(ValidationRegistryProxy)
-            .upgrade()
+            .upgrade(ids)
             .code(VALIDATION_CODE)
             .run();
     }
@@ -801,6 +1515,7 @@ impl AgentTestState {
 
     // ── Update agent (raw call with NFT transfer) ──
 
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     pub fn update_agent_raw(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -875,6 +1590,145 @@ impl AgentTestState {
             .run();
     }
 
+    // ── Registration fee and bond ──
+
+    pub fn set_identity_treasury(&mut self, treasury: &multiversx_sc::types::TestAddress) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .set_treasury(treasury.to_managed_address())
+            .run();
+    }
+
+    pub fn set_registration_fee(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        token: EgldOrEsdtTokenIdentifier<StaticApi>,
+        amount: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .set_registration_fee(token, BigUint::from(amount))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn set_registration_bond(
+        &mut self,
+        token: EgldOrEsdtTokenIdentifier<StaticApi>,
+        amount: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .set_registration_bond(token, BigUint::from(amount))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    fn registration_payments(
+        payments: &[(EgldOrEsdtTokenIdentifier<StaticApi>, u64)],
+    ) -> ManagedVec<StaticApi, EgldOrEsdtTokenPayment<StaticApi>> {
+        let mut transfers = ManagedVec::new();
+        for (token, amount) in payments {
+            transfers.push(EgldOrEsdtTokenPayment::new(
+                token.clone(),
+                0,
+                BigUint::from(*amount),
+            ));
+        }
+        transfers
+    }
+
+    /// Registers an agent without metadata or services, paying `payments`.
+    pub fn register_agent_paying(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        name: &[u8],
+        payments: &[(EgldOrEsdtTokenIdentifier<StaticApi>, u64)],
+        expected_err: Option<&str>,
+    ) {
+        let args = Self::register_agent_raw_args(name, b"https://agent.com", b"pubkey", &[], &[]);
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .raw_call("register_agent")
+            .arguments_raw(args)
+            .payment(Self::registration_payments(payments))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn deregister_agent(&mut self, from: &multiversx_sc::types::TestAddress, nft_nonce: u64) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .deregister_agent()
+            .esdt(TestEsdtTransfer(AGENT_TOKEN, nft_nonce, 1))
+            .run();
+    }
+
+    pub fn slash_bond(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        nonce: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .slash_bond(nonce)
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn query_registration_fee(&mut self) -> Option<EgldOrEsdtTokenPayment<StaticApi>> {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .get_registration_fee()
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+    }
+
+    pub fn query_registration_bond(&mut self) -> Option<EgldOrEsdtTokenPayment<StaticApi>> {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .get_registration_bond()
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+    }
+
+    pub fn query_agent_bond(&mut self, nonce: u64) -> Option<EgldOrEsdtTokenPayment<StaticApi>> {
+        self.world
+            .query()
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .get_agent_bond(nonce)
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+    }
+
     // ── Admin config setters ──
 
     pub fn set_identity_registry_address(
@@ -967,96 +1821,244 @@ impl AgentTestState {
             .run();
     }
 
-    // ── Error-path helpers ──
-
-    pub fn init_job_expect_err(
+    pub fn set_feedback_auth_required(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        agent_nonce: u64,
-        service_id: Option<u32>,
-        err_msg: &str,
+        required: bool,
+        expected_err: Option<&str>,
     ) {
-        let svc = match service_id {
-            Some(sid) => OptionalValue::Some(sid),
-            None => OptionalValue::None,
-        };
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .init_job(ManagedBuffer::from(job_id), agent_nonce, svc)
-            .returns(ExpectMessage(err_msg))
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .set_feedback_auth_required(required)
+            .returns(ExpectOutcome(expected_err))
             .run();
     }
 
-    pub fn submit_proof_expect_err(
+    pub fn set_leaderboard_config(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        proof: &[u8],
-        err_msg: &str,
+        size: u32,
+        min_jobs: u64,
+        expected_err: Option<&str>,
     ) {
         self.world
             .tx()
             .from(*from)
-            .to(VALIDATION_SC_ADDRESS)
-            .typed(ValidationRegistryProxy)
-            .submit_proof(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
-            .returns(ExpectMessage(err_msg))
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .set_leaderboard_config(size, min_jobs)
+            .returns(ExpectOutcome(expected_err))
             .run();
     }
 
-    pub fn set_metadata_expect_err(
+    pub fn flag_feedback(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        nonce: u64,
-        entries: Vec<(&[u8], &[u8])>,
-        err_msg: &str,
+        job_id: &[u8],
+        evidence_uri: &[u8],
+        expected_err: Option<&str>,
     ) {
-        let mut args = ManagedArgBuffer::<StaticApi>::new();
-        args.push_arg(nonce);
-        args.push_arg(entries.len());
-        for (k, v) in &entries {
-            args.push_arg(MetadataEntry::<StaticApi> {
-                key: ManagedBuffer::from(*k),
-                value: ManagedBuffer::from(*v),
-            });
-        }
         self.world
             .tx()
             .from(*from)
-            .to(IDENTITY_SC_ADDRESS)
-            .raw_call("set_metadata")
-            .arguments_raw(args)
-            .returns(ExpectMessage(err_msg))
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .flag_feedback(
+                ManagedBuffer::from(job_id),
+                ManagedBuffer::from(evidence_uri),
+            )
+            .returns(ExpectOutcome(expected_err))
             .run();
     }
 
-    pub fn set_service_configs_expect_err(
+    pub fn uphold_feedback(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        nonce: u64,
-        services: Vec<(u32, u64, &[u8], u64)>,
-        err_msg: &str,
+        job_id: &[u8],
+        expected_err: Option<&str>,
     ) {
-        let mut args = ManagedArgBuffer::<StaticApi>::new();
-        args.push_arg(nonce);
-        args.push_arg(services.len());
-        for (sid, price, token, tok_nonce) in &services {
-            args.push_arg(ServiceConfigInput::<StaticApi> {
-                service_id: *sid,
-                price: BigUint::from(*price),
-                token: TokenId::from(*token),
-                nonce: *tok_nonce,
-            });
-        }
         self.world
             .tx()
             .from(*from)
-            .to(IDENTITY_SC_ADDRESS)
-            .raw_call("set_service_configs")
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .uphold_feedback(ManagedBuffer::from(job_id))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn strike_feedback(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .strike_feedback(ManagedBuffer::from(job_id))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn flag_client_feedback(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        client: &multiversx_sc::types::TestAddress,
+        feedback_index: u64,
+        evidence_uri: &[u8],
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .flag_client_feedback(
+                agent_nonce,
+                client.to_managed_address(),
+                feedback_index,
+                ManagedBuffer::from(evidence_uri),
+            )
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn uphold_client_feedback(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        client: &multiversx_sc::types::TestAddress,
+        feedback_index: u64,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .uphold_client_feedback(agent_nonce, client.to_managed_address(), feedback_index)
+            .run();
+    }
+
+    pub fn strike_client_feedback(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        agent_nonce: u64,
+        client: &multiversx_sc::types::TestAddress,
+        feedback_index: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(REPUTATION_SC_ADDRESS)
+            .typed(ReputationRegistryProxy)
+            .strike_client_feedback(agent_nonce, client.to_managed_address(), feedback_index)
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    // ── Error-path helpers ──
+
+    pub fn init_job_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        service_id: Option<u32>,
+        err_msg: &str,
+    ) {
+        let svc = match service_id {
+            Some(sid) => OptionalValue::Some(sid),
+            None => OptionalValue::None,
+        };
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init_job(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                svc,
+                OptionalValue::<bool>::None,
+            )
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn submit_proof_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        proof: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .submit_proof(ManagedBuffer::from(job_id), ManagedBuffer::from(proof))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn set_metadata_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        nonce: u64,
+        entries: Vec<(&[u8], &[u8])>,
+        err_msg: &str,
+    ) {
+        let mut args = ManagedArgBuffer::<StaticApi>::new();
+        args.push_arg(nonce);
+        args.push_arg(entries.len());
+        for (k, v) in &entries {
+            args.push_arg(MetadataEntry::<StaticApi> {
+                key: ManagedBuffer::from(*k),
+                value: ManagedBuffer::from(*v),
+            });
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .raw_call("set_metadata")
+            .arguments_raw(args)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn set_service_configs_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        nonce: u64,
+        services: Vec<(u32, u64, &[u8], u64)>,
+        err_msg: &str,
+    ) {
+        let mut args = ManagedArgBuffer::<StaticApi>::new();
+        args.push_arg(nonce);
+        args.push_arg(services.len());
+        for (sid, price, token, tok_nonce) in &services {
+            args.push_arg(ServiceConfigInput::<StaticApi> {
+                service_id: *sid,
+                price: BigUint::from(*price),
+                token: TokenId::from(*token),
+                nonce: *tok_nonce,
+            });
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .raw_call("set_service_configs")
             .arguments_raw(args)
             .returns(ExpectMessage(err_msg))
             .run();
@@ -1104,7 +2106,6 @@ impl AgentTestState {
             .run();
     }
 
-
     pub fn append_response_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -1125,6 +2126,7 @@ impl AgentTestState {
             .run();
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn init_job_with_wrong_token_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -1145,6 +2147,7 @@ impl AgentTestState {
                 ManagedBuffer::from(job_id),
                 agent_nonce,
                 OptionalValue::Some(service_id),
+                OptionalValue::<bool>::None,
             )
             .esdt(TestEsdtTransfer(
                 multiversx_sc_scenario::imports::TestTokenIdentifier::new(token),
@@ -1242,7 +2245,8 @@ impl AgentTestState {
 // Escrow Test State — extends AgentTestState with Escrow SC
 // ════════════════════════════════════════════════════════════
 
-use escrow::storage::EscrowData;
+use escrow::storage::{BatchOutcome, EscrowData, EscrowDispute, EscrowStatus, Milestone};
+use multiversx_sc::proxy_imports::MultiValue3;
 use proxies::escrow_proxy::EscrowProxy;
 
 pub struct EscrowTestState {
@@ -1340,7 +2344,9 @@ impl EscrowTestState {
             .account(EMPLOYER)
             .nonce(1)
             .balance(10_000_000_000u64)
-            .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64);
+            .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64)
+            .esdt_balance(PROJECT_TOKEN, 1_000_000_000u64);
+        world.account(TREASURY).nonce(1);
 
         Self {
             world,
@@ -1391,6 +2397,17 @@ impl EscrowTestState {
             .run();
     }
 
+    pub fn deregister_agent(&mut self, from: &multiversx_sc::types::TestAddress, nft_nonce: u64) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .deregister_agent()
+            .esdt(TestEsdtTransfer(AGENT_TOKEN, nft_nonce, 1))
+            .run();
+    }
+
     // ── Validation helpers ──
 
     pub fn init_job(
@@ -1409,7 +2426,12 @@ impl EscrowTestState {
             .from(*from)
             .to(VALIDATION_SC_ADDRESS)
             .typed(ValidationRegistryProxy)
-            .init_job(ManagedBuffer::from(job_id), agent_nonce, svc)
+            .init_job(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                svc,
+                OptionalValue::<bool>::None,
+            )
             .run();
     }
 
@@ -1495,11 +2517,13 @@ impl EscrowTestState {
                 receiver.to_managed_address(),
                 ManagedBuffer::from(poa_hash),
                 deadline,
+                OptionalValue::<ManagedAddress<StaticApi>>::None,
             )
             .egld(amount)
             .run();
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn deposit_egld_expect_err(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -1520,12 +2544,14 @@ impl EscrowTestState {
                 receiver.to_managed_address(),
                 ManagedBuffer::from(poa_hash),
                 deadline,
+                OptionalValue::<ManagedAddress<StaticApi>>::None,
             )
             .egld(amount)
             .returns(ExpectMessage(err_msg))
             .run();
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn deposit_esdt(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
@@ -1547,6 +2573,7 @@ impl EscrowTestState {
                 receiver.to_managed_address(),
                 ManagedBuffer::from(poa_hash),
                 deadline,
+                OptionalValue::<ManagedAddress<StaticApi>>::None,
             )
             .esdt(TestEsdtTransfer(
                 multiversx_sc_scenario::imports::TestTokenIdentifier::new(token),
@@ -1556,83 +2583,862 @@ impl EscrowTestState {
             .run();
     }
 
-    pub fn release(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+    pub fn deposit_for_agent(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        deadline: u64,
+        amount: u64,
+        expected_err: Option<&str>,
+    ) {
         self.world
             .tx()
             .from(*from)
             .to(ESCROW_SC_ADDRESS)
             .typed(EscrowProxy)
-            .release(ManagedBuffer::from(job_id))
+            .deposit_for_agent(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                ManagedBuffer::from(b"poa"),
+                deadline,
+                OptionalValue::<ManagedAddress<StaticApi>>::None,
+            )
+            .esdt(TestEsdtTransfer(PAYMENT_TOKEN, 0, amount))
+            .returns(ExpectOutcome(expected_err))
             .run();
     }
 
-    pub fn release_expect_err(
-        &mut self,
-        from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        err_msg: &str,
-    ) {
+    // ── Escrowed service payments (init_job -> deposit_for_job) ──
+
+    pub fn set_escrow_contract_address(&mut self) {
         self.world
             .tx()
-            .from(*from)
-            .to(ESCROW_SC_ADDRESS)
-            .typed(EscrowProxy)
-            .release(ManagedBuffer::from(job_id))
-            .returns(ExpectMessage(err_msg))
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_escrow_contract_address(ESCROW_SC_ADDRESS)
             .run();
     }
 
-    pub fn refund(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+    fn service_escrow_durations(
+        durations: &[(u32, u64)],
+    ) -> MultiValueEncoded<StaticApi, MultiValue2<u32, DurationSeconds>> {
+        let mut encoded = MultiValueEncoded::new();
+        for (service_id, duration) in durations {
+            encoded.push(MultiValue2::from((
+                *service_id,
+                DurationSeconds::new(*duration),
+            )));
+        }
+        encoded
+    }
+
+    pub fn set_service_escrow_durations(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        nonce: u64,
+        durations: &[(u32, u64)],
+        expected_err: Option<&str>,
+    ) {
         self.world
             .tx()
             .from(*from)
-            .to(ESCROW_SC_ADDRESS)
-            .typed(EscrowProxy)
-            .refund(ManagedBuffer::from(job_id))
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .set_service_escrow_durations(nonce, Self::service_escrow_durations(durations))
+            .returns(ExpectOutcome(expected_err))
             .run();
     }
 
-    pub fn refund_expect_err(
+    pub fn remove_service_configs(
         &mut self,
         from: &multiversx_sc::types::TestAddress,
-        job_id: &[u8],
-        err_msg: &str,
+        nonce: u64,
+        service_ids: Vec<u32>,
     ) {
+        let mut ids_encoded = MultiValueEncoded::<StaticApi, u32>::new();
+        for sid in &service_ids {
+            ids_encoded.push(*sid);
+        }
         self.world
             .tx()
             .from(*from)
-            .to(ESCROW_SC_ADDRESS)
-            .typed(EscrowProxy)
-            .refund(ManagedBuffer::from(job_id))
-            .returns(ExpectMessage(err_msg))
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .remove_service_configs(nonce, ids_encoded)
             .run();
     }
 
-    // ── Escrow queries ──
-
-    pub fn query_escrow(&mut self, job_id: &[u8]) -> EscrowData<StaticApi> {
-        self.world
+    pub fn query_service_escrow_duration(&mut self, nonce: u64, service_id: u32) -> Option<u64> {
+        let result = self
+            .world
             .query()
-            .to(ESCROW_SC_ADDRESS)
-            .typed(EscrowProxy)
-            .get_escrow(ManagedBuffer::from(job_id))
+            .to(IDENTITY_SC_ADDRESS)
+            .typed(IdentityRegistryProxy)
+            .get_service_escrow_duration(nonce, service_id)
             .returns(ReturnsResult)
-            .run()
+            .run();
+        result
+            .into_option()
+            .map(|duration| duration.as_u64_seconds())
     }
 
-    pub fn query_is_job_verified(&mut self, job_id: &[u8]) -> bool {
+    pub fn init_job_with_payment(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        service_id: u32,
+        amount: u64,
+    ) {
         self.world
-            .query()
+            .tx()
+            .from(*from)
             .to(VALIDATION_SC_ADDRESS)
             .typed(ValidationRegistryProxy)
-            .is_job_verified(ManagedBuffer::from(job_id))
-            .returns(ReturnsResult)
-            .run()
+            .init_job(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                OptionalValue::Some(service_id),
+                OptionalValue::<bool>::None,
+            )
+            .esdt(TestEsdtTransfer(PAYMENT_TOKEN, 0, amount))
+            .run();
     }
 
-    /// Whitebox helper: directly set job status to Verified in the validation registry.
-    ///
-    /// NOTE: The current `validation_response` endpoint does NOT transition job status
+    pub fn init_job_with_payment_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        service_id: u32,
+        amount: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init_job(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                OptionalValue::Some(service_id),
+                OptionalValue::<bool>::None,
+            )
+            .esdt(TestEsdtTransfer(PAYMENT_TOKEN, 0, amount))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn deposit_for_job_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        agent_nonce: u64,
+        deadline: u64,
+        amount: u64,
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .deposit_for_job(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                *from,
+                TimestampSeconds::new(deadline),
+            )
+            .esdt(TestEsdtTransfer(PAYMENT_TOKEN, 0, amount))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn deposit_bundle(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        receiver: &multiversx_sc::types::TestAddress,
+        deadline: u64,
+        payments: &[(TestTokenIdentifier, u64)],
+    ) {
+        let mut transfers = MultiEsdtPayment::<StaticApi>::new();
+        for (token, amount) in payments {
+            transfers.push(EsdtTokenPayment::new(
+                token.to_token_identifier(),
+                0,
+                BigUint::from(*amount),
+            ));
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .deposit(
+                ManagedBuffer::from(job_id),
+                receiver.to_managed_address(),
+                ManagedBuffer::from(b"poa"),
+                deadline,
+                OptionalValue::<ManagedAddress<StaticApi>>::None,
+            )
+            .payment(transfers)
+            .run();
+    }
+
+    pub fn top_up(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        token: TestTokenIdentifier,
+        amount: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .top_up(ManagedBuffer::from(job_id))
+            .esdt(TestEsdtTransfer(token, 0, amount))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn extend_deadline(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        new_deadline: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .extend_deadline(
+                ManagedBuffer::from(job_id),
+                TimestampSeconds::new(new_deadline),
+            )
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn mutual_cancel(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .mutual_cancel(ManagedBuffer::from(job_id))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn query_cancel_request(&mut self, job_id: &[u8]) -> Option<ManagedAddress<StaticApi>> {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_cancel_request(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+            .into_option()
+    }
+
+    pub fn release(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .release(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn release_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .release(ManagedBuffer::from(job_id))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn refund(&mut self, from: &multiversx_sc::types::TestAddress, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .refund(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn refund_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .refund(ManagedBuffer::from(job_id))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    fn milestone_args(
+        milestones: &[(u64, u64, &[u8])],
+    ) -> MultiValueEncoded<
+        StaticApi,
+        MultiValue3<BigUint<StaticApi>, TimestampSeconds, ManagedBuffer<StaticApi>>,
+    > {
+        let mut args = MultiValueEncoded::new();
+        for (amount, deadline, verification_job_id) in milestones {
+            args.push(MultiValue3::from((
+                BigUint::from(*amount),
+                TimestampSeconds::new(*deadline),
+                ManagedBuffer::from(*verification_job_id),
+            )));
+        }
+        args
+    }
+
+    pub fn deposit_milestones_esdt(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        receiver: &multiversx_sc::types::TestAddress,
+        milestones: &[(u64, u64, &[u8])],
+        amount: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .deposit_milestones(
+                ManagedBuffer::from(job_id),
+                receiver.to_managed_address(),
+                ManagedBuffer::from(b"poa_hash"),
+                Self::milestone_args(milestones),
+            )
+            .esdt(TestEsdtTransfer(PAYMENT_TOKEN, 0, amount))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn release_milestone(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        milestone_index: usize,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .release_milestone(ManagedBuffer::from(job_id), milestone_index)
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn refund_milestone(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        milestone_index: usize,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .refund_milestone(ManagedBuffer::from(job_id), milestone_index)
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn deposit_esdt_with_arbiter(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        receiver: &multiversx_sc::types::TestAddress,
+        deadline: u64,
+        amount: u64,
+        arbiter: &multiversx_sc::types::TestAddress,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .deposit(
+                ManagedBuffer::from(job_id),
+                receiver.to_managed_address(),
+                ManagedBuffer::from(b"poa_hash"),
+                deadline,
+                OptionalValue::Some(arbiter.to_managed_address()),
+            )
+            .esdt(TestEsdtTransfer(PAYMENT_TOKEN, 0, amount))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn add_arbiter(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        arbiter: &multiversx_sc::types::TestAddress,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .add_arbiter(arbiter.to_managed_address())
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn remove_arbiter(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        arbiter: &multiversx_sc::types::TestAddress,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .remove_arbiter(arbiter.to_managed_address())
+            .run();
+    }
+
+    pub fn open_dispute(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        reason_uri: &[u8],
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .open_dispute(ManagedBuffer::from(job_id), ManagedBuffer::from(reason_uri))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn resolve_dispute(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        receiver_amount: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .resolve_dispute(ManagedBuffer::from(job_id), BigUint::from(receiver_amount))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    // ── Escrow queries ──
+
+    pub fn query_dispute(&mut self, job_id: &[u8]) -> EscrowDispute<StaticApi> {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_dispute(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_milestones(&mut self, job_id: &[u8]) -> Vec<Milestone<StaticApi>> {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_milestones(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .collect()
+    }
+
+    pub fn set_treasury(&mut self, treasury: &multiversx_sc::types::TestAddress) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .set_treasury(treasury.to_managed_address())
+            .run();
+    }
+
+    pub fn set_fee_bps(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        token: EgldOrEsdtTokenIdentifier<StaticApi>,
+        fee_bps: u32,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .set_fee_bps(token, fee_bps)
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn query_fee_bps(&mut self, token: EgldOrEsdtTokenIdentifier<StaticApi>) -> u32 {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_fee_bps(token)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_collected_fees(&mut self, token: EgldOrEsdtTokenIdentifier<StaticApi>) -> u64 {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_collected_fees(token)
+            .returns(ReturnsResult)
+            .run()
+            .to_u64()
+            .unwrap()
+    }
+
+    pub fn query_all_collected_fees(&mut self) -> Vec<(EgldOrEsdtTokenIdentifier<StaticApi>, u64)> {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_all_collected_fees()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|entry| {
+                let (token, amount) = entry.into_tuple();
+                (token, amount.to_u64().unwrap())
+            })
+            .collect()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn deposit_stream(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        receiver: &multiversx_sc::types::TestAddress,
+        start: u64,
+        end: u64,
+        amount: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .deposit_stream(
+                ManagedBuffer::from(job_id),
+                receiver.to_managed_address(),
+                ManagedBuffer::from(b"poa"),
+                TimestampSeconds::new(start),
+                TimestampSeconds::new(end),
+                OptionalValue::<ManagedAddress<StaticApi>>::None,
+            )
+            .esdt(TestEsdtTransfer(PAYMENT_TOKEN, 0, amount))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn withdraw_vested(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .withdraw_vested(ManagedBuffer::from(job_id))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn stop_stream(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .stop_stream(ManagedBuffer::from(job_id))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    /// `(vested, withdrawable)` for a streaming escrow.
+    pub fn query_vested_amount(&mut self, job_id: &[u8]) -> (u64, u64) {
+        let (vested, withdrawable) = self
+            .world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_vested_amount(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+            .into_tuple();
+        (vested.to_u64().unwrap(), withdrawable.to_u64().unwrap())
+    }
+
+    pub fn release_batch(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_ids: &[&[u8]],
+    ) -> Vec<(Vec<u8>, BatchOutcome, String)> {
+        let outcomes = self
+            .world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .release_batch(Self::batch_args(job_ids))
+            .gas(600_000_000)
+            .returns(ReturnsResult)
+            .run();
+        Self::batch_outcomes(outcomes)
+    }
+
+    pub fn refund_batch(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_ids: &[&[u8]],
+    ) -> Vec<(Vec<u8>, BatchOutcome, String)> {
+        self.refund_batch_with_gas(from, job_ids, 600_000_000)
+    }
+
+    pub fn refund_batch_with_gas(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_ids: &[&[u8]],
+        gas: u64,
+    ) -> Vec<(Vec<u8>, BatchOutcome, String)> {
+        let outcomes = self
+            .world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .refund_batch(Self::batch_args(job_ids))
+            .gas(gas)
+            .returns(ReturnsResult)
+            .run();
+        Self::batch_outcomes(outcomes)
+    }
+
+    pub fn refund_batch_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_ids: &[&[u8]],
+        err_msg: &str,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .refund_batch(Self::batch_args(job_ids))
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    fn batch_args(job_ids: &[&[u8]]) -> MultiValueEncoded<StaticApi, ManagedBuffer<StaticApi>> {
+        let mut args = MultiValueEncoded::new();
+        for job_id in job_ids {
+            args.push(ManagedBuffer::from(*job_id));
+        }
+        args
+    }
+
+    fn batch_outcomes(
+        outcomes: MultiValueEncoded<
+            StaticApi,
+            MultiValue3<ManagedBuffer<StaticApi>, BatchOutcome, ManagedBuffer<StaticApi>>,
+        >,
+    ) -> Vec<(Vec<u8>, BatchOutcome, String)> {
+        outcomes
+            .into_iter()
+            .map(|entry| {
+                let (job_id, outcome, reason) = entry.into_tuple();
+                (
+                    job_id.to_vec(),
+                    outcome,
+                    String::from_utf8(reason.to_vec()).unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    pub fn query_employer_escrows(
+        &mut self,
+        employer: &multiversx_sc::types::TestAddress,
+        from: usize,
+        size: usize,
+        status: Option<EscrowStatus>,
+    ) -> Vec<ManagedBuffer<StaticApi>> {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_employer_escrows(
+                employer.to_managed_address(),
+                from,
+                size,
+                OptionalValue::from(status),
+            )
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|entry| entry.into_tuple().0)
+            .collect()
+    }
+
+    pub fn query_receiver_escrows(
+        &mut self,
+        receiver: &multiversx_sc::types::TestAddress,
+        from: usize,
+        size: usize,
+        status: Option<EscrowStatus>,
+    ) -> Vec<ManagedBuffer<StaticApi>> {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_receiver_escrows(
+                receiver.to_managed_address(),
+                from,
+                size,
+                OptionalValue::from(status),
+            )
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|entry| entry.into_tuple().0)
+            .collect()
+    }
+
+    pub fn query_employer_escrow_count(
+        &mut self,
+        employer: &multiversx_sc::types::TestAddress,
+    ) -> usize {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_employer_escrow_count(employer.to_managed_address())
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_total_locked(&mut self, token: EgldOrEsdtTokenIdentifier<StaticApi>) -> u64 {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_total_locked(token)
+            .returns(ReturnsResult)
+            .run()
+            .to_u64()
+            .unwrap()
+    }
+
+    pub fn query_all_total_locked(&mut self) -> Vec<(EgldOrEsdtTokenIdentifier<StaticApi>, u64)> {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_all_total_locked()
+            .returns(ReturnsResult)
+            .run()
+            .into_iter()
+            .map(|entry| {
+                let (token, amount) = entry.into_tuple();
+                (token, amount.to_u64().unwrap())
+            })
+            .collect()
+    }
+
+    pub fn query_escrow(&mut self, job_id: &[u8]) -> EscrowData<StaticApi> {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_escrow(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn query_is_job_verified(&mut self, job_id: &[u8]) -> bool {
+        self.world
+            .query()
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .is_job_verified(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Whitebox helper: directly set job status to Verified in the validation registry.
+    ///
+    /// NOTE: The current `validation_response` endpoint does NOT transition job status
     /// to `Verified` — it only updates `ValidationRequestData`. This is a known gap
     /// in the validation registry design. This helper simulates the expected behavior
     /// for escrow release testing.
@@ -1646,6 +3452,348 @@ impl EscrowTestState {
                 sc.job_data(&job_id_buf).update(|job| {
                     job.status = common::structs::JobStatus::Verified;
                 });
+                sc.job_verified_at(&job_id_buf)
+                    .set_if_empty(sc.blockchain().get_block_timestamp_seconds());
+            });
+    }
+
+    pub fn clean_old_jobs(&mut self, job_ids: Vec<&[u8]>) {
+        let mut ids_encoded = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for id in &job_ids {
+            ids_encoded.push(ManagedBuffer::from(*id));
+        }
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .clean_old_jobs(ids_encoded)
+            .run();
+    }
+
+    /// Simulates an agent ownership change in the identity registry.
+    pub fn reassign_agent(
+        &mut self,
+        agent_nonce: u64,
+        new_owner: &multiversx_sc::types::TestAddress,
+    ) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(IDENTITY_SC_ADDRESS)
+            .whitebox(identity_registry::contract_obj, |sc| {
+                sc.agents().remove_by_id(&agent_nonce);
+                sc.agents()
+                    .insert(agent_nonce, new_owner.to_managed_address());
             });
     }
+
+    pub fn set_challenge_window(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        window: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .set_challenge_window(DurationSeconds::new(window))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn query_challenge_window(&mut self) -> u64 {
+        self.world
+            .query()
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_challenge_window()
+            .returns(ReturnsResult)
+            .run()
+            .as_u64_seconds()
+    }
+
+    // ── Cross-shard deployments ──
+
+    /// A second escrow on another shard than the registries it reads.
+    pub fn deploy_cross_shard_escrow(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(EscrowProxy)
+            .init(self.validation_sc.clone(), self.identity_sc.clone())
+            .code(ESCROW_CODE)
+            .new_address(ESCROW_CROSS_SHARD_SC_ADDRESS)
+            .run();
+    }
+
+    /// A second validation registry on another shard than identity-registry.
+    pub fn deploy_cross_shard_validation(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .init(self.identity_sc.clone())
+            .code(VALIDATION_CODE)
+            .new_address(VALIDATION_CROSS_SHARD_SC_ADDRESS)
+            .run();
+    }
+
+    /// `sync_agent` on an escrow; `escrow` picks the same- or cross-shard one.
+    pub fn sync_escrow_agent(
+        &mut self,
+        escrow: multiversx_sc::types::TestSCAddress,
+        agent_nonce: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(WORKER)
+            .to(escrow)
+            .typed(EscrowProxy)
+            .sync_agent(agent_nonce)
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn query_escrow_synced_agent(&mut self, agent_nonce: u64) -> SyncedAgent<StaticApi> {
+        self.world
+            .query()
+            .to(ESCROW_CROSS_SHARD_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_synced_agent(agent_nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn sync_escrow_job(&mut self, job_id: &[u8]) {
+        self.world
+            .tx()
+            .from(WORKER)
+            .to(ESCROW_CROSS_SHARD_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .sync_job(ManagedBuffer::from(job_id))
+            .run();
+    }
+
+    pub fn query_escrow_synced_job(&mut self, job_id: &[u8]) -> SyncedJob<StaticApi> {
+        self.world
+            .query()
+            .to(ESCROW_CROSS_SHARD_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .get_synced_job(ManagedBuffer::from(job_id))
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// `deposit_for_agent` of 500 USDC on the cross-shard escrow.
+    pub fn cross_shard_deposit_for_agent(
+        &mut self,
+        job_id: &[u8],
+        agent_nonce: u64,
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(EMPLOYER)
+            .to(ESCROW_CROSS_SHARD_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .deposit_for_agent(
+                ManagedBuffer::from(job_id),
+                agent_nonce,
+                ManagedBuffer::from(b"poa"),
+                1_000u64,
+                OptionalValue::<ManagedAddress<StaticApi>>::None,
+            )
+            .esdt(TestEsdtTransfer(PAYMENT_TOKEN, 0, 500))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn cross_shard_release(&mut self, job_id: &[u8], expected_err: Option<&str>) {
+        self.world
+            .tx()
+            .from(EMPLOYER)
+            .to(ESCROW_CROSS_SHARD_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .release(ManagedBuffer::from(job_id))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    pub fn cross_shard_refund(&mut self, job_id: &[u8], expected_err: Option<&str>) {
+        self.world
+            .tx()
+            .from(EMPLOYER)
+            .to(ESCROW_CROSS_SHARD_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .refund(ManagedBuffer::from(job_id))
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    /// `sync_agent` on the cross-shard validation registry.
+    pub fn sync_validation_agent(&mut self, agent_nonce: u64) {
+        self.world
+            .tx()
+            .from(WORKER)
+            .to(VALIDATION_CROSS_SHARD_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .sync_agent(agent_nonce)
+            .run();
+    }
+
+    pub fn query_validation_synced_agent(&mut self, agent_nonce: u64) -> SyncedAgent<StaticApi> {
+        self.world
+            .query()
+            .to(VALIDATION_CROSS_SHARD_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .synced_agent(agent_nonce)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    /// Points the cross-shard validation registry at another identity registry.
+    pub fn set_cross_shard_validation_identity(&mut self, address: ManagedAddress<StaticApi>) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_CROSS_SHARD_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .set_identity_registry_address(address)
+            .run();
+    }
+
+    pub fn cross_shard_validation_request(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_id: &[u8],
+        expected_err: Option<&str>,
+    ) {
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_CROSS_SHARD_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .validation_request(
+                ManagedBuffer::from(job_id),
+                VALIDATOR.to_managed_address(),
+                ManagedBuffer::from(b"https://request"),
+                ManagedBuffer::from(b"shard_request"),
+            )
+            .returns(ExpectOutcome(expected_err))
+            .run();
+    }
+
+    // ── Storage migration helpers ──
+
+    pub fn upgrade_escrow(&mut self, job_ids: &[&[u8]]) {
+        let mut ids = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for id in job_ids {
+            ids.push(ManagedBuffer::from(*id));
+        }
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .upgrade(ids)
+            .code(ESCROW_CODE)
+            .run();
+    }
+
+    pub fn upgrade_validation(&mut self, job_ids: &[&[u8]]) {
+        let mut ids = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for id in job_ids {
+            ids.push(ManagedBuffer::from(*id));
+        }
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .upgrade(ids)
+            .code(VALIDATION_CODE)
+            .run();
+    }
+
+    pub fn migrate_escrows(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_ids: &[&[u8]],
+    ) -> u32 {
+        let mut ids = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for id in job_ids {
+            ids.push(ManagedBuffer::from(*id));
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .migrate_escrows(ids)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn migrate_escrows_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_ids: &[&[u8]],
+        err_msg: &str,
+    ) {
+        let mut ids = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for id in job_ids {
+            ids.push(ManagedBuffer::from(*id));
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(ESCROW_SC_ADDRESS)
+            .typed(EscrowProxy)
+            .migrate_escrows(ids)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
+
+    pub fn migrate_jobs(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_ids: &[&[u8]],
+    ) -> u32 {
+        let mut ids = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for id in job_ids {
+            ids.push(ManagedBuffer::from(*id));
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .migrate_jobs(ids)
+            .returns(ReturnsResult)
+            .run()
+    }
+
+    pub fn migrate_jobs_expect_err(
+        &mut self,
+        from: &multiversx_sc::types::TestAddress,
+        job_ids: &[&[u8]],
+        err_msg: &str,
+    ) {
+        let mut ids = MultiValueEncoded::<StaticApi, ManagedBuffer<StaticApi>>::new();
+        for id in job_ids {
+            ids.push(ManagedBuffer::from(*id));
+        }
+        self.world
+            .tx()
+            .from(*from)
+            .to(VALIDATION_SC_ADDRESS)
+            .typed(ValidationRegistryProxy)
+            .migrate_jobs(ids)
+            .returns(ExpectMessage(err_msg))
+            .run();
+    }
 }
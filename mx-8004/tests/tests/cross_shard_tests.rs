@@ -0,0 +1,206 @@
+use common::structs::JobStatus;
+use multiversx_sc::proxy_imports::OptionalValue;
+use multiversx_sc::types::{ManagedAddress, ManagedBuffer, TestEsdtTransfer};
+use multiversx_sc_scenario::{ScenarioTxRun, api::StaticApi, imports::ExpectMessage};
+use mx_8004_tests::{constants::*, setup::EscrowTestState};
+use proxies::{escrow_proxy::EscrowProxy, validation_registry_proxy::ValidationRegistryProxy};
+
+fn register_agent(state: &mut EscrowTestState) {
+    state.register_agent(
+        &AGENT_OWNER,
+        b"ShardAgent",
+        b"https://agent.com",
+        b"pubkey",
+        vec![],
+        vec![(1, 100, PAYMENT_TOKEN.as_bytes(), 0)],
+    );
+}
+
+// ============================================
+// 1. Escrow Reads Agents and Jobs Through Snapshots
+// ============================================
+
+#[test]
+fn test_cross_shard_escrow_release() {
+    let mut state = EscrowTestState::new();
+    register_agent(&mut state);
+    state.deploy_cross_shard_escrow();
+    state.init_job(&EMPLOYER, b"shard_job", 1, None);
+
+    // Same-shard deployments read directly; there is nothing to sync
+    state.sync_escrow_agent(
+        ESCROW_SC_ADDRESS,
+        1,
+        Some("E1013: Contract is on this shard; reads are direct, no sync needed"),
+    );
+
+    state.cross_shard_deposit_for_agent(
+        b"shard_job",
+        1,
+        Some("E5043: Job not synced from the validation registry on another shard; call sync_job"),
+    );
+    state.sync_escrow_job(b"shard_job");
+    state.cross_shard_deposit_for_agent(
+        b"shard_job",
+        1,
+        Some(
+            "E1012: Agent not synced from the identity registry on another shard; call sync_agent",
+        ),
+    );
+    state.sync_escrow_agent(ESCROW_CROSS_SHARD_SC_ADDRESS, 1, None);
+    let synced = state.query_escrow_synced_agent(1);
+    assert_eq!(synced.owner, Some(AGENT_OWNER.to_managed_address()));
+    assert_eq!(synced.synced_at.as_u64_seconds(), 0);
+    state.cross_shard_deposit_for_agent(b"shard_job", 1, None);
+
+    // The snapshot only changes when synced again
+    state.mark_job_verified(b"shard_job");
+    state.cross_shard_release(
+        b"shard_job",
+        Some("E5004: Job must be verified before release"),
+    );
+    state.sync_escrow_job(b"shard_job");
+    assert_eq!(
+        state
+            .query_escrow_synced_job(b"shard_job")
+            .job
+            .unwrap()
+            .status,
+        JobStatus::Verified
+    );
+
+    // Payouts need a recent owner snapshot
+    state.world.current_block().block_timestamp_seconds(3_601);
+    state.cross_shard_release(
+        b"shard_job",
+        Some("E1015: Agent snapshot is too old; call sync_agent"),
+    );
+    state.sync_escrow_agent(ESCROW_CROSS_SHARD_SC_ADDRESS, 1, None);
+    assert_eq!(
+        state
+            .query_escrow_synced_agent(1)
+            .synced_at
+            .as_u64_seconds(),
+        3_601
+    );
+
+    state.cross_shard_release(b"shard_job", None);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 500u64);
+}
+
+// ============================================
+// 2. Refunds Need a Snapshot Taken After the Deadline
+// ============================================
+
+#[test]
+fn test_cross_shard_escrow_refund() {
+    let mut state = EscrowTestState::new();
+    state.deploy_cross_shard_escrow();
+    state.world.current_block().block_timestamp_seconds(10);
+
+    state
+        .world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW_CROSS_SHARD_SC_ADDRESS)
+        .typed(EscrowProxy)
+        .deposit(
+            ManagedBuffer::from(b"offchain_job"),
+            WORKER.to_managed_address(),
+            ManagedBuffer::from(b"poa"),
+            100u64,
+            OptionalValue::<ManagedAddress<StaticApi>>::None,
+        )
+        .esdt(TestEsdtTransfer(PAYMENT_TOKEN, 0, 300))
+        .run();
+
+    state.sync_escrow_job(b"offchain_job");
+    state.world.current_block().block_timestamp_seconds(101);
+    state.cross_shard_refund(
+        b"offchain_job",
+        Some("E5044: Job snapshot was taken before the deadline; call sync_job"),
+    );
+
+    state.sync_escrow_job(b"offchain_job");
+    state.cross_shard_refund(b"offchain_job", None);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64);
+}
+
+// ============================================
+// 3. Validation Registry on Another Shard
+// ============================================
+
+#[test]
+fn test_cross_shard_validation_registry() {
+    let mut state = EscrowTestState::new();
+    register_agent(&mut state);
+    state.deploy_cross_shard_validation();
+
+    // Priced services read identity storage directly
+    state
+        .world
+        .tx()
+        .from(EMPLOYER)
+        .to(VALIDATION_CROSS_SHARD_SC_ADDRESS)
+        .typed(ValidationRegistryProxy)
+        .init_job(
+            ManagedBuffer::from(b"paid_job"),
+            1u64,
+            OptionalValue::Some(1u32),
+            OptionalValue::<bool>::None,
+        )
+        .esdt(TestEsdtTransfer(PAYMENT_TOKEN, 0, 100))
+        .returns(ExpectMessage(
+            "E1011: Contract is on another shard; this call needs a same-shard deployment",
+        ))
+        .run();
+
+    state
+        .world
+        .tx()
+        .from(EMPLOYER)
+        .to(VALIDATION_CROSS_SHARD_SC_ADDRESS)
+        .typed(ValidationRegistryProxy)
+        .init_job(
+            ManagedBuffer::from(b"shard_job"),
+            1u64,
+            OptionalValue::<u32>::None,
+            OptionalValue::<bool>::None,
+        )
+        .run();
+
+    state.cross_shard_validation_request(
+        &AGENT_OWNER,
+        b"shard_job",
+        Some(
+            "E1012: Agent not synced from the identity registry on another shard; call sync_agent",
+        ),
+    );
+
+    // An agent that does not exist is cached as missing
+    state.sync_validation_agent(99);
+    assert_eq!(state.query_validation_synced_agent(99).owner, None);
+
+    state.sync_validation_agent(1);
+    state.cross_shard_validation_request(
+        &EMPLOYER,
+        b"shard_job",
+        Some("E1002: Only the agent owner can perform this action"),
+    );
+
+    // A failed read keeps the last snapshot: the escrow has no agent view
+    state.world.current_block().block_timestamp_seconds(50);
+    state.set_cross_shard_validation_identity(ESCROW_SC_ADDRESS.to_managed_address());
+    state.sync_validation_agent(1);
+    let synced = state.query_validation_synced_agent(1);
+    assert_eq!(synced.owner, Some(AGENT_OWNER.to_managed_address()));
+    assert_eq!(synced.synced_at.as_u64_seconds(), 0);
+
+    state.cross_shard_validation_request(&AGENT_OWNER, b"shard_job", None);
+}
@@ -6,6 +6,7 @@
 #[cfg(feature = "chain-simulator-tests")]
 mod cs {
     use mx_8004_tests::interact::CsInteract;
+    use proxies::errors::ErrorCode;
     use serial_test::serial;
 
     #[tokio::test]
@@ -113,7 +114,7 @@ mod cs {
     /// to read job data from the validation-registry. This VM hook only works
     /// when both contracts are deployed in the same shard. On the chain simulator
     /// with 3 shards, contracts deployed at different nonces land in different
-    /// shards non-deterministically, causing "Job not found" errors.
+    /// shards non-deterministically, causing "E1001: Job not found" errors.
     ///
     /// The full lifecycle (including feedback) is covered by the 32 scenario tests.
     ///
@@ -209,8 +210,7 @@ mod cs {
                 b"TestAgent2",
                 b"https://agent2.example.com",
                 b"pubkey456",
-                4,
-                "Agent already registered for this address",
+                ErrorCode::AgentAlreadyRegistered,
             )
             .await;
 
@@ -225,7 +225,7 @@ mod cs {
 
         // Token is already issued during CsInteract::new(), second issuance should fail
         interact
-            .issue_token_expect_err(4, "Token already issued")
+            .issue_token_expect_err(ErrorCode::TokenAlreadyIssued)
             .await;
 
         println!("Duplicate token issuance correctly rejected");
@@ -262,8 +262,7 @@ mod cs {
                 b"job-001",
                 b"req-uri",
                 b"req-hash",
-                4,
-                "Only the agent owner can request validation",
+                ErrorCode::NotAgentOwner,
             )
             .await;
 
@@ -282,8 +281,7 @@ mod cs {
                 &worker,
                 b"nonexistent-job",
                 b"proof-data",
-                4,
-                "Job not found",
+                ErrorCode::JobNotFound,
             )
             .await;
 
@@ -311,7 +309,7 @@ mod cs {
 
         // Same job_id again should fail
         interact
-            .init_job_expect_err(&carol, b"job-001", 1u64, 4, "Job already initialized")
+            .init_job_expect_err(&carol, b"job-001", 1u64, ErrorCode::JobAlreadyInitialized)
             .await;
 
         println!("Duplicate job init correctly rejected");
@@ -376,8 +374,7 @@ mod cs {
                 b"no-pay-job-001",
                 1u64,
                 1,
-                4,
-                "Insufficient payment",
+                ErrorCode::InsufficientPayment,
             )
             .await;
 
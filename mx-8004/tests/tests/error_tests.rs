@@ -0,0 +1,86 @@
+use multiversx_sc::types::ManagedBuffer;
+use multiversx_sc_scenario::ScenarioTxRun;
+use multiversx_sc_scenario::imports::{ReturnsMessage, ReturnsStatus};
+use mx_8004_tests::{
+    constants::*,
+    setup::{EscrowTestState, query_error_codes},
+};
+use proxies::{
+    errors::{ErrorCode, decode_error},
+    escrow_proxy::EscrowProxy,
+};
+
+// ============================================
+// 1. Catalog Codes and Messages
+// ============================================
+
+#[test]
+fn test_error_catalog_round_trip() {
+    for error in ErrorCode::ALL {
+        assert_eq!(ErrorCode::from_code(error.code()), Some(*error));
+        assert_eq!(
+            error.message(),
+            format!("E{}: {}", error.code(), error.description())
+        );
+        assert_eq!(decode_error(error.message()), Some(*error));
+    }
+    assert_eq!(
+        ErrorCode::NotEmployer.message(),
+        "E5003: Only the employer can call this"
+    );
+
+    // Codes are found inside wrapped messages; anything else is not ours
+    assert_eq!(
+        decode_error("execution failed: E1004: Contract is paused"),
+        Some(ErrorCode::ContractPaused)
+    );
+    assert_eq!(decode_error("not enough gas"), None);
+    assert_eq!(decode_error("E9999: Unknown"), None);
+    assert_eq!(decode_error("EGLD: E: missing code"), None);
+}
+
+// ============================================
+// 2. ABI Listing
+// ============================================
+
+#[test]
+fn test_error_codes_view() {
+    let mut state = EscrowTestState::new();
+
+    for contract in [
+        IDENTITY_SC_ADDRESS,
+        VALIDATION_SC_ADDRESS,
+        REPUTATION_SC_ADDRESS,
+        ESCROW_SC_ADDRESS,
+    ] {
+        let codes = query_error_codes(&mut state.world, contract);
+        assert_eq!(codes.len(), ErrorCode::ALL.len());
+        assert!(codes.contains(&(
+            5042,
+            "Only the validation registry can call this".to_string()
+        )));
+    }
+}
+
+// ============================================
+// 3. Decoding a Failed Transaction
+// ============================================
+
+#[test]
+fn test_failed_tx_decodes_to_error_code() {
+    let mut state = EscrowTestState::new();
+
+    let (status, message) = state
+        .world
+        .tx()
+        .from(EMPLOYER)
+        .to(ESCROW_SC_ADDRESS)
+        .typed(EscrowProxy)
+        .release(ManagedBuffer::from(b"missing_job"))
+        .returns(ReturnsStatus)
+        .returns(ReturnsMessage)
+        .run();
+
+    assert_eq!(status, 4);
+    assert_eq!(decode_error(&message), Some(ErrorCode::EscrowNotFound));
+}
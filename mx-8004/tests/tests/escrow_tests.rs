@@ -1,5 +1,5 @@
-use escrow::storage::EscrowStatus;
-use multiversx_sc::types::{BigUint, ManagedAddress, ManagedBuffer};
+use escrow::storage::{BatchOutcome, EscrowStatus, MilestoneStatus};
+use multiversx_sc::types::{BigUint, EgldOrEsdtTokenIdentifier, ManagedAddress, ManagedBuffer};
 use multiversx_sc_scenario::api::StaticApi;
 use mx_8004_tests::{constants::*, setup::EscrowTestState};
 
@@ -83,7 +83,7 @@ fn test_deposit_zero_amount() {
         b"poa_hash",
         1_000_000,
         0,
-        "Deposit amount must be greater than zero",
+        "E5008: Deposit amount must be greater than zero",
     );
 }
 
@@ -111,7 +111,7 @@ fn test_deposit_duplicate_job() {
         b"poa_hash_2",
         2_000_000,
         500_000,
-        "Escrow already exists for this job",
+        "E5001: Escrow already exists for this job",
     );
 }
 
@@ -210,7 +210,7 @@ fn test_release_not_verified() {
     state.release_expect_err(
         &EMPLOYER,
         b"job_not_verified",
-        "Job must be verified before release",
+        "E5004: Job must be verified before release",
     );
 }
 
@@ -261,8 +261,12 @@ fn test_release_not_employer() {
         500_000,
     );
 
-    // Non-employer (CLIENT) tries to release → error
-    state.release_expect_err(&CLIENT, b"job_not_emp", "Only the employer can call this");
+    // Non-employer (CLIENT) tries to release inside the challenge window → error
+    state.release_expect_err(
+        &CLIENT,
+        b"job_not_emp",
+        "E5023: Challenge window has not elapsed",
+    );
 }
 
 // ============================================
@@ -313,7 +317,11 @@ fn test_release_already_released() {
     state.release(&EMPLOYER, b"job_double_rel");
 
     // Second release → already settled
-    state.release_expect_err(&EMPLOYER, b"job_double_rel", "Escrow already settled");
+    state.release_expect_err(
+        &EMPLOYER,
+        b"job_double_rel",
+        "E5007: Escrow already settled",
+    );
 }
 
 // ============================================
@@ -370,7 +378,7 @@ fn test_refund_before_deadline() {
     state.refund_expect_err(
         &EMPLOYER,
         b"job_early_refund",
-        "Deadline has not passed yet",
+        "E5005: Deadline has not passed yet",
     );
 }
 
@@ -399,7 +407,11 @@ fn test_refund_already_refunded() {
     state.refund(&EMPLOYER, b"job_double_ref");
 
     // Second refund → already settled
-    state.refund_expect_err(&EMPLOYER, b"job_double_ref", "Escrow already settled");
+    state.refund_expect_err(
+        &EMPLOYER,
+        b"job_double_ref",
+        "E5007: Escrow already settled",
+    );
 }
 
 // ============================================
@@ -423,6 +435,21 @@ fn test_release_after_refund() {
 
     state.init_job(&EMPLOYER, b"job_ref_then_rel", 1, None);
     state.submit_proof(&WORKER, b"job_ref_then_rel", b"proof");
+
+    state.deposit_egld(
+        &EMPLOYER,
+        b"job_ref_then_rel",
+        &AGENT_OWNER,
+        b"poa_hash",
+        200,
+        500_000,
+    );
+
+    // Refund first (deadline passed, job not yet verified)
+    state.world.current_block().block_timestamp_seconds(201);
+    state.refund(&EMPLOYER, b"job_ref_then_rel");
+
+    // Verification lands late
     state.validation_request(
         &AGENT_OWNER,
         b"job_ref_then_rel",
@@ -439,21 +466,12 @@ fn test_release_after_refund() {
         b"approved",
     );
 
-    state.deposit_egld(
+    // Try release after refund → already settled
+    state.release_expect_err(
         &EMPLOYER,
         b"job_ref_then_rel",
-        &AGENT_OWNER,
-        b"poa_hash",
-        200,
-        500_000,
+        "E5007: Escrow already settled",
     );
-
-    // Refund first (deadline passed)
-    state.world.current_block().block_timestamp_seconds(201);
-    state.refund(&EMPLOYER, b"job_ref_then_rel");
-
-    // Try release after refund → already settled
-    state.release_expect_err(&EMPLOYER, b"job_ref_then_rel", "Escrow already settled");
 }
 
 // ============================================
@@ -507,7 +525,11 @@ fn test_refund_after_release() {
 
     // Advance past deadline and try refund → already settled
     state.world.current_block().block_timestamp_seconds(201);
-    state.refund_expect_err(&CLIENT, b"job_rel_then_ref", "Escrow already settled");
+    state.refund_expect_err(
+        &CLIENT,
+        b"job_rel_then_ref",
+        "E5007: Escrow already settled",
+    );
 }
 
 // ============================================
@@ -518,7 +540,11 @@ fn test_refund_after_release() {
 fn test_release_nonexistent() {
     let mut state = EscrowTestState::new();
 
-    state.release_expect_err(&EMPLOYER, b"no_such_job", "Escrow not found for this job");
+    state.release_expect_err(
+        &EMPLOYER,
+        b"no_such_job",
+        "E5002: Escrow not found for this job",
+    );
 }
 
 // ============================================
@@ -529,7 +555,11 @@ fn test_release_nonexistent() {
 fn test_refund_nonexistent() {
     let mut state = EscrowTestState::new();
 
-    state.refund_expect_err(&EMPLOYER, b"no_such_job", "Escrow not found for this job");
+    state.refund_expect_err(
+        &EMPLOYER,
+        b"no_such_job",
+        "E5002: Escrow not found for this job",
+    );
 }
 
 // ============================================
@@ -656,3 +686,2036 @@ fn test_full_lifecycle_esdt() {
     let escrow = state.query_escrow(b"lifecycle_esdt");
     assert_eq!(escrow.status, EscrowStatus::Released);
 }
+
+// ============================================
+// 19. Milestones: Release per Stage + Partial Refund
+// ============================================
+
+fn setup_milestone_jobs(state: &mut EscrowTestState) {
+    state.register_agent(
+        &AGENT_OWNER,
+        b"StagedAgent",
+        b"https://staged.agent.com",
+        b"pubkey",
+        vec![],
+        vec![],
+    );
+    for job in [&b"ms-1"[..], b"ms-2", b"ms-3"] {
+        state.init_job(&EMPLOYER, job, 1, None);
+    }
+}
+
+const STAGES: &[(u64, u64, &[u8])] = &[
+    (300, 1_000, b"ms-1"),
+    (200, 2_000, b"ms-2"),
+    (500, 3_000, b"ms-3"),
+];
+
+#[test]
+fn test_milestone_release_and_partial_refund() {
+    let mut state = EscrowTestState::new();
+    setup_milestone_jobs(&mut state);
+
+    state.deposit_milestones_esdt(&EMPLOYER, b"staged", &AGENT_OWNER, STAGES, 1_000, None);
+
+    let escrow = state.query_escrow(b"staged");
+    assert_eq!(escrow.status, EscrowStatus::Active);
+    assert_eq!(escrow.amount, BigUint::<StaticApi>::from(1_000u64));
+    assert_eq!(escrow.deadline.as_u64_seconds(), 3_000);
+    let milestones = state.query_milestones(b"staged");
+    assert_eq!(milestones.len(), 3);
+    assert_eq!(milestones[1].amount, BigUint::<StaticApi>::from(200u64));
+    assert_eq!(milestones[1].status, MilestoneStatus::Pending);
+
+    // Stage 1: gated on its own verification job
+    state.release_milestone(
+        &EMPLOYER,
+        b"staged",
+        0,
+        Some("E5004: Job must be verified before release"),
+    );
+    state.mark_job_verified(b"ms-1");
+    state.release_milestone(
+        &AGENT_OWNER,
+        b"staged",
+        0,
+        Some("E5023: Challenge window has not elapsed"),
+    );
+    state.release_milestone(&EMPLOYER, b"staged", 0, None);
+    state.release_milestone(
+        &EMPLOYER,
+        b"staged",
+        0,
+        Some("E5014: Milestone already settled"),
+    );
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 300u64);
+
+    let escrow = state.query_escrow(b"staged");
+    assert_eq!(escrow.status, EscrowStatus::PartiallyReleased);
+    assert_eq!(escrow.released_amount, BigUint::<StaticApi>::from(300u64));
+
+    // Stage 2: missed its deadline → refunded on its own
+    state.refund_milestone(
+        &WORKER,
+        b"staged",
+        1,
+        Some("E5005: Deadline has not passed yet"),
+    );
+    state.world.current_block().block_timestamp_seconds(2_001);
+    state.refund_milestone(&WORKER, b"staged", 1, None);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 800);
+
+    let escrow = state.query_escrow(b"staged");
+    assert_eq!(escrow.status, EscrowStatus::PartiallyReleased);
+    assert_eq!(escrow.refunded_amount, BigUint::<StaticApi>::from(200u64));
+    assert_eq!(
+        state.query_milestones(b"staged")[1].status,
+        MilestoneStatus::Refunded
+    );
+
+    // Stage 3: released → nothing left locked
+    state.mark_job_verified(b"ms-3");
+    state.release_milestone(&EMPLOYER, b"staged", 2, None);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 800u64);
+
+    let escrow = state.query_escrow(b"staged");
+    assert_eq!(escrow.status, EscrowStatus::PartiallyReleased);
+    assert_eq!(escrow.released_amount, BigUint::<StaticApi>::from(800u64));
+
+    state.world.current_block().block_timestamp_seconds(5_000);
+    state.refund_expect_err(&WORKER, b"staged", "E5007: Escrow already settled");
+    state.release_milestone(&EMPLOYER, b"staged", 3, Some("E5013: Milestone not found"));
+}
+
+// ============================================
+// 20. Milestones: Deposit Validation
+// ============================================
+
+#[test]
+fn test_milestone_deposit_errors() {
+    let mut state = EscrowTestState::new();
+    setup_milestone_jobs(&mut state);
+
+    state.deposit_milestones_esdt(
+        &EMPLOYER,
+        b"staged",
+        &AGENT_OWNER,
+        STAGES,
+        999,
+        Some("E5011: Milestone amounts must add up to the deposit"),
+    );
+    state.deposit_milestones_esdt(
+        &EMPLOYER,
+        b"staged",
+        &AGENT_OWNER,
+        &[],
+        1_000,
+        Some("E5009: At least one milestone is required"),
+    );
+    state.deposit_milestones_esdt(
+        &EMPLOYER,
+        b"staged",
+        &AGENT_OWNER,
+        &[(1_000, 1_000, b"ms-1"), (0, 2_000, b"ms-2")],
+        1_000,
+        Some("E5012: Milestone amount must be greater than zero"),
+    );
+
+    state.world.current_block().block_timestamp_seconds(1_500);
+    state.deposit_milestones_esdt(
+        &EMPLOYER,
+        b"staged",
+        &AGENT_OWNER,
+        STAGES,
+        1_000,
+        Some("E5006: Deadline must be in the future"),
+    );
+
+    // Milestone escrows cannot be released in one shot
+    state.deposit_milestones_esdt(
+        &EMPLOYER,
+        b"staged",
+        &AGENT_OWNER,
+        &[(600, 2_000, b"ms-2"), (400, 3_000, b"ms-3")],
+        1_000,
+        None,
+    );
+    state.init_job(&EMPLOYER, b"staged", 1, None);
+    state.mark_job_verified(b"staged");
+    state.release_expect_err(
+        &EMPLOYER,
+        b"staged",
+        "E5015: Escrow has milestones; use release_milestone",
+    );
+}
+
+// ============================================
+// 21. Milestones: Refund Remaining After Final Deadline
+// ============================================
+
+#[test]
+fn test_milestone_refund_remaining() {
+    let mut state = EscrowTestState::new();
+    setup_milestone_jobs(&mut state);
+
+    state.deposit_milestones_esdt(&EMPLOYER, b"staged", &AGENT_OWNER, STAGES, 1_000, None);
+
+    // Past stage 2's deadline but not the last one
+    state.world.current_block().block_timestamp_seconds(2_500);
+    state.refund_expect_err(&WORKER, b"staged", "E5005: Deadline has not passed yet");
+
+    state.world.current_block().block_timestamp_seconds(3_001);
+    state.refund(&WORKER, b"staged");
+
+    let escrow = state.query_escrow(b"staged");
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.refunded_amount, BigUint::<StaticApi>::from(1_000u64));
+    assert!(
+        state
+            .query_milestones(b"staged")
+            .iter()
+            .all(|m| m.status == MilestoneStatus::Refunded)
+    );
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64);
+
+    state.refund_milestone(
+        &WORKER,
+        b"staged",
+        0,
+        Some("E5014: Milestone already settled"),
+    );
+}
+
+// ============================================
+// 22. Dispute: Named Arbiter Splits Locked Funds
+// ============================================
+
+#[test]
+fn test_dispute_named_arbiter_split() {
+    let mut state = EscrowTestState::new();
+
+    // Only arbiters on the role-managed list can be named
+    state.deposit_esdt_with_arbiter(
+        &EMPLOYER,
+        b"job_d",
+        &AGENT_OWNER,
+        1_000,
+        1_000,
+        &VALIDATOR,
+        Some("E5045: Arbiter is not on the arbiter list"),
+    );
+    state.add_arbiter(&OWNER_ADDRESS, &VALIDATOR, None);
+    state.deposit_esdt_with_arbiter(
+        &EMPLOYER,
+        b"job_d",
+        &AGENT_OWNER,
+        1_000,
+        1_000,
+        &VALIDATOR,
+        None,
+    );
+    assert_eq!(
+        state.query_escrow(b"job_d").arbiter,
+        Some(VALIDATOR.to_managed_address())
+    );
+
+    // Receiver disputes before the deadline → escrow frozen
+    state.world.current_block().block_timestamp_seconds(500);
+    state.open_dispute(&AGENT_OWNER, b"job_d", b"https://evidence", None);
+
+    let dispute = state.query_dispute(b"job_d");
+    assert_eq!(dispute.opened_by, AGENT_OWNER.to_managed_address());
+    assert_eq!(dispute.opened_at.as_u64_seconds(), 500);
+    assert_eq!(state.query_escrow(b"job_d").status, EscrowStatus::Disputed);
+
+    state.world.current_block().block_timestamp_seconds(2_000);
+    state.refund_expect_err(&EMPLOYER, b"job_d", "E5019: Escrow is under dispute");
+    state.release_expect_err(&EMPLOYER, b"job_d", "E5019: Escrow is under dispute");
+
+    // A listed arbiter cannot override the one named at deposit
+    state.add_arbiter(&OWNER_ADDRESS, &WORKER, None);
+    state.resolve_dispute(
+        &WORKER,
+        b"job_d",
+        500,
+        Some("E5021: Only the arbiter can resolve this dispute"),
+    );
+
+    state.resolve_dispute(&VALIDATOR, b"job_d", 700, None);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 700u64);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 700);
+
+    let escrow = state.query_escrow(b"job_d");
+    assert_eq!(escrow.status, EscrowStatus::Resolved);
+    assert_eq!(escrow.released_amount, BigUint::<StaticApi>::from(700u64));
+    assert_eq!(escrow.refunded_amount, BigUint::<StaticApi>::from(300u64));
+
+    state.resolve_dispute(
+        &VALIDATOR,
+        b"job_d",
+        0,
+        Some("E5020: Escrow is not under dispute"),
+    );
+    state.refund_expect_err(&EMPLOYER, b"job_d", "E5007: Escrow already settled");
+}
+
+// ============================================
+// 23. Dispute: Listed Arbiters + Error Paths
+// ============================================
+
+#[test]
+fn test_dispute_listed_arbiters_and_errors() {
+    let mut state = EscrowTestState::new();
+
+    state.deposit_esdt_with_arbiter(
+        &EMPLOYER,
+        b"job_d",
+        &AGENT_OWNER,
+        1_000,
+        1_000,
+        &AGENT_OWNER,
+        Some("E5016: Arbiter cannot be the employer or the receiver"),
+    );
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_d",
+        &AGENT_OWNER,
+        b"poa",
+        1_000,
+        "USDC-abcdef",
+        0,
+        1_000,
+    );
+
+    state.open_dispute(
+        &WORKER,
+        b"job_d",
+        b"",
+        Some("E5017: Only the employer or the receiver can dispute"),
+    );
+    state.open_dispute(&EMPLOYER, b"job_d", b"https://late-delivery", None);
+    state.open_dispute(
+        &AGENT_OWNER,
+        b"job_d",
+        b"",
+        Some("E5019: Escrow is under dispute"),
+    );
+
+    // No named arbiter → any arbiter from the owner-managed list
+    state.resolve_dispute(
+        &WORKER,
+        b"job_d",
+        0,
+        Some("E5021: Only the arbiter can resolve this dispute"),
+    );
+    state.add_arbiter(
+        &EMPLOYER,
+        &WORKER,
+        Some("E1007: Caller does not have the required role"),
+    );
+    state.add_arbiter(&OWNER_ADDRESS, &WORKER, None);
+    state.resolve_dispute(
+        &WORKER,
+        b"job_d",
+        1_001,
+        Some("E5022: Split exceeds the locked amount"),
+    );
+    state.resolve_dispute(&WORKER, b"job_d", 0, None);
+
+    let escrow = state.query_escrow(b"job_d");
+    assert_eq!(escrow.status, EscrowStatus::Resolved);
+    assert_eq!(escrow.refunded_amount, BigUint::<StaticApi>::from(1_000u64));
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64);
+
+    // Disputes must be opened before the deadline
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_late",
+        &AGENT_OWNER,
+        b"poa",
+        1_000,
+        "USDC-abcdef",
+        0,
+        1_000,
+    );
+    state.world.current_block().block_timestamp_seconds(1_001);
+    state.open_dispute(
+        &AGENT_OWNER,
+        b"job_late",
+        b"",
+        Some("E5018: Deadline has passed"),
+    );
+}
+
+// ============================================
+// 24. Dispute: Milestone Escrow Frozen and Resolved
+// ============================================
+
+#[test]
+fn test_dispute_milestone_escrow() {
+    let mut state = EscrowTestState::new();
+    setup_milestone_jobs(&mut state);
+
+    state.deposit_milestones_esdt(&EMPLOYER, b"staged", &AGENT_OWNER, STAGES, 1_000, None);
+    state.mark_job_verified(b"ms-1");
+    state.release_milestone(&EMPLOYER, b"staged", 0, None);
+
+    state.open_dispute(&AGENT_OWNER, b"staged", b"https://evidence", None);
+    state.mark_job_verified(b"ms-2");
+    state.release_milestone(
+        &EMPLOYER,
+        b"staged",
+        1,
+        Some("E5019: Escrow is under dispute"),
+    );
+    state.world.current_block().block_timestamp_seconds(2_500);
+    state.refund_milestone(
+        &EMPLOYER,
+        b"staged",
+        1,
+        Some("E5019: Escrow is under dispute"),
+    );
+
+    // Split applies to the 700 still locked
+    state.add_arbiter(&OWNER_ADDRESS, &VALIDATOR, None);
+    state.resolve_dispute(
+        &VALIDATOR,
+        b"staged",
+        701,
+        Some("E5022: Split exceeds the locked amount"),
+    );
+    state.resolve_dispute(&VALIDATOR, b"staged", 200, None);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 500u64);
+
+    let escrow = state.query_escrow(b"staged");
+    assert_eq!(escrow.status, EscrowStatus::Resolved);
+    assert_eq!(escrow.released_amount, BigUint::<StaticApi>::from(500u64));
+    assert_eq!(escrow.refunded_amount, BigUint::<StaticApi>::from(500u64));
+    let milestones = state.query_milestones(b"staged");
+    assert_eq!(milestones[0].status, MilestoneStatus::Released);
+    assert_eq!(milestones[1].status, MilestoneStatus::Resolved);
+    assert_eq!(milestones[2].status, MilestoneStatus::Resolved);
+}
+
+// ============================================
+// 25. Permissionless Release After the Challenge Window
+// ============================================
+
+#[test]
+fn test_release_after_challenge_window() {
+    let mut state = EscrowTestState::new();
+    assert_eq!(state.query_challenge_window(), 24 * 60 * 60);
+    state.set_challenge_window(
+        &EMPLOYER,
+        10,
+        Some("E1007: Caller does not have the required role"),
+    );
+    state.set_challenge_window(&OWNER_ADDRESS, 600, None);
+    assert_eq!(state.query_challenge_window(), 600);
+
+    state.register_agent(
+        &AGENT_OWNER,
+        b"KeeperAgent",
+        b"https://agent.com",
+        b"pubkey",
+        vec![],
+        vec![],
+    );
+    state.world.current_block().block_timestamp_seconds(100);
+    state.init_job(&EMPLOYER, b"job_keeper", 1, None);
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_keeper",
+        &AGENT_OWNER,
+        b"poa",
+        5_000,
+        "USDC-abcdef",
+        0,
+        1_000,
+    );
+    state.release_expect_err(
+        &CLIENT,
+        b"job_keeper",
+        "E5004: Job must be verified before release",
+    );
+
+    // Verified at 1_000: window runs until 1_600
+    state.world.current_block().block_timestamp_seconds(1_000);
+    state.submit_proof(&WORKER, b"job_keeper", b"proof");
+    state.validation_request(
+        &AGENT_OWNER,
+        b"job_keeper",
+        &VALIDATOR,
+        b"https://req.uri",
+        b"req_hash_keeper",
+    );
+    state.validation_response(
+        &VALIDATOR,
+        b"req_hash_keeper",
+        100,
+        b"https://resp.uri",
+        b"resp_hash",
+        b"approved",
+    );
+
+    state.world.current_block().block_timestamp_seconds(1_599);
+    state.release_expect_err(
+        &AGENT_OWNER,
+        b"job_keeper",
+        "E5023: Challenge window has not elapsed",
+    );
+    state.release_expect_err(
+        &CLIENT,
+        b"job_keeper",
+        "E5023: Challenge window has not elapsed",
+    );
+
+    // A keeper settles once the window closes; funds still go to the receiver
+    state.world.current_block().block_timestamp_seconds(1_600);
+    state.release(&CLIENT, b"job_keeper");
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000u64);
+    assert_eq!(
+        state.query_escrow(b"job_keeper").status,
+        EscrowStatus::Released
+    );
+}
+
+// ============================================
+// 26. Refund Refused for a Verified Job
+// ============================================
+
+#[test]
+fn test_refund_refused_when_verified() {
+    let mut state = EscrowTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"DoneAgent",
+        b"https://agent.com",
+        b"pubkey",
+        vec![],
+        vec![],
+    );
+
+    state.world.current_block().block_timestamp_seconds(100);
+    state.init_job(&EMPLOYER, b"job_done", 1, None);
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_done",
+        &AGENT_OWNER,
+        b"poa",
+        200,
+        "USDC-abcdef",
+        0,
+        1_000,
+    );
+    state.mark_job_verified(b"job_done");
+
+    // Deadline passes without the employer releasing
+    state.world.current_block().block_timestamp_seconds(201);
+    state.refund_expect_err(
+        &EMPLOYER,
+        b"job_done",
+        "E5024: Job is verified; it can only be released",
+    );
+    state.refund_expect_err(
+        &CLIENT,
+        b"job_done",
+        "E5024: Job is verified; it can only be released",
+    );
+
+    // The receiver collects instead
+    state
+        .world
+        .current_block()
+        .block_timestamp_seconds(100 + 24 * 60 * 60);
+    state.release(&AGENT_OWNER, b"job_done");
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000u64);
+}
+
+// ============================================
+// 27. Challenge Window and Refund Guard on Milestones
+// ============================================
+
+#[test]
+fn test_milestone_challenge_window_and_refund_guard() {
+    let mut state = EscrowTestState::new();
+    setup_milestone_jobs(&mut state);
+    state.set_challenge_window(&OWNER_ADDRESS, 100, None);
+
+    state.world.current_block().block_timestamp_seconds(50);
+    state.deposit_milestones_esdt(&EMPLOYER, b"staged", &AGENT_OWNER, STAGES, 1_000, None);
+    state.mark_job_verified(b"ms-2");
+
+    // Verified stages are never refunded, individually or in bulk
+    state.world.current_block().block_timestamp_seconds(2_001);
+    state.refund_milestone(
+        &EMPLOYER,
+        b"staged",
+        1,
+        Some("E5024: Job is verified; it can only be released"),
+    );
+    state.world.current_block().block_timestamp_seconds(3_001);
+    state.refund_expect_err(
+        &EMPLOYER,
+        b"staged",
+        "E5024: Job is verified; it can only be released",
+    );
+
+    // Unverified stage 1 can still be refunded on its own
+    state.refund_milestone(&EMPLOYER, b"staged", 0, None);
+
+    // Anyone releases stage 2 now that its window (50..150) is long over
+    state.release_milestone(&CLIENT, b"staged", 1, None);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 200u64);
+
+    // Nothing verified is left pending, so the bulk refund goes through
+    state.refund(&EMPLOYER, b"staged");
+    let escrow = state.query_escrow(b"staged");
+    assert_eq!(escrow.status, EscrowStatus::PartiallyReleased);
+    assert_eq!(escrow.refunded_amount, BigUint::<StaticApi>::from(800u64));
+}
+
+// ============================================
+// 28. Agent-Bound Escrow Follows the Agent Owner
+// ============================================
+
+#[test]
+fn test_deposit_for_agent_follows_owner() {
+    let mut state = EscrowTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"BoundAgent",
+        b"https://agent.com",
+        b"pubkey",
+        vec![],
+        vec![],
+    );
+    state.init_job(&EMPLOYER, b"job_bound", 1, None);
+    state.deposit_for_agent(&EMPLOYER, b"job_bound", 1, 1_000, 700, None);
+
+    let escrow = state.query_escrow(b"job_bound");
+    assert_eq!(escrow.agent_nonce, Some(1));
+    assert_eq!(escrow.receiver, AGENT_OWNER.to_managed_address());
+
+    // Agent changes hands before settlement: the new owner is paid
+    state.reassign_agent(1, &WORKER);
+    state.open_dispute(
+        &AGENT_OWNER,
+        b"job_bound",
+        b"",
+        Some("E5017: Only the employer or the receiver can dispute"),
+    );
+    state.mark_job_verified(b"job_bound");
+    state.release(&EMPLOYER, b"job_bound");
+    state
+        .world
+        .check_account(WORKER)
+        .esdt_balance(PAYMENT_TOKEN, 700u64);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 0u64);
+}
+
+// ============================================
+// 29. Agent-Bound Deposit Checks
+// ============================================
+
+#[test]
+fn test_deposit_for_agent_checks() {
+    let mut state = EscrowTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"BoundAgent",
+        b"https://agent.com",
+        b"pubkey",
+        vec![],
+        vec![],
+    );
+    state.register_agent(
+        &WORKER,
+        b"OtherAgent",
+        b"https://other.com",
+        b"pubkey2",
+        vec![],
+        vec![],
+    );
+
+    state.deposit_for_agent(
+        &EMPLOYER,
+        b"job_missing",
+        1,
+        1_000,
+        100,
+        Some("E5025: Job not found in the validation registry"),
+    );
+
+    state.init_job(&EMPLOYER, b"job_a1", 1, None);
+    state.deposit_for_agent(
+        &EMPLOYER,
+        b"job_a1",
+        2,
+        1_000,
+        100,
+        Some("E5026: Job belongs to another agent"),
+    );
+
+    state.init_job(&CLIENT, b"job_client", 1, None);
+    state.deposit_for_agent(
+        &EMPLOYER,
+        b"job_client",
+        1,
+        1_000,
+        100,
+        Some("E5027: Job belongs to another employer"),
+    );
+
+    state.init_job(&EMPLOYER, b"job_ghost", 9, None);
+    state.deposit_for_agent(
+        &EMPLOYER,
+        b"job_ghost",
+        9,
+        1_000,
+        100,
+        Some("E1003: Agent not found"),
+    );
+
+    state.deposit_for_agent(&EMPLOYER, b"job_a1", 1, 1_000, 100, None);
+    assert_eq!(
+        state.query_escrow(b"job_a1").receiver,
+        AGENT_OWNER.to_managed_address()
+    );
+}
+
+// ============================================
+// 30. Protocol Fee on EGLD Release (rounds down)
+// ============================================
+
+fn setup_verified_jobs(state: &mut EscrowTestState, jobs: &[&[u8]]) {
+    state.register_agent(
+        &AGENT_OWNER,
+        b"FeeAgent",
+        b"https://agent.com",
+        b"pubkey",
+        vec![],
+        vec![],
+    );
+    for job in jobs {
+        state.init_job(&EMPLOYER, job, 1, None);
+        state.mark_job_verified(job);
+    }
+}
+
+#[test]
+fn test_fee_on_egld_release() {
+    let mut state = EscrowTestState::new();
+    setup_verified_jobs(&mut state, &[b"job_fee_egld"]);
+    let egld = EgldOrEsdtTokenIdentifier::<StaticApi>::egld();
+
+    state.set_fee_bps(
+        &OWNER_ADDRESS,
+        egld.clone(),
+        250,
+        Some("E5029: Treasury address not set"),
+    );
+    state.set_treasury(&TREASURY);
+    state.set_fee_bps(
+        &OWNER_ADDRESS,
+        egld.clone(),
+        1_001,
+        Some("E5028: Fee exceeds the maximum"),
+    );
+    state.set_fee_bps(
+        &EMPLOYER,
+        egld.clone(),
+        250,
+        Some("E1007: Caller does not have the required role"),
+    );
+    state.set_fee_bps(&OWNER_ADDRESS, egld.clone(), 250, None);
+    assert_eq!(state.query_fee_bps(egld.clone()), 250);
+
+    // 2.5% of 999 = 24.975 → 24 to the treasury, 975 to the receiver
+    state.deposit_egld(&EMPLOYER, b"job_fee_egld", &AGENT_OWNER, b"poa", 1_000, 999);
+    state.release(&EMPLOYER, b"job_fee_egld");
+    state.world.check_account(TREASURY).balance(24u64);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 975);
+    assert_eq!(state.query_collected_fees(egld.clone()), 24);
+
+    state.set_fee_bps(&OWNER_ADDRESS, egld.clone(), 0, None);
+    assert_eq!(state.query_fee_bps(egld), 0);
+}
+
+// ============================================
+// 31. Protocol Fee on ESDT and Milestone Releases
+// ============================================
+
+#[test]
+fn test_fee_on_esdt_releases() {
+    let mut state = EscrowTestState::new();
+    setup_verified_jobs(&mut state, &[b"job_fee_big", b"job_fee_dust", b"ms-1"]);
+    let usdc = EgldOrEsdtTokenIdentifier::<StaticApi>::esdt(PAYMENT_TOKEN);
+    state.set_treasury(&TREASURY);
+    state.set_fee_bps(&OWNER_ADDRESS, usdc.clone(), 33, None);
+
+    // 0.33% of 1_001 = 3.3 → 3
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_fee_big",
+        &AGENT_OWNER,
+        b"poa",
+        1_000,
+        "USDC-abcdef",
+        0,
+        1_001,
+    );
+    state.release(&EMPLOYER, b"job_fee_big");
+
+    // 0.33% of 30 rounds to zero: nothing goes to the treasury
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_fee_dust",
+        &AGENT_OWNER,
+        b"poa",
+        1_000,
+        "USDC-abcdef",
+        0,
+        30,
+    );
+    state.release(&EMPLOYER, b"job_fee_dust");
+
+    // Milestone releases pay the fee per milestone: 0.33% of 1_000 = 3.3 → 3
+    state.deposit_milestones_esdt(
+        &EMPLOYER,
+        b"staged",
+        &AGENT_OWNER,
+        &[(1_000, 2_000, b"ms-1")],
+        1_000,
+        None,
+    );
+    state.release_milestone(&EMPLOYER, b"staged", 0, None);
+
+    state
+        .world
+        .check_account(TREASURY)
+        .esdt_balance(PAYMENT_TOKEN, 6u64);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 998u64 + 30 + 997);
+    assert_eq!(state.query_collected_fees(usdc.clone()), 6);
+    assert_eq!(state.query_all_collected_fees(), vec![(usdc, 6)]);
+
+    // The escrow books the gross amount as released
+    assert_eq!(
+        state.query_escrow(b"job_fee_big").released_amount,
+        BigUint::<StaticApi>::from(1_001u64)
+    );
+}
+
+// ============================================
+// 32. Multi-Token Escrow Released in One Transfer
+// ============================================
+
+#[test]
+fn test_bundle_release_with_fees() {
+    let mut state = EscrowTestState::new();
+    setup_verified_jobs(&mut state, &[b"job_bundle"]);
+    state.set_treasury(&TREASURY);
+    state.set_fee_bps(
+        &OWNER_ADDRESS,
+        EgldOrEsdtTokenIdentifier::esdt(PROJECT_TOKEN),
+        100,
+        None,
+    );
+
+    state.deposit_bundle(
+        &EMPLOYER,
+        b"job_bundle",
+        &AGENT_OWNER,
+        1_000,
+        &[(PAYMENT_TOKEN, 1_000), (PROJECT_TOKEN, 500)],
+    );
+    let escrow = state.query_escrow(b"job_bundle");
+    assert_eq!(
+        escrow.token_id,
+        EgldOrEsdtTokenIdentifier::esdt(PAYMENT_TOKEN)
+    );
+    assert_eq!(escrow.amount, BigUint::<StaticApi>::from(1_000u64));
+    assert_eq!(escrow.extra_payments.len(), 1);
+    assert_eq!(
+        escrow.extra_payments.get(0).amount,
+        BigUint::<StaticApi>::from(500u64)
+    );
+
+    // Fee applies per token: only PROJ is charged (1% of 500 = 5)
+    state.release(&EMPLOYER, b"job_bundle");
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000u64)
+        .esdt_balance(PROJECT_TOKEN, 495u64);
+    state
+        .world
+        .check_account(TREASURY)
+        .esdt_balance(PROJECT_TOKEN, 5u64);
+    assert_eq!(
+        state.query_escrow(b"job_bundle").status,
+        EscrowStatus::Released
+    );
+}
+
+// ============================================
+// 33. Multi-Token Escrow Refund and Dispute
+// ============================================
+
+#[test]
+fn test_bundle_refund_and_dispute() {
+    let mut state = EscrowTestState::new();
+    let bundle = [(PAYMENT_TOKEN, 1_000), (PROJECT_TOKEN, 500)];
+
+    state.world.current_block().block_timestamp_seconds(100);
+    state.deposit_bundle(&EMPLOYER, b"job_refund", &AGENT_OWNER, 200, &bundle);
+    state.deposit_bundle(&EMPLOYER, b"job_dispute", &AGENT_OWNER, 200, &bundle);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 2_000)
+        .esdt_balance(PROJECT_TOKEN, 1_000_000_000u64 - 1_000);
+
+    // A dispute settles the whole bundle on one side
+    state.open_dispute(&EMPLOYER, b"job_dispute", b"https://evidence", None);
+    state.add_arbiter(&OWNER_ADDRESS, &VALIDATOR, None);
+    state.resolve_dispute(
+        &VALIDATOR,
+        b"job_dispute",
+        400,
+        Some("E5031: Multi-token escrows settle all-or-nothing"),
+    );
+    state.resolve_dispute(&VALIDATOR, b"job_dispute", 1_000, None);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000u64)
+        .esdt_balance(PROJECT_TOKEN, 500u64);
+
+    // Refund returns every token at once
+    state.world.current_block().block_timestamp_seconds(201);
+    state.refund(&CLIENT, b"job_refund");
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 1_000)
+        .esdt_balance(PROJECT_TOKEN, 1_000_000_000u64 - 500);
+    assert_eq!(
+        state.query_escrow(b"job_refund").status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_bundle_dispute_for_employer() {
+    let mut state = EscrowTestState::new();
+    let bundle = [(PAYMENT_TOKEN, 1_000), (PROJECT_TOKEN, 500)];
+
+    state.deposit_bundle(&EMPLOYER, b"job_dispute", &AGENT_OWNER, 200, &bundle);
+    state.deposit_bundle(&EMPLOYER, b"job_other", &AGENT_OWNER, 200, &bundle);
+
+    // The employer gets the bundle back once, not its first token twice
+    state.open_dispute(&AGENT_OWNER, b"job_dispute", b"https://evidence", None);
+    state.add_arbiter(&OWNER_ADDRESS, &VALIDATOR, None);
+    state.resolve_dispute(&VALIDATOR, b"job_dispute", 0, None);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 1_000)
+        .esdt_balance(PROJECT_TOKEN, 1_000_000_000u64 - 500);
+    state
+        .world
+        .check_account(ESCROW_SC_ADDRESS)
+        .esdt_balance(PAYMENT_TOKEN, 1_000u64)
+        .esdt_balance(PROJECT_TOKEN, 500u64);
+
+    let escrow = state.query_escrow(b"job_dispute");
+    assert_eq!(escrow.status, EscrowStatus::Resolved);
+    assert_eq!(escrow.refunded_amount, BigUint::<StaticApi>::from(1_000u64));
+}
+
+// ============================================
+// 34. Top-Up
+// ============================================
+
+#[test]
+fn test_top_up() {
+    let mut state = EscrowTestState::new();
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_grow",
+        &AGENT_OWNER,
+        b"poa",
+        1_000,
+        "USDC-abcdef",
+        0,
+        400,
+    );
+    state.top_up(&EMPLOYER, b"job_grow", PAYMENT_TOKEN, 100, None);
+    assert_eq!(
+        state.query_escrow(b"job_grow").amount,
+        BigUint::<StaticApi>::from(500u64)
+    );
+
+    state.top_up(
+        &CLIENT,
+        b"job_grow",
+        PAYMENT_TOKEN,
+        100,
+        Some("E5003: Only the employer can call this"),
+    );
+    state.top_up(
+        &EMPLOYER,
+        b"job_grow",
+        PROJECT_TOKEN,
+        100,
+        Some("E5032: Top-up token is not part of this escrow"),
+    );
+
+    // Bundles take top-ups in any of their tokens
+    state.deposit_bundle(
+        &EMPLOYER,
+        b"job_bundle",
+        &AGENT_OWNER,
+        1_000,
+        &[(PAYMENT_TOKEN, 100), (PROJECT_TOKEN, 50)],
+    );
+    state.top_up(&EMPLOYER, b"job_bundle", PROJECT_TOKEN, 25, None);
+    let escrow = state.query_escrow(b"job_bundle");
+    assert_eq!(escrow.amount, BigUint::<StaticApi>::from(100u64));
+    assert_eq!(
+        escrow.extra_payments.get(0).amount,
+        BigUint::<StaticApi>::from(75u64)
+    );
+
+    // Refund returns the topped-up total
+    state.world.current_block().block_timestamp_seconds(1_001);
+    state.refund(&EMPLOYER, b"job_grow");
+    state.top_up(
+        &EMPLOYER,
+        b"job_grow",
+        PAYMENT_TOKEN,
+        100,
+        Some("E5007: Escrow already settled"),
+    );
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 100);
+}
+
+// ============================================
+// 35. Deadline Extension
+// ============================================
+
+#[test]
+fn test_extend_deadline() {
+    let mut state = EscrowTestState::new();
+    state.world.current_block().block_timestamp_seconds(100);
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_late",
+        &AGENT_OWNER,
+        b"poa",
+        500,
+        "USDC-abcdef",
+        0,
+        400,
+    );
+
+    state.extend_deadline(
+        &AGENT_OWNER,
+        b"job_late",
+        900,
+        Some("E5003: Only the employer can call this"),
+    );
+    state.extend_deadline(
+        &EMPLOYER,
+        b"job_late",
+        500,
+        Some("E5033: New deadline must be later than the current one"),
+    );
+    state.extend_deadline(
+        &EMPLOYER,
+        b"job_late",
+        400,
+        Some("E5033: New deadline must be later than the current one"),
+    );
+    state.extend_deadline(&EMPLOYER, b"job_late", 900, None);
+    assert_eq!(
+        state.query_escrow(b"job_late").deadline.as_u64_seconds(),
+        900
+    );
+
+    // The old deadline no longer unlocks a refund
+    state.world.current_block().block_timestamp_seconds(501);
+    state.refund_expect_err(&EMPLOYER, b"job_late", "E5005: Deadline has not passed yet");
+    state.world.current_block().block_timestamp_seconds(901);
+    state.refund(&EMPLOYER, b"job_late");
+}
+
+// ============================================
+// 36. Mutual Cancellation
+// ============================================
+
+#[test]
+fn test_mutual_cancel() {
+    let mut state = EscrowTestState::new();
+    state.world.current_block().block_timestamp_seconds(100);
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_cancel",
+        &AGENT_OWNER,
+        b"poa",
+        1_000,
+        "USDC-abcdef",
+        0,
+        400,
+    );
+
+    state.mutual_cancel(
+        &CLIENT,
+        b"job_cancel",
+        Some("E5034: Only the employer or the receiver can cancel"),
+    );
+
+    // One side alone only records the request, even if repeated
+    state.mutual_cancel(&AGENT_OWNER, b"job_cancel", None);
+    state.mutual_cancel(&AGENT_OWNER, b"job_cancel", None);
+    assert_eq!(
+        state.query_cancel_request(b"job_cancel"),
+        Some(AGENT_OWNER.to_managed_address())
+    );
+    assert_eq!(
+        state.query_escrow(b"job_cancel").status,
+        EscrowStatus::Active
+    );
+
+    // The other side confirms: refunded before the deadline
+    state.mutual_cancel(&EMPLOYER, b"job_cancel", None);
+    assert_eq!(
+        state.query_escrow(b"job_cancel").status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(state.query_cancel_request(b"job_cancel"), None);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64);
+    state.mutual_cancel(
+        &EMPLOYER,
+        b"job_cancel",
+        Some("E5007: Escrow already settled"),
+    );
+
+    // A disputed escrow goes to the arbiter instead
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_fight",
+        &AGENT_OWNER,
+        b"poa",
+        1_000,
+        "USDC-abcdef",
+        0,
+        400,
+    );
+    state.open_dispute(&AGENT_OWNER, b"job_fight", b"https://evidence", None);
+    state.mutual_cancel(
+        &EMPLOYER,
+        b"job_fight",
+        Some("E5019: Escrow is under dispute"),
+    );
+}
+
+// ============================================
+// 37. Escrows Listed by Employer and Receiver
+// ============================================
+
+#[test]
+fn test_escrow_enumeration() {
+    let mut state = EscrowTestState::new();
+    setup_verified_jobs(&mut state, &[b"job_a"]);
+
+    for (job, receiver) in [
+        (&b"job_a"[..], AGENT_OWNER),
+        (b"job_b", AGENT_OWNER),
+        (b"job_c", WORKER),
+        (b"job_d", AGENT_OWNER),
+    ] {
+        state.deposit_esdt(
+            &EMPLOYER,
+            job,
+            &receiver,
+            b"poa",
+            1_000,
+            "USDC-abcdef",
+            0,
+            100,
+        );
+    }
+    state.release(&EMPLOYER, b"job_a");
+
+    let ids = |jobs: &[&[u8]]| -> Vec<ManagedBuffer<StaticApi>> {
+        jobs.iter().map(|job| ManagedBuffer::from(*job)).collect()
+    };
+
+    assert_eq!(state.query_employer_escrow_count(&EMPLOYER), 4);
+    assert_eq!(
+        state.query_employer_escrows(&EMPLOYER, 0, 2, None),
+        ids(&[b"job_a", b"job_b"])
+    );
+    assert_eq!(
+        state.query_employer_escrows(&EMPLOYER, 2, 10, None),
+        ids(&[b"job_c", b"job_d"])
+    );
+    assert_eq!(
+        state.query_employer_escrows(&EMPLOYER, 1, 10, Some(EscrowStatus::Active)),
+        ids(&[b"job_c", b"job_d"])
+    );
+    assert_eq!(
+        state.query_employer_escrows(&EMPLOYER, 0, 10, Some(EscrowStatus::Released)),
+        ids(&[b"job_a"])
+    );
+    assert_eq!(
+        state.query_receiver_escrows(&AGENT_OWNER, 0, 10, Some(EscrowStatus::Active)),
+        ids(&[b"job_b", b"job_d"])
+    );
+    assert_eq!(
+        state.query_receiver_escrows(&WORKER, 0, 10, None),
+        ids(&[b"job_c"])
+    );
+    assert!(
+        state
+            .query_employer_escrows(&CLIENT, 0, 10, None)
+            .is_empty()
+    );
+}
+
+// ============================================
+// 38. Total Locked per Token
+// ============================================
+
+#[test]
+fn test_total_locked() {
+    let mut state = EscrowTestState::new();
+    setup_verified_jobs(&mut state, &[b"job_usdc"]);
+    let usdc = EgldOrEsdtTokenIdentifier::<StaticApi>::esdt(PAYMENT_TOKEN);
+    let proj = EgldOrEsdtTokenIdentifier::<StaticApi>::esdt(PROJECT_TOKEN);
+    let egld = EgldOrEsdtTokenIdentifier::<StaticApi>::egld();
+    state.set_treasury(&TREASURY);
+    state.set_fee_bps(&OWNER_ADDRESS, usdc.clone(), 100, None);
+
+    state.world.current_block().block_timestamp_seconds(100);
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_usdc",
+        &AGENT_OWNER,
+        b"poa",
+        1_000,
+        "USDC-abcdef",
+        0,
+        300,
+    );
+    state.deposit_egld(&EMPLOYER, b"job_egld", &AGENT_OWNER, b"poa", 200, 1_000);
+    state.deposit_bundle(
+        &EMPLOYER,
+        b"job_bundle",
+        &AGENT_OWNER,
+        1_000,
+        &[(PAYMENT_TOKEN, 200), (PROJECT_TOKEN, 50)],
+    );
+    state.top_up(&EMPLOYER, b"job_usdc", PAYMENT_TOKEN, 100, None);
+    assert_eq!(state.query_total_locked(usdc.clone()), 600);
+    assert_eq!(state.query_total_locked(egld.clone()), 1_000);
+    assert_eq!(
+        state.query_all_total_locked(),
+        vec![
+            (usdc.clone(), 600),
+            (egld.clone(), 1_000),
+            (proj.clone(), 50)
+        ]
+    );
+
+    // Releases count gross (fee included), refunds drop the token once empty
+    state.release(&EMPLOYER, b"job_usdc");
+    assert_eq!(state.query_total_locked(usdc.clone()), 200);
+    state.world.current_block().block_timestamp_seconds(201);
+    state.refund(&EMPLOYER, b"job_egld");
+    assert_eq!(state.query_total_locked(egld), 0);
+    assert_eq!(
+        state.query_all_total_locked(),
+        vec![(usdc, 200), (proj, 50)]
+    );
+}
+
+// ============================================
+// 39. Batch Refund and Release
+// ============================================
+
+fn outcome(job_id: &[u8], outcome: BatchOutcome, reason: &str) -> (Vec<u8>, BatchOutcome, String) {
+    (job_id.to_vec(), outcome, reason.to_string())
+}
+
+#[test]
+fn test_refund_batch_skips_ineligible() {
+    let mut state = EscrowTestState::new();
+    setup_verified_jobs(&mut state, &[b"job_verified"]);
+
+    for (job, deadline) in [
+        (&b"job_a"[..], 100),
+        (b"job_b", 100),
+        (b"job_late", 10_000),
+        (b"job_verified", 100),
+        (b"job_disputed", 100),
+    ] {
+        state.deposit_esdt(
+            &EMPLOYER,
+            job,
+            &AGENT_OWNER,
+            b"poa",
+            deadline,
+            "USDC-abcdef",
+            0,
+            100,
+        );
+    }
+    state.open_dispute(&EMPLOYER, b"job_disputed", b"ipfs://reason", None);
+    state.world.current_block().block_timestamp_seconds(101);
+
+    // A keeper (not a party) cleans up; duplicates and unknown jobs are skipped
+    let outcomes = state.refund_batch(
+        &CLIENT,
+        &[
+            b"job_a",
+            b"job_late",
+            b"job_verified",
+            b"job_disputed",
+            b"job_missing",
+            b"job_b",
+            b"job_a",
+        ],
+    );
+    assert_eq!(
+        outcomes,
+        vec![
+            outcome(b"job_a", BatchOutcome::Settled, ""),
+            outcome(
+                b"job_late",
+                BatchOutcome::Skipped,
+                "E5005: Deadline has not passed yet"
+            ),
+            outcome(
+                b"job_verified",
+                BatchOutcome::Skipped,
+                "E5024: Job is verified; it can only be released"
+            ),
+            outcome(
+                b"job_disputed",
+                BatchOutcome::Skipped,
+                "E5019: Escrow is under dispute"
+            ),
+            outcome(
+                b"job_missing",
+                BatchOutcome::Skipped,
+                "E5002: Escrow not found for this job"
+            ),
+            outcome(b"job_b", BatchOutcome::Settled, ""),
+            outcome(
+                b"job_a",
+                BatchOutcome::Skipped,
+                "E5007: Escrow already settled"
+            ),
+        ]
+    );
+
+    assert_eq!(state.query_escrow(b"job_a").status, EscrowStatus::Refunded);
+    assert_eq!(state.query_escrow(b"job_b").status, EscrowStatus::Refunded);
+    assert_eq!(state.query_escrow(b"job_late").status, EscrowStatus::Active);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 300);
+}
+
+#[test]
+fn test_release_batch_respects_challenge_window() {
+    let mut state = EscrowTestState::new();
+    state.world.current_block().block_timestamp_seconds(100);
+    setup_verified_jobs(&mut state, &[b"job_a", b"job_b"]);
+
+    for job in [&b"job_a"[..], b"job_b", b"job_unverified"] {
+        state.deposit_esdt(
+            &EMPLOYER,
+            job,
+            &AGENT_OWNER,
+            b"poa",
+            100_000,
+            "USDC-abcdef",
+            0,
+            200,
+        );
+    }
+
+    // Within the challenge window a keeper releases nothing
+    let jobs: [&[u8]; 3] = [b"job_a", b"job_unverified", b"job_b"];
+    let outcomes = state.release_batch(&CLIENT, &jobs);
+    assert_eq!(
+        outcomes,
+        vec![
+            outcome(
+                b"job_a",
+                BatchOutcome::Skipped,
+                "E5023: Challenge window has not elapsed"
+            ),
+            outcome(
+                b"job_unverified",
+                BatchOutcome::Skipped,
+                "E5004: Job must be verified before release"
+            ),
+            outcome(
+                b"job_b",
+                BatchOutcome::Skipped,
+                "E5023: Challenge window has not elapsed"
+            ),
+        ]
+    );
+
+    state
+        .world
+        .current_block()
+        .block_timestamp_seconds(100 + 24 * 60 * 60);
+    let outcomes = state.release_batch(&CLIENT, &jobs);
+    assert_eq!(
+        outcomes,
+        vec![
+            outcome(b"job_a", BatchOutcome::Settled, ""),
+            outcome(
+                b"job_unverified",
+                BatchOutcome::Skipped,
+                "E5004: Job must be verified before release"
+            ),
+            outcome(b"job_b", BatchOutcome::Settled, ""),
+        ]
+    );
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 400u64);
+}
+
+#[test]
+fn test_release_batch_pays_deregistered_agent() {
+    let mut state = EscrowTestState::new();
+    setup_verified_jobs(&mut state, &[b"job_agent", b"job_plain"]);
+    state.deposit_for_agent(&EMPLOYER, b"job_agent", 1, 1_000, 200, None);
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_plain",
+        &AGENT_OWNER,
+        b"poa",
+        1_000,
+        "USDC-abcdef",
+        0,
+        200,
+    );
+
+    // A deregistered agent's escrow pays the owner recorded at deposit
+    state.deregister_agent(&AGENT_OWNER, 1);
+    let jobs: [&[u8]; 2] = [b"job_agent", b"job_plain"];
+    let outcomes = state.release_batch(&EMPLOYER, &jobs);
+    assert_eq!(
+        outcomes,
+        vec![
+            outcome(b"job_agent", BatchOutcome::Settled, ""),
+            outcome(b"job_plain", BatchOutcome::Settled, ""),
+        ]
+    );
+}
+
+#[test]
+fn test_release_after_agent_deregistered() {
+    let mut state = EscrowTestState::new();
+    setup_verified_jobs(&mut state, &[]);
+    state.init_job(&EMPLOYER, b"job_agent", 1, None);
+    state.deposit_for_agent(&EMPLOYER, b"job_agent", 1, 1_000, 700, None);
+    state.mark_job_verified(b"job_agent");
+    state.deregister_agent(&AGENT_OWNER, 1);
+
+    state.release(&EMPLOYER, b"job_agent");
+    assert_eq!(
+        state.query_escrow(b"job_agent").status,
+        EscrowStatus::Released
+    );
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 700u64);
+}
+
+// ============================================
+// 40. Batch Limits
+// ============================================
+
+#[test]
+fn test_batch_limits() {
+    let mut state = EscrowTestState::new();
+    for job in [&b"job_a"[..], b"job_b"] {
+        state.deposit_esdt(
+            &EMPLOYER,
+            job,
+            &AGENT_OWNER,
+            b"poa",
+            100,
+            "USDC-abcdef",
+            0,
+            100,
+        );
+    }
+    state.world.current_block().block_timestamp_seconds(101);
+
+    state.refund_batch_expect_err(&CLIENT, &[], "E5035: Batch is empty");
+    let too_many: Vec<Vec<u8>> = (0..51).map(|i| format!("job_{i}").into_bytes()).collect();
+    let too_many: Vec<&[u8]> = too_many.iter().map(|job| job.as_slice()).collect();
+    state.refund_batch_expect_err(&CLIENT, &too_many, "E5036: Batch exceeds the maximum size");
+
+    // Below the per-job gas reserve nothing is touched
+    let outcomes = state.refund_batch_with_gas(&CLIENT, &[b"job_a", b"job_b"], 10_000_000);
+    assert_eq!(
+        outcomes,
+        vec![
+            outcome(b"job_a", BatchOutcome::NotProcessed, ""),
+            outcome(b"job_b", BatchOutcome::NotProcessed, ""),
+        ]
+    );
+    assert_eq!(state.query_escrow(b"job_a").status, EscrowStatus::Active);
+
+    let outcomes = state.refund_batch(&CLIENT, &[b"job_a", b"job_b"]);
+    assert_eq!(outcomes[0].1, BatchOutcome::Settled);
+    assert_eq!(outcomes[1].1, BatchOutcome::Settled);
+}
+
+// ============================================
+// 41. Streaming Escrow — Linear Vesting
+// ============================================
+
+#[test]
+fn test_stream_withdraw_vested() {
+    let mut state = EscrowTestState::new();
+    state.world.current_block().block_timestamp_seconds(100);
+
+    state.deposit_stream(
+        &EMPLOYER,
+        b"job_stream",
+        &AGENT_OWNER,
+        1_100,
+        1_100,
+        1_000,
+        Some("E5039: Stream must end after it starts"),
+    );
+    state.deposit_stream(
+        &EMPLOYER,
+        b"job_stream",
+        &AGENT_OWNER,
+        100,
+        1_100,
+        1_000,
+        None,
+    );
+    assert_eq!(state.query_vested_amount(b"job_stream"), (0, 0));
+    state.withdraw_vested(
+        &AGENT_OWNER,
+        b"job_stream",
+        Some("E5041: Nothing vested to withdraw"),
+    );
+    state.release_expect_err(
+        &EMPLOYER,
+        b"job_stream",
+        "E5037: Escrow is a stream; use withdraw_vested or stop_stream",
+    );
+
+    state.world.current_block().block_timestamp_seconds(350);
+    assert_eq!(state.query_vested_amount(b"job_stream"), (250, 250));
+    state.withdraw_vested(
+        &EMPLOYER,
+        b"job_stream",
+        Some("E5040: Only the receiver can withdraw"),
+    );
+    state.withdraw_vested(&AGENT_OWNER, b"job_stream", None);
+    assert_eq!(
+        state.query_escrow(b"job_stream").status,
+        EscrowStatus::PartiallyReleased
+    );
+
+    state.world.current_block().block_timestamp_seconds(600);
+    assert_eq!(state.query_vested_amount(b"job_stream"), (500, 250));
+    state.withdraw_vested(&AGENT_OWNER, b"job_stream", None);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 500u64);
+
+    // Past the end everything has vested; refunds do not apply to streams
+    state.world.current_block().block_timestamp_seconds(2_000);
+    assert_eq!(state.query_vested_amount(b"job_stream"), (1_000, 500));
+    state.refund_expect_err(
+        &EMPLOYER,
+        b"job_stream",
+        "E5037: Escrow is a stream; use withdraw_vested or stop_stream",
+    );
+    state.withdraw_vested(&AGENT_OWNER, b"job_stream", None);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000u64);
+    assert_eq!(
+        state.query_escrow(b"job_stream").status,
+        EscrowStatus::Released
+    );
+    state.withdraw_vested(
+        &AGENT_OWNER,
+        b"job_stream",
+        Some("E5007: Escrow already settled"),
+    );
+}
+
+// ============================================
+// 42. Streaming Escrow — Employer Stops the Stream
+// ============================================
+
+#[test]
+fn test_stop_stream() {
+    let mut state = EscrowTestState::new();
+    state.world.current_block().block_timestamp_seconds(100);
+    state.deposit_stream(
+        &EMPLOYER,
+        b"job_stream",
+        &AGENT_OWNER,
+        100,
+        1_100,
+        1_000,
+        None,
+    );
+
+    state.world.current_block().block_timestamp_seconds(400);
+    state.withdraw_vested(&AGENT_OWNER, b"job_stream", None);
+
+    // Vested-but-unwithdrawn goes to the receiver, the unvested rest back
+    state.world.current_block().block_timestamp_seconds(700);
+    state.stop_stream(
+        &AGENT_OWNER,
+        b"job_stream",
+        Some("E5003: Only the employer can call this"),
+    );
+    state.stop_stream(&EMPLOYER, b"job_stream", None);
+
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 600u64);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 600);
+    let escrow = state.query_escrow(b"job_stream");
+    assert_eq!(escrow.status, EscrowStatus::PartiallyReleased);
+    assert_eq!(escrow.refunded_amount, BigUint::from(400u64));
+    assert_eq!(state.query_vested_amount(b"job_stream"), (600, 0));
+
+    state.world.current_block().block_timestamp_seconds(900);
+    state.withdraw_vested(
+        &AGENT_OWNER,
+        b"job_stream",
+        Some("E5007: Escrow already settled"),
+    );
+    state.stop_stream(
+        &EMPLOYER,
+        b"job_stream",
+        Some("E5007: Escrow already settled"),
+    );
+
+    // Regular escrows are not streams
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_plain",
+        &AGENT_OWNER,
+        b"poa",
+        2_000,
+        "USDC-abcdef",
+        0,
+        100,
+    );
+    state.stop_stream(
+        &EMPLOYER,
+        b"job_plain",
+        Some("E5038: Escrow is not a stream"),
+    );
+}
+
+// ============================================
+// 43. Streaming Escrow — Paused While Disputed
+// ============================================
+
+#[test]
+fn test_stream_paused_while_disputed() {
+    let mut state = EscrowTestState::new();
+    state.add_arbiter(&OWNER_ADDRESS, &WORKER, None);
+    state.world.current_block().block_timestamp_seconds(100);
+    state.deposit_stream(
+        &EMPLOYER,
+        b"job_stream",
+        &AGENT_OWNER,
+        100,
+        1_100,
+        1_000,
+        None,
+    );
+
+    state.world.current_block().block_timestamp_seconds(300);
+    state.open_dispute(&EMPLOYER, b"job_stream", b"ipfs://reason", None);
+
+    // Vesting stops at the dispute; nothing moves until the arbiter decides
+    state.world.current_block().block_timestamp_seconds(800);
+    assert_eq!(state.query_vested_amount(b"job_stream"), (200, 0));
+    state.withdraw_vested(
+        &AGENT_OWNER,
+        b"job_stream",
+        Some("E5019: Escrow is under dispute"),
+    );
+    state.stop_stream(
+        &EMPLOYER,
+        b"job_stream",
+        Some("E5019: Escrow is under dispute"),
+    );
+
+    state.resolve_dispute(&WORKER, b"job_stream", 200, None);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 200u64);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 200);
+    state.withdraw_vested(
+        &AGENT_OWNER,
+        b"job_stream",
+        Some("E5007: Escrow already settled"),
+    );
+}
+
+// ============================================
+// 44. init_job Locks Service Payments in Escrow
+// ============================================
+
+#[test]
+fn test_init_job_locks_payment_in_escrow() {
+    let mut state = EscrowTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"EscrowedAgent",
+        b"https://agent.com",
+        b"pubkey",
+        vec![],
+        vec![(1, 500, PAYMENT_TOKEN.as_bytes(), 0)],
+    );
+    state.set_escrow_contract_address();
+    state.set_service_escrow_durations(&AGENT_OWNER, 1, &[(1, 1_000)], None);
+    assert_eq!(state.query_service_escrow_duration(1, 1), Some(1_000));
+
+    // One transaction creates the job and funds an agent-bound escrow with
+    // the service price; the overpayment goes straight back
+    state.world.current_block().block_timestamp_seconds(100);
+    state.init_job_with_payment(&EMPLOYER, b"job_paid", 1, 1, 600);
+    state.init_job_with_payment(&EMPLOYER, b"job_unverified", 1, 1, 500);
+
+    let escrow = state.query_escrow(b"job_paid");
+    assert_eq!(escrow.employer, EMPLOYER.to_managed_address());
+    assert_eq!(escrow.receiver, AGENT_OWNER.to_managed_address());
+    assert_eq!(escrow.agent_nonce, Some(1));
+    assert_eq!(escrow.amount, BigUint::from(500u64));
+    assert_eq!(escrow.deadline.as_u64_seconds(), 1_100);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 0u64);
+    state
+        .world
+        .check_account(ESCROW_SC_ADDRESS)
+        .esdt_balance(PAYMENT_TOKEN, 1_000u64);
+
+    // Verified work is paid out; unverified work goes back after the deadline
+    state.mark_job_verified(b"job_paid");
+    state.release(&EMPLOYER, b"job_paid");
+    state.world.current_block().block_timestamp_seconds(1_101);
+    state.refund(&WORKER, b"job_unverified");
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 500u64);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 500);
+}
+
+// ============================================
+// 45. Escrowed Service Payment Checks
+// ============================================
+
+#[test]
+fn test_init_job_escrow_checks() {
+    let mut state = EscrowTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"EscrowedAgent",
+        b"https://agent.com",
+        b"pubkey",
+        vec![],
+        vec![(1, 500, PAYMENT_TOKEN.as_bytes(), 0)],
+    );
+    state.set_service_escrow_durations(
+        &AGENT_OWNER,
+        1,
+        &[(2, 1_000)],
+        Some("E2005: Service config not found"),
+    );
+    state.set_service_escrow_durations(
+        &WORKER,
+        1,
+        &[(1, 1_000)],
+        Some("E1002: Only the agent owner can perform this action"),
+    );
+    state.set_service_escrow_durations(&AGENT_OWNER, 1, &[(1, 1_000)], None);
+
+    // No escrow configured on the validation registry: nothing is created
+    state.init_job_with_payment_expect_err(
+        &EMPLOYER,
+        b"job_1",
+        1,
+        1,
+        500,
+        "E3008: Escrow contract address not set",
+    );
+    state.set_escrow_contract_address();
+
+    // Only the validation registry deposits on an employer's behalf
+    state.init_job(&EMPLOYER, b"job_direct", 1, None);
+    state.deposit_for_job_expect_err(
+        &EMPLOYER,
+        b"job_direct",
+        1,
+        1_000,
+        500,
+        "E5042: Only the validation registry can call this",
+    );
+
+    // A zero duration switches the service back to direct payment
+    state.set_service_escrow_durations(&AGENT_OWNER, 1, &[(1, 0)], None);
+    assert_eq!(state.query_service_escrow_duration(1, 1), None);
+    state.init_job_with_payment(&EMPLOYER, b"job_1", 1, 1, 500);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 500u64);
+
+    // Removing the service clears its escrow duration
+    state.set_service_escrow_durations(&AGENT_OWNER, 1, &[(1, 1_000)], None);
+    state.remove_service_configs(&AGENT_OWNER, 1, vec![1]);
+    assert_eq!(state.query_service_escrow_duration(1, 1), None);
+}
+
+// ============================================
+// 46. Job Cleanup Waits for the Escrow
+// ============================================
+
+#[test]
+fn test_clean_old_jobs_keeps_funded_jobs() {
+    let mut state = EscrowTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"BoundAgent",
+        b"https://agent.com",
+        b"pubkey",
+        vec![],
+        vec![],
+    );
+    state.set_escrow_contract_address();
+    state.init_job(&EMPLOYER, b"job_bound", 1, None);
+    state.deposit_for_agent(&EMPLOYER, b"job_bound", 1, 1_000_000, 700, None);
+    state.mark_job_verified(b"job_bound");
+
+    // Verified and past retention, but the escrow still holds the payment
+    let four_days_ms: u64 = 4 * 24 * 60 * 60 * 1000;
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(four_days_ms);
+    state.clean_old_jobs(vec![b"job_bound"]);
+    assert!(state.query_is_job_verified(b"job_bound"));
+
+    state.release(&EMPLOYER, b"job_bound");
+    state.clean_old_jobs(vec![b"job_bound"]);
+    assert!(!state.query_is_job_verified(b"job_bound"));
+}
+
+#[test]
+fn test_clean_old_jobs_keeps_milestone_verification_jobs() {
+    let mut state = EscrowTestState::new();
+    setup_milestone_jobs(&mut state);
+    state.set_escrow_contract_address();
+    state.world.current_block().block_timestamp_seconds(0);
+    state.deposit_milestones_esdt(
+        &EMPLOYER,
+        b"staged",
+        &AGENT_OWNER,
+        &[(300, 1_000, b"ms-1"), (700, 2_000, b"ms-2")],
+        1_000,
+        None,
+    );
+    state.mark_job_verified(b"ms-1");
+    state.release_milestone(&EMPLOYER, b"staged", 0, None);
+
+    // ms-1's milestone is paid, but the escrow it belongs to still holds ms-2
+    let four_days_ms: u64 = 4 * 24 * 60 * 60 * 1000;
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(four_days_ms);
+    state.clean_old_jobs(vec![b"ms-1"]);
+    assert!(state.query_is_job_verified(b"ms-1"));
+
+    state.mark_job_verified(b"ms-2");
+    state.release_milestone(&EMPLOYER, b"staged", 1, None);
+    state.clean_old_jobs(vec![b"ms-1", b"ms-2"]);
+    assert!(!state.query_is_job_verified(b"ms-1"));
+    assert!(!state.query_is_job_verified(b"ms-2"));
+}
+
+// ============================================
+// 47. Disputes Close With the Challenge Window
+// ============================================
+
+#[test]
+fn test_dispute_closes_with_challenge_window() {
+    let mut state = EscrowTestState::new();
+    setup_verified_jobs(&mut state, &[b"job_late"]);
+    state.set_challenge_window(&OWNER_ADDRESS, 100, None);
+
+    state.world.current_block().block_timestamp_seconds(0);
+    state.deposit_esdt(
+        &EMPLOYER,
+        b"job_late",
+        &AGENT_OWNER,
+        b"poa",
+        1_000,
+        "USDC-abcdef",
+        0,
+        1_000,
+    );
+
+    // Past the window after verification anyone can release, so the
+    // employer can no longer freeze the escrow
+    state.world.current_block().block_timestamp_seconds(100);
+    state.open_dispute(
+        &EMPLOYER,
+        b"job_late",
+        b"https://evidence",
+        Some("E5046: Job is verified and its challenge window has elapsed; release it instead"),
+    );
+    state.release(&AGENT_OWNER, b"job_late");
+}
+
+// ============================================
+// 48. Dispute Payouts Pay the Protocol Fee
+// ============================================
+
+#[test]
+fn test_dispute_receiver_share_pays_fee() {
+    let mut state = EscrowTestState::new();
+    state.set_treasury(&TREASURY);
+    state.set_fee_bps(
+        &OWNER_ADDRESS,
+        EgldOrEsdtTokenIdentifier::esdt(PAYMENT_TOKEN),
+        500,
+        None,
+    );
+    state.add_arbiter(&OWNER_ADDRESS, &VALIDATOR, None);
+
+    state.deposit_esdt_with_arbiter(
+        &EMPLOYER,
+        b"job_fee",
+        &AGENT_OWNER,
+        1_000,
+        1_000,
+        &VALIDATOR,
+        None,
+    );
+    state.open_dispute(&EMPLOYER, b"job_fee", b"https://evidence", None);
+
+    // A delisted arbiter loses the escrow; any listed arbiter decides instead
+    state.add_arbiter(&OWNER_ADDRESS, &WORKER, None);
+    state.remove_arbiter(&OWNER_ADDRESS, &VALIDATOR);
+    state.resolve_dispute(
+        &VALIDATOR,
+        b"job_fee",
+        600,
+        Some("E5021: Only the arbiter can resolve this dispute"),
+    );
+
+    // 5% of the receiver's 600 goes to the treasury; the employer's 400 is fee-free
+    state.resolve_dispute(&WORKER, b"job_fee", 600, None);
+    state
+        .world
+        .check_account(TREASURY)
+        .esdt_balance(PAYMENT_TOKEN, 30u64);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 570u64);
+    state
+        .world
+        .check_account(EMPLOYER)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 600);
+}
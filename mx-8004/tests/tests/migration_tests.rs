@@ -0,0 +1,277 @@
+use common::migration::StorageVersionModule;
+use common::structs::JobStatus;
+use escrow::migration::{EscrowDataV0, MigrationModule as _};
+use escrow::storage::EscrowStatus;
+use multiversx_sc::codec::TopEncode;
+use multiversx_sc::types::{
+    BigUint, EgldOrEsdtTokenIdentifier, ManagedBuffer, TimestampMillis, TimestampSeconds,
+};
+use multiversx_sc_scenario::{
+    ScenarioTxWhitebox,
+    api::{DebugApi, StaticApi},
+};
+use mx_8004_tests::{
+    constants::*,
+    setup::{EscrowTestState, query_storage_version},
+};
+use validation_registry::migration::{JobDataV0, MigrationModule as _};
+
+/// Writes escrows in the version 0 layout and rolls the escrow contract back
+/// to an unversioned deployment.
+fn seed_legacy_escrows(state: &mut EscrowTestState, escrows: &[(&[u8], EscrowStatus, u64)]) {
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(ESCROW_SC_ADDRESS)
+        .whitebox(escrow::contract_obj, |sc| {
+            for (job_id, status, amount) in escrows {
+                let legacy = EscrowDataV0::<DebugApi> {
+                    employer: EMPLOYER.to_managed_address(),
+                    receiver: AGENT_OWNER.to_managed_address(),
+                    token_id: EgldOrEsdtTokenIdentifier::esdt(PAYMENT_TOKEN.to_token_identifier()),
+                    token_nonce: 0,
+                    amount: BigUint::from(*amount),
+                    poa_hash: ManagedBuffer::from(b"poa_hash"),
+                    deadline: TimestampSeconds::new(1_000_000),
+                    status: match status {
+                        EscrowStatus::Released => EscrowStatus::Released,
+                        EscrowStatus::Refunded => EscrowStatus::Refunded,
+                        _ => EscrowStatus::Active,
+                    },
+                };
+                let mut raw = ManagedBuffer::new();
+                legacy.top_encode(&mut raw).unwrap();
+                sc.raw_escrow_data(&ManagedBuffer::from(*job_id)).set(raw);
+            }
+            sc.storage_version().clear();
+        });
+}
+
+/// Writes a verified job in the version 0 layout and rolls the validation
+/// registry back to an unversioned deployment.
+fn seed_legacy_job(state: &mut EscrowTestState, job_id: &[u8]) {
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(VALIDATION_SC_ADDRESS)
+        .whitebox(validation_registry::contract_obj, |sc| {
+            let legacy = JobDataV0::<DebugApi> {
+                status: JobStatus::Verified,
+                proof: ManagedBuffer::from(b"proof"),
+                employer: EMPLOYER.to_managed_address(),
+                creation_timestamp: TimestampMillis::new(0),
+                agent_nonce: 1,
+            };
+            let mut raw = ManagedBuffer::new();
+            legacy.top_encode(&mut raw).unwrap();
+            sc.raw_job_data(&ManagedBuffer::from(job_id)).set(raw);
+            sc.storage_version().clear();
+        });
+}
+
+fn usdc() -> EgldOrEsdtTokenIdentifier<StaticApi> {
+    EgldOrEsdtTokenIdentifier::esdt(PAYMENT_TOKEN.to_token_identifier())
+}
+
+// ============================================
+// 1. Fresh Deployments Start at the Current Version
+// ============================================
+
+#[test]
+fn test_storage_version_set_on_deploy() {
+    let mut state = EscrowTestState::new();
+
+    for contract in [
+        IDENTITY_SC_ADDRESS,
+        VALIDATION_SC_ADDRESS,
+        REPUTATION_SC_ADDRESS,
+        ESCROW_SC_ADDRESS,
+    ] {
+        assert_eq!(query_storage_version(&mut state.world, contract), 1);
+    }
+}
+
+// ============================================
+// 2. Upgrade Migrates Legacy Records
+// ============================================
+
+#[test]
+fn test_upgrade_migrates_legacy_records() {
+    let mut state = EscrowTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"LegacyAgent",
+        b"https://agent.com",
+        b"pubkey",
+        vec![],
+        vec![],
+    );
+    state
+        .world
+        .set_esdt_balance(ESCROW_SC_ADDRESS, PAYMENT_TOKEN.as_bytes(), 700u64);
+
+    seed_legacy_job(&mut state, b"legacy_job");
+    seed_legacy_escrows(
+        &mut state,
+        &[
+            (b"legacy_job", EscrowStatus::Active, 700),
+            (b"legacy_done", EscrowStatus::Released, 300),
+        ],
+    );
+    assert_eq!(
+        query_storage_version(&mut state.world, ESCROW_SC_ADDRESS),
+        0
+    );
+
+    state.upgrade_validation(&[b"legacy_job"]);
+    state.upgrade_escrow(&[b"legacy_job", b"legacy_done"]);
+
+    assert_eq!(
+        query_storage_version(&mut state.world, VALIDATION_SC_ADDRESS),
+        1
+    );
+    assert_eq!(
+        query_storage_version(&mut state.world, ESCROW_SC_ADDRESS),
+        1
+    );
+    assert!(state.query_is_job_verified(b"legacy_job"));
+
+    let active = state.query_escrow(b"legacy_job");
+    assert_eq!(active.status, EscrowStatus::Active);
+    assert_eq!(active.released_amount, BigUint::<StaticApi>::zero());
+    assert_eq!(active.agent_nonce, None);
+    let done = state.query_escrow(b"legacy_done");
+    assert_eq!(done.released_amount, BigUint::<StaticApi>::from(300u64));
+
+    // Only the active escrow counts towards the locked total
+    assert_eq!(state.query_total_locked(usdc()), 700);
+    assert_eq!(state.query_employer_escrow_count(&EMPLOYER), 2);
+
+    state.release(&EMPLOYER, b"legacy_job");
+    assert_eq!(
+        state.query_escrow(b"legacy_job").status,
+        EscrowStatus::Released
+    );
+    assert_eq!(state.query_total_locked(usdc()), 0);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 700u64);
+}
+
+// ============================================
+// 3. Batched Migrations
+// ============================================
+
+#[test]
+fn test_batched_migrations() {
+    let mut state = EscrowTestState::new();
+    state
+        .world
+        .set_esdt_balance(ESCROW_SC_ADDRESS, PAYMENT_TOKEN.as_bytes(), 300u64);
+
+    seed_legacy_job(&mut state, b"batch_job");
+    seed_legacy_escrows(
+        &mut state,
+        &[
+            (b"batch_a", EscrowStatus::Active, 100),
+            (b"batch_b", EscrowStatus::Active, 200),
+            (b"batch_c", EscrowStatus::Refunded, 50),
+        ],
+    );
+
+    // Upgrading without ids only records the new version
+    state.upgrade_validation(&[]);
+    state.upgrade_escrow(&[]);
+    assert_eq!(
+        query_storage_version(&mut state.world, ESCROW_SC_ADDRESS),
+        1
+    );
+    assert_eq!(state.query_total_locked(usdc()), 0);
+
+    state.migrate_escrows_expect_err(
+        &CLIENT,
+        &[b"batch_a"],
+        "E1007: Caller does not have the required role",
+    );
+    state.migrate_jobs_expect_err(
+        &CLIENT,
+        &[b"batch_job"],
+        "E1007: Caller does not have the required role",
+    );
+
+    let oversized: Vec<Vec<u8>> = (0..101).map(|i| format!("job_{i}").into_bytes()).collect();
+    let oversized: Vec<&[u8]> = oversized.iter().map(Vec::as_slice).collect();
+    state.migrate_escrows_expect_err(
+        &OWNER_ADDRESS,
+        &oversized,
+        "E1010: Migration batch exceeds the maximum size",
+    );
+
+    // Unknown ids are skipped; repeats are no-ops
+    assert_eq!(
+        state.migrate_escrows(&OWNER_ADDRESS, &[b"batch_a", b"missing"]),
+        1
+    );
+    assert_eq!(
+        state.migrate_escrows(&OWNER_ADDRESS, &[b"batch_a", b"batch_b", b"batch_c"]),
+        2
+    );
+    assert_eq!(
+        state.migrate_escrows(&OWNER_ADDRESS, &[b"batch_a", b"batch_b", b"batch_c"]),
+        0
+    );
+    assert_eq!(state.query_total_locked(usdc()), 300);
+    assert_eq!(
+        state.query_escrow(b"batch_c").refunded_amount,
+        BigUint::<StaticApi>::from(50u64)
+    );
+
+    assert_eq!(state.migrate_jobs(&OWNER_ADDRESS, &[b"batch_job"]), 1);
+    assert_eq!(state.migrate_jobs(&OWNER_ADDRESS, &[b"batch_job"]), 0);
+    assert!(state.query_is_job_verified(b"batch_job"));
+}
+
+// ============================================
+// 4. Job Cleanup Keeps Jobs of Unmigrated Escrows
+// ============================================
+
+#[test]
+fn test_clean_old_jobs_keeps_legacy_escrow_jobs() {
+    let mut state = EscrowTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"LegacyAgent",
+        b"https://agent.com",
+        b"pubkey",
+        vec![],
+        vec![],
+    );
+    state.init_job(&EMPLOYER, b"legacy_job", 1, None);
+    state.mark_job_verified(b"legacy_job");
+    state.set_escrow_contract_address();
+    state
+        .world
+        .set_esdt_balance(ESCROW_SC_ADDRESS, PAYMENT_TOKEN.as_bytes(), 700u64);
+    seed_legacy_escrows(&mut state, &[(b"legacy_job", EscrowStatus::Active, 700)]);
+
+    // The legacy record does not decode as current escrow data, so the job
+    // is kept rather than the cleanup aborting
+    let four_days_ms: u64 = 4 * 24 * 60 * 60 * 1000;
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(four_days_ms);
+    state.clean_old_jobs(vec![b"legacy_job"]);
+    assert!(state.query_is_job_verified(b"legacy_job"));
+
+    state.upgrade_escrow(&[b"legacy_job"]);
+    state.clean_old_jobs(vec![b"legacy_job"]);
+    assert!(state.query_is_job_verified(b"legacy_job"));
+
+    state.release(&EMPLOYER, b"legacy_job");
+    state.clean_old_jobs(vec![b"legacy_job"]);
+    assert!(!state.query_is_job_verified(b"legacy_job"));
+}
@@ -0,0 +1,195 @@
+use common::roles::Role;
+use escrow::storage::{BatchOutcome, EscrowStatus};
+use mx_8004_tests::{
+    constants::*,
+    setup::{
+        AgentTestState, EscrowTestState, grant_role, pause, query_is_paused, revoke_role, unpause,
+    },
+};
+
+// ============================================
+// 1. Owner and Pausers
+// ============================================
+
+#[test]
+fn test_pause_access_control() {
+    let mut state = AgentTestState::new();
+    assert!(!query_is_paused(&mut state.world, IDENTITY_SC_ADDRESS));
+
+    pause(
+        &mut state.world,
+        &VALIDATOR,
+        IDENTITY_SC_ADDRESS,
+        Some("E1007: Caller does not have the required role"),
+    );
+
+    // A pauser can pause but only the owner or an admin can unpause
+    grant_role(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        IDENTITY_SC_ADDRESS,
+        Role::Pauser,
+        &VALIDATOR,
+        None,
+    );
+    pause(&mut state.world, &VALIDATOR, IDENTITY_SC_ADDRESS, None);
+    assert!(query_is_paused(&mut state.world, IDENTITY_SC_ADDRESS));
+    unpause(
+        &mut state.world,
+        &VALIDATOR,
+        IDENTITY_SC_ADDRESS,
+        Some("E1007: Caller does not have the required role"),
+    );
+    unpause(&mut state.world, &OWNER_ADDRESS, IDENTITY_SC_ADDRESS, None);
+    assert!(!query_is_paused(&mut state.world, IDENTITY_SC_ADDRESS));
+
+    // Roles are per contract and can be revoked
+    pause(
+        &mut state.world,
+        &VALIDATOR,
+        VALIDATION_SC_ADDRESS,
+        Some("E1007: Caller does not have the required role"),
+    );
+    revoke_role(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        IDENTITY_SC_ADDRESS,
+        Role::Pauser,
+        &VALIDATOR,
+    );
+    pause(
+        &mut state.world,
+        &VALIDATOR,
+        IDENTITY_SC_ADDRESS,
+        Some("E1007: Caller does not have the required role"),
+    );
+    pause(&mut state.world, &OWNER_ADDRESS, IDENTITY_SC_ADDRESS, None);
+    assert!(query_is_paused(&mut state.world, IDENTITY_SC_ADDRESS));
+}
+
+// ============================================
+// 2. Registries Paused
+// ============================================
+
+#[test]
+fn test_registries_paused() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.init_job(&CLIENT, b"job_1", 1, None);
+    state.submit_proof(&WORKER, b"job_1", b"proof");
+
+    pause(&mut state.world, &OWNER_ADDRESS, IDENTITY_SC_ADDRESS, None);
+    pause(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        VALIDATION_SC_ADDRESS,
+        None,
+    );
+    pause(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        REPUTATION_SC_ADDRESS,
+        None,
+    );
+
+    state.register_agent_expect_err(
+        &WORKER,
+        b"Other",
+        b"https://other.example.com",
+        b"pubkey456",
+        "E1004: Contract is paused",
+    );
+    state.init_job_expect_err(&CLIENT, b"job_2", 1, None, "E1004: Contract is paused");
+    state.give_feedback_simple_expect_err(&CLIENT, b"job_1", 1, 80, "E1004: Contract is paused");
+
+    // Views stay available
+    assert_eq!(state.query_agent_owner(1), AGENT_OWNER.to_managed_address());
+    assert!(state.query_job_data(b"job_1").is_some());
+
+    unpause(&mut state.world, &OWNER_ADDRESS, IDENTITY_SC_ADDRESS, None);
+    unpause(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        VALIDATION_SC_ADDRESS,
+        None,
+    );
+    unpause(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        REPUTATION_SC_ADDRESS,
+        None,
+    );
+    state.init_job(&CLIENT, b"job_2", 1, None);
+    state.give_feedback_simple(&CLIENT, b"job_1", 1, 80);
+}
+
+// ============================================
+// 3. Escrow Paused — Refunds Stay Open
+// ============================================
+
+#[test]
+fn test_escrow_paused_refunds_available() {
+    let mut state = EscrowTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.com",
+        b"pubkey",
+        vec![],
+        vec![],
+    );
+    state.init_job(&EMPLOYER, b"job_verified", 1, None);
+    state.mark_job_verified(b"job_verified");
+    for job in [&b"job_verified"[..], b"job_a", b"job_b"] {
+        state.deposit_esdt(
+            &EMPLOYER,
+            job,
+            &AGENT_OWNER,
+            b"poa",
+            100,
+            "USDC-abcdef",
+            0,
+            100,
+        );
+    }
+
+    pause(&mut state.world, &OWNER_ADDRESS, ESCROW_SC_ADDRESS, None);
+    state.deposit_egld_expect_err(
+        &EMPLOYER,
+        b"job_new",
+        &AGENT_OWNER,
+        b"poa",
+        100,
+        1_000,
+        "E1004: Contract is paused",
+    );
+    state.release_expect_err(&EMPLOYER, b"job_verified", "E1004: Contract is paused");
+    state.open_dispute(
+        &EMPLOYER,
+        b"job_a",
+        b"ipfs://reason",
+        Some("E1004: Contract is paused"),
+    );
+
+    // Employers can still get their money back
+    state.world.current_block().block_timestamp_seconds(101);
+    state.refund(&CLIENT, b"job_a");
+    let outcomes = state.refund_batch(&CLIENT, &[b"job_b"]);
+    assert_eq!(outcomes[0].1, BatchOutcome::Settled);
+    assert_eq!(state.query_escrow(b"job_a").status, EscrowStatus::Refunded);
+    assert_eq!(state.query_escrow(b"job_b").status, EscrowStatus::Refunded);
+
+    unpause(&mut state.world, &OWNER_ADDRESS, ESCROW_SC_ADDRESS, None);
+    state.release(&EMPLOYER, b"job_verified");
+    assert_eq!(
+        state.query_escrow(b"job_verified").status,
+        EscrowStatus::Released
+    );
+}
@@ -0,0 +1,235 @@
+use common::roles::Role;
+use multiversx_sc::types::{DurationSeconds, ManagedAddress};
+use multiversx_sc_scenario::{ScenarioTxRun, ScenarioTxWhitebox, api::StaticApi};
+use mx_8004_tests::{
+    constants::*,
+    setup::{
+        AgentTestState, EscrowTestState, accept_ownership, change_owner_address, grant_role,
+        query_has_role, query_owner, revoke_role, transfer_ownership,
+    },
+};
+use proxies::escrow_proxy::EscrowProxy;
+use reputation_registry::storage::StorageModule;
+
+// ============================================
+// 1. Granting and Revoking Roles
+// ============================================
+
+#[test]
+fn test_grant_and_revoke_roles() {
+    let mut state = AgentTestState::new();
+    let new_addr = ManagedAddress::<StaticApi>::from(CLIENT.to_address());
+
+    grant_role(
+        &mut state.world,
+        &CLIENT,
+        VALIDATION_SC_ADDRESS,
+        Role::ConfigManager,
+        &WORKER,
+        Some("E1008: Only the owner or an admin can manage roles"),
+    );
+
+    // The owner appoints an admin, who manages every role but Admin
+    grant_role(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        VALIDATION_SC_ADDRESS,
+        Role::Admin,
+        &CLIENT,
+        None,
+    );
+    assert!(query_has_role(
+        &mut state.world,
+        VALIDATION_SC_ADDRESS,
+        Role::ConfigManager,
+        &CLIENT
+    ));
+    grant_role(
+        &mut state.world,
+        &CLIENT,
+        VALIDATION_SC_ADDRESS,
+        Role::Admin,
+        &WORKER,
+        Some("E1009: Only the owner can grant or revoke the admin role"),
+    );
+    grant_role(
+        &mut state.world,
+        &CLIENT,
+        VALIDATION_SC_ADDRESS,
+        Role::ConfigManager,
+        &WORKER,
+        None,
+    );
+    assert!(!query_has_role(
+        &mut state.world,
+        VALIDATION_SC_ADDRESS,
+        Role::Pauser,
+        &WORKER
+    ));
+
+    state.set_identity_registry_address(&WORKER, new_addr.clone());
+
+    revoke_role(
+        &mut state.world,
+        &CLIENT,
+        VALIDATION_SC_ADDRESS,
+        Role::ConfigManager,
+        &WORKER,
+    );
+    state.set_identity_registry_address_expect_err(
+        &WORKER,
+        new_addr,
+        "E1007: Caller does not have the required role",
+    );
+}
+
+// ============================================
+// 2. Roles Gate Admin Endpoints
+// ============================================
+
+#[test]
+fn test_escrow_admin_endpoints_use_roles() {
+    let mut state = EscrowTestState::new();
+    grant_role(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        ESCROW_SC_ADDRESS,
+        Role::ConfigManager,
+        &CLIENT,
+        None,
+    );
+    grant_role(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        ESCROW_SC_ADDRESS,
+        Role::Moderator,
+        &WORKER,
+        None,
+    );
+
+    // Config managers configure; the arbiter list stays with admins
+    state.set_challenge_window(
+        &WORKER,
+        10,
+        Some("E1007: Caller does not have the required role"),
+    );
+    state
+        .world
+        .tx()
+        .from(CLIENT)
+        .to(ESCROW_SC_ADDRESS)
+        .typed(EscrowProxy)
+        .set_challenge_window(DurationSeconds::new(10))
+        .run();
+    assert_eq!(state.query_challenge_window(), 10);
+    state.add_arbiter(
+        &CLIENT,
+        &VALIDATOR,
+        Some("E1007: Caller does not have the required role"),
+    );
+    state.add_arbiter(&OWNER_ADDRESS, &VALIDATOR, None);
+}
+
+// ============================================
+// 3. Two-Step Ownership Transfer
+// ============================================
+
+#[test]
+fn test_two_step_ownership_transfer() {
+    let mut state = AgentTestState::new();
+    let new_addr = ManagedAddress::<StaticApi>::from(WORKER.to_address());
+    assert_eq!(
+        query_owner(&mut state.world, VALIDATION_SC_ADDRESS),
+        OWNER_ADDRESS.to_managed_address()
+    );
+
+    transfer_ownership(
+        &mut state.world,
+        &CLIENT,
+        VALIDATION_SC_ADDRESS,
+        &CLIENT,
+        Some("E1005: Only the owner can call this"),
+    );
+    transfer_ownership(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        VALIDATION_SC_ADDRESS,
+        &CLIENT,
+        None,
+    );
+
+    // Nothing changes until the new owner accepts
+    assert_eq!(
+        query_owner(&mut state.world, VALIDATION_SC_ADDRESS),
+        OWNER_ADDRESS.to_managed_address()
+    );
+    accept_ownership(
+        &mut state.world,
+        &WORKER,
+        VALIDATION_SC_ADDRESS,
+        Some("E1006: Only the pending owner can accept ownership"),
+    );
+
+    // The upgrade rights have to move first
+    accept_ownership(
+        &mut state.world,
+        &CLIENT,
+        VALIDATION_SC_ADDRESS,
+        Some("E1014: Change the contract owner address to the new owner before accepting"),
+    );
+    change_owner_address(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        VALIDATION_SC_ADDRESS,
+        &CLIENT,
+    );
+    accept_ownership(&mut state.world, &CLIENT, VALIDATION_SC_ADDRESS, None);
+    assert_eq!(
+        query_owner(&mut state.world, VALIDATION_SC_ADDRESS),
+        CLIENT.to_managed_address()
+    );
+
+    state.set_identity_registry_address_expect_err(
+        &OWNER_ADDRESS,
+        new_addr.clone(),
+        "E1007: Caller does not have the required role",
+    );
+    state.set_identity_registry_address(&CLIENT, new_addr);
+    accept_ownership(
+        &mut state.world,
+        &CLIENT,
+        VALIDATION_SC_ADDRESS,
+        Some("E1006: Only the pending owner can accept ownership"),
+    );
+}
+
+// ============================================
+// 4. Legacy Moderators Migrated on Upgrade
+// ============================================
+
+#[test]
+fn test_moderators_migrated_on_upgrade() {
+    let mut state = AgentTestState::new();
+    state
+        .world
+        .tx()
+        .from(OWNER_ADDRESS)
+        .to(REPUTATION_SC_ADDRESS)
+        .whitebox(reputation_registry::contract_obj, |sc| {
+            sc.moderators().insert(VALIDATOR.to_managed_address());
+        });
+    assert!(!query_has_role(
+        &mut state.world,
+        REPUTATION_SC_ADDRESS,
+        Role::Moderator,
+        &VALIDATOR
+    ));
+
+    state.upgrade_reputation();
+    assert!(query_has_role(
+        &mut state.world,
+        REPUTATION_SC_ADDRESS,
+        Role::Moderator,
+        &VALIDATOR
+    ));
+}
@@ -1,8 +1,16 @@
+use common::roles::Role;
 use common::structs::JobStatus;
+use ed25519_dalek::SigningKey;
 use multiversx_sc::proxy_imports::OptionalValue;
-use multiversx_sc::types::{BigUint, ManagedAddress, ManagedBuffer};
+use multiversx_sc::types::{
+    BigUint, EgldOrEsdtTokenIdentifier, ManagedAddress, ManagedBuffer, ManagedVec,
+};
 use multiversx_sc_scenario::api::StaticApi;
-use mx_8004_tests::{constants::*, setup::AgentTestState};
+use mx_8004_tests::{
+    constants::*,
+    setup::{AgentTestState, grant_role, revoke_role, sign_feedback_auth},
+};
+use proxies::reputation_registry_proxy::{DisputeStatus, LeaderboardEntry};
 
 // ============================================
 // 1. Deploy
@@ -84,7 +92,7 @@ fn test_register_agent_duplicate() {
         b"TestAgent2",
         b"https://agent2.example.com",
         b"pubkey456",
-        "Agent already registered for this address",
+        "E2003: Agent already registered for this address",
     );
 }
 
@@ -301,7 +309,7 @@ fn test_init_job_invalid_payment() {
         "USDC-abcdef",
         0,
         50, // insufficient
-        "Insufficient payment",
+        "E3002: Insufficient payment",
     );
 }
 
@@ -395,7 +403,7 @@ fn test_validation_request_not_owner() {
         &VALIDATOR,
         b"https://request.uri",
         b"req_hash_err",
-        "Only the agent owner can perform this action",
+        "E1002: Only the agent owner can perform this action",
     );
 }
 
@@ -470,7 +478,7 @@ fn test_validation_response_not_validator() {
         b"https://response.uri",
         b"resp_hash",
         b"tag",
-        "Only the designated validator can respond",
+        "E3006: Only the designated validator can respond",
     );
 }
 
@@ -493,6 +501,9 @@ fn test_clean_old_jobs() {
     // Set block timestamp to 0
     state.world.current_block().block_timestamp_millis(0);
     state.init_job(&CLIENT, b"job_old", 1, None);
+    state.validation_request(&AGENT_OWNER, b"job_old", &VALIDATOR, b"uri", b"hash_old");
+    state.validation_response(&VALIDATOR, b"hash_old", 100, b"uri", b"resp", b"tag");
+    assert_eq!(state.query_agent_validations(1), 1);
 
     // Advance time by 4 days (> 3 days threshold)
     let four_days_ms: u64 = 4 * 24 * 60 * 60 * 1000;
@@ -503,9 +514,11 @@ fn test_clean_old_jobs() {
 
     state.clean_old_jobs(vec![b"job_old"]);
 
-    // Job should be cleaned
+    // Job and its validation request should be cleaned
     let job = state.query_job_data(b"job_old");
     assert!(job.is_none());
+    assert!(state.query_validation_status(b"hash_old").is_none());
+    assert_eq!(state.query_agent_validations(1), 0);
 }
 
 // ============================================
@@ -565,7 +578,16 @@ fn test_feedback_guards() {
         b"job_guard",
         1,
         80,
-        "Only the employer can provide feedback",
+        "E4001: Only the employer can provide feedback",
+    );
+
+    // Employer rates an agent other than the one that did the job -> error
+    state.give_feedback_simple_expect_err(
+        &CLIENT,
+        b"job_guard",
+        2,
+        80,
+        "E4019: Job was done for another agent",
     );
 
     // Employer submits feedback (no authorize needed in ERC-8004)
@@ -577,7 +599,7 @@ fn test_feedback_guards() {
         b"job_guard",
         1,
         90,
-        "Feedback already provided for this job",
+        "E4002: Feedback already provided for this job",
     );
 }
 
@@ -678,7 +700,7 @@ fn test_upgrade_all_contracts() {
 fn test_issue_token_already_issued() {
     let mut state = AgentTestState::new();
     // Token is already set via whitebox in new(), so issuing again should fail
-    state.issue_token_expect_err("Token already issued");
+    state.issue_token_expect_err("E2001: Token already issued");
 }
 
 // ============================================
@@ -805,7 +827,7 @@ fn test_set_identity_registry_address_not_owner() {
     state.set_identity_registry_address_expect_err(
         &CLIENT,
         new_addr,
-        "Endpoint can only be called by owner",
+        "E1007: Caller does not have the required role",
     );
 }
 
@@ -829,7 +851,7 @@ fn test_set_reputation_identity_address_not_owner() {
     state.set_reputation_identity_address_expect_err(
         &CLIENT,
         new_addr,
-        "Endpoint can only be called by owner",
+        "E1007: Caller does not have the required role",
     );
 }
 
@@ -853,7 +875,7 @@ fn test_set_reputation_validation_address_not_owner() {
     state.set_reputation_validation_address_expect_err(
         &CLIENT,
         new_addr,
-        "Endpoint can only be called by owner",
+        "E1007: Caller does not have the required role",
     );
 }
 
@@ -864,7 +886,12 @@ fn test_set_reputation_validation_address_not_owner() {
 #[test]
 fn test_submit_proof_nonexistent_job() {
     let mut state = AgentTestState::new();
-    state.submit_proof_expect_err(&WORKER, b"nonexistent-job", b"proof-data", "Job not found");
+    state.submit_proof_expect_err(
+        &WORKER,
+        b"nonexistent-job",
+        b"proof-data",
+        "E1001: Job not found",
+    );
 }
 
 // ============================================
@@ -884,7 +911,13 @@ fn test_init_job_duplicate() {
     );
 
     state.init_job(&CLIENT, b"job-dup", 1, None);
-    state.init_job_expect_err(&CLIENT, b"job-dup", 1, None, "Job already initialized");
+    state.init_job_expect_err(
+        &CLIENT,
+        b"job-dup",
+        1,
+        None,
+        "E3001: Job already initialized",
+    );
 }
 
 // ============================================
@@ -911,7 +944,7 @@ fn test_init_job_wrong_token() {
         "WRONG-abcdef",
         0,
         100,
-        "Invalid payment token",
+        "E3004: Invalid payment token",
     );
 }
 
@@ -935,7 +968,7 @@ fn test_set_metadata_not_owner() {
         &CLIENT,
         1,
         vec![(b"key1", b"val1")],
-        "Only the agent owner can perform this action",
+        "E1002: Only the agent owner can perform this action",
     );
 }
 
@@ -959,7 +992,7 @@ fn test_set_service_configs_not_owner() {
         &CLIENT,
         1,
         vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
-        "Only the agent owner can perform this action",
+        "E1002: Only the agent owner can perform this action",
     );
 }
 
@@ -983,7 +1016,7 @@ fn test_remove_metadata_not_owner() {
         &CLIENT,
         1,
         vec![b"key1"],
-        "Only the agent owner can perform this action",
+        "E1002: Only the agent owner can perform this action",
     );
 }
 
@@ -1007,7 +1040,7 @@ fn test_remove_service_configs_not_owner() {
         &CLIENT,
         1,
         vec![1],
-        "Only the agent owner can perform this action",
+        "E1002: Only the agent owner can perform this action",
     );
 }
 
@@ -1086,7 +1119,7 @@ fn test_give_feedback_simple_not_employer() {
         b"job-wrong-caller",
         1,
         80,
-        "Only the employer can provide feedback",
+        "E4001: Only the employer can provide feedback",
     );
 }
 
@@ -1137,9 +1170,11 @@ fn test_clean_old_jobs_not_old_enough() {
 
     state.world.current_block().block_timestamp_millis(0);
     state.init_job(&CLIENT, b"job_recent", 1, None);
+    state.validation_request(&AGENT_OWNER, b"job_recent", &VALIDATOR, b"uri", b"hash");
+    state.validation_response(&VALIDATOR, b"hash", 100, b"uri", b"resp", b"tag");
 
     // Advance only 1 day (< 3 days threshold)
-    let one_day_ms: u64 = 1 * 24 * 60 * 60 * 1000;
+    let one_day_ms: u64 = 24 * 60 * 60 * 1000;
     state
         .world
         .current_block()
@@ -1527,7 +1562,7 @@ fn test_submit_proof_with_nft_nonexistent_job() {
         b"nft-proof-hash",
         &AGENT_TOKEN,
         1,
-        "Job not found",
+        "E1001: Job not found",
     );
 }
 
@@ -1655,6 +1690,1520 @@ fn test_register_agent_token_not_issued() {
         b"ShouldFail",
         b"https://example.com/manifest",
         b"pubkey123",
-        "Token not issued",
+        "E2002: Token not issued",
+    );
+}
+
+// ============================================
+// 55. Init Job — service id carried into JobData
+// ============================================
+
+#[test]
+fn test_init_job_records_service_id() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![(7u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+
+    state.init_job_with_payment(&CLIENT, b"job-svc", 1, 7, "USDC-abcdef", 0, 100);
+    state.init_job(&CLIENT, b"job-no-svc", 1, None);
+
+    match state.query_job_data(b"job-svc") {
+        OptionalValue::Some(data) => assert_eq!(data.service_id, Some(7)),
+        OptionalValue::None => panic!("job-svc should exist"),
+    }
+    match state.query_job_data(b"job-no-svc") {
+        OptionalValue::Some(data) => assert_eq!(data.service_id, None),
+        OptionalValue::None => panic!("job-no-svc should exist"),
+    }
+}
+
+// ============================================
+// 56. Per-Service Reputation + Ranked Services
+// ============================================
+
+#[test]
+fn test_per_service_reputation() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"MultiServiceBot",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![
+            (1u32, 100u64, b"USDC-abcdef", 0u64),
+            (2u32, 50u64, b"USDC-abcdef", 0u64),
+        ],
+    );
+
+    state.init_job_with_payment(&CLIENT, b"svc1-a", 1, 1, "USDC-abcdef", 0, 100);
+    state.give_feedback_simple(&CLIENT, b"svc1-a", 1, 90);
+
+    state.init_job_with_payment(&CLIENT, b"svc1-b", 1, 1, "USDC-abcdef", 0, 100);
+    state.give_feedback_simple(&CLIENT, b"svc1-b", 1, 70);
+
+    state.init_job_with_payment(&CLIENT, b"svc2-a", 1, 2, "USDC-abcdef", 0, 50);
+    state.give_feedback_simple(&CLIENT, b"svc2-a", 1, 95);
+
+    // Job without a service only counts towards the overall score
+    state.init_job(&CLIENT, b"no-svc", 1, None);
+    state.give_feedback_simple(&CLIENT, b"no-svc", 1, 50);
+
+    // Overall: 90 → 80 → 85 → (85*3 + 50) / 4 = 76
+    assert_eq!(state.query_reputation_score(1), 76u64);
+    assert_eq!(state.query_total_jobs(1), 4);
+
+    let svc1 = state.query_service_reputation(1, 1);
+    assert_eq!(svc1.score, 80u64);
+    assert_eq!(svc1.total_jobs, 2);
+
+    let svc2 = state.query_service_reputation(1, 2);
+    assert_eq!(svc2.score, 95u64);
+    assert_eq!(svc2.total_jobs, 1);
+
+    // Unrated service reads as empty
+    let svc3 = state.query_service_reputation(1, 3);
+    assert_eq!(svc3.score, 0u64);
+    assert_eq!(svc3.total_jobs, 0);
+
+    let ranked = state.query_ranked_services(1);
+    assert_eq!(ranked.len(), 2);
+    assert_eq!(ranked.get(0).service_id, 2);
+    assert_eq!(ranked.get(1).service_id, 1);
+}
+
+// ============================================
+// 57. appendResponse — Threaded Responses (ERC-8004)
+// ============================================
+
+#[test]
+fn test_append_feedback_response_thread() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+
+    state.give_feedback(&CLIENT, 1, 40, b"quality", None);
+
+    // Agent owner and a third party both respond — neither overwrites the other
+    state.world.current_block().block_timestamp_seconds(1_000);
+    state.append_feedback_response(
+        &AGENT_OWNER,
+        1,
+        &CLIENT,
+        1,
+        b"https://refund.proof",
+        b"refund_hash",
+        None,
+    );
+    state.world.current_block().block_timestamp_seconds(2_000);
+    state.append_feedback_response(
+        &WORKER,
+        1,
+        &CLIENT,
+        1,
+        b"https://spam.tag",
+        b"spam_hash",
+        None,
+    );
+    state.append_feedback_response(&AGENT_OWNER, 1, &CLIENT, 1, b"https://follow.up", b"", None);
+
+    assert_eq!(state.query_response_count(1, &CLIENT, 1), 3);
+
+    let all = state.query_responses(1, &CLIENT, 1, 0, 10);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all[0].responder, AGENT_OWNER.to_managed_address());
+    assert_eq!(
+        all[0].response_uri,
+        ManagedBuffer::<StaticApi>::from(b"https://refund.proof")
+    );
+    assert_eq!(
+        all[0].response_hash,
+        ManagedBuffer::<StaticApi>::from(b"refund_hash")
+    );
+    assert_eq!(all[0].timestamp.as_u64_seconds(), 1_000);
+    assert_eq!(all[1].responder, WORKER.to_managed_address());
+    assert_eq!(all[1].timestamp.as_u64_seconds(), 2_000);
+
+    // Pagination
+    let page = state.query_responses(1, &CLIENT, 1, 1, 1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].responder, WORKER.to_managed_address());
+
+    let tail = state.query_responses(1, &CLIENT, 1, 2, 10);
+    assert_eq!(tail.len(), 1);
+    assert_eq!(
+        tail[0].response_uri,
+        ManagedBuffer::<StaticApi>::from(b"https://follow.up")
+    );
+
+    let past_end = state.query_responses(1, &CLIENT, 1, 5, 10);
+    assert!(past_end.is_empty());
+}
+
+// ============================================
+// 58. appendResponse — Unknown Feedback
+// ============================================
+
+#[test]
+fn test_append_feedback_response_unknown_feedback() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+
+    state.append_feedback_response(
+        &AGENT_OWNER,
+        1,
+        &CLIENT,
+        1,
+        b"https://response.uri",
+        b"",
+        Some("E4005: Feedback not found"),
+    );
+
+    state.give_feedback(&CLIENT, 1, 40, b"quality", None);
+    state.append_feedback_response(
+        &AGENT_OWNER,
+        1,
+        &CLIENT,
+        2,
+        b"https://response.uri",
+        b"",
+        Some("E4005: Feedback not found"),
+    );
+    assert_eq!(state.query_response_count(1, &CLIENT, 2), 0);
+}
+
+// ============================================
+// 59. giveFeedback — Agent-Signed feedbackAuth
+// ============================================
+
+const AGENT_SIGNING_KEY: [u8; 32] = [7u8; 32];
+
+fn setup_signing_agent() -> (AgentTestState, SigningKey) {
+    let mut state = AgentTestState::new();
+    let signing_key = SigningKey::from_bytes(&AGENT_SIGNING_KEY);
+    state.register_agent(
+        &AGENT_OWNER,
+        b"SigningAgent",
+        b"https://agent.example.com",
+        signing_key.verifying_key().as_bytes(),
+        vec![],
+        vec![],
+    );
+    state.world.current_block().block_timestamp_seconds(1_000);
+    (state, signing_key)
+}
+
+#[test]
+fn test_feedback_auth_index_limit() {
+    let (mut state, key) = setup_signing_agent();
+
+    let auth = sign_feedback_auth(&key, 1, &CLIENT, 2, 5_000, &AGENT_OWNER);
+    state.give_feedback_with_auth(&CLIENT, 1, 80, auth.clone(), None);
+    state.give_feedback_with_auth(&CLIENT, 1, 90, auth.clone(), None);
+
+    // Third feedback would be index 3 > index_limit 2
+    state.give_feedback_with_auth(
+        &CLIENT,
+        1,
+        70,
+        auth,
+        Some("E4010: Feedback index limit exceeded"),
+    );
+
+    // A fresh authorization with a higher limit unlocks the next index
+    let auth = sign_feedback_auth(&key, 1, &CLIENT, 3, 5_000, &AGENT_OWNER);
+    state.give_feedback_with_auth(&CLIENT, 1, 70, auth, None);
+}
+
+// ============================================
+// 60. giveFeedback — feedbackAuth Rejections
+// ============================================
+
+#[test]
+fn test_feedback_auth_rejections() {
+    let (mut state, key) = setup_signing_agent();
+
+    // Expired
+    let auth = sign_feedback_auth(&key, 1, &CLIENT, 5, 1_000, &AGENT_OWNER);
+    state.give_feedback_with_auth(
+        &CLIENT,
+        1,
+        80,
+        auth,
+        Some("E4011: Feedback authorization expired"),
+    );
+
+    // Issued to another client
+    let auth = sign_feedback_auth(&key, 1, &CLIENT, 5, 5_000, &AGENT_OWNER);
+    state.give_feedback_with_auth(
+        &WORKER,
+        1,
+        80,
+        auth,
+        Some("E4009: Feedback authorization is for another client"),
+    );
+
+    // Issued for another agent
+    let auth = sign_feedback_auth(&key, 2, &CLIENT, 5, 5_000, &AGENT_OWNER);
+    state.give_feedback_with_auth(
+        &CLIENT,
+        1,
+        80,
+        auth,
+        Some("E4008: Feedback authorization is for another agent"),
+    );
+
+    // Signer is not the agent owner
+    let auth = sign_feedback_auth(&key, 1, &CLIENT, 5, 5_000, &WORKER);
+    state.give_feedback_with_auth(
+        &CLIENT,
+        1,
+        80,
+        auth,
+        Some("E4012: Feedback authorization signer is not the agent owner"),
+    );
+
+    // Signed with a key other than the agent's registered public_key
+    let other_key = SigningKey::from_bytes(&[9u8; 32]);
+    let auth = sign_feedback_auth(&other_key, 1, &CLIENT, 5, 5_000, &AGENT_OWNER);
+    state.give_feedback_with_auth(&CLIENT, 1, 80, auth, Some("ed25519 verify error"));
+
+    // Tampered payload: limit raised after signing
+    let mut auth = sign_feedback_auth(&key, 1, &CLIENT, 5, 5_000, &AGENT_OWNER);
+    auth.index_limit = 50;
+    state.give_feedback_with_auth(&CLIENT, 1, 80, auth, Some("ed25519 verify error"));
+
+    // Nothing was stored: an authorization capped at index 1 still fits
+    let auth = sign_feedback_auth(&key, 1, &CLIENT, 1, 5_000, &AGENT_OWNER);
+    state.give_feedback_with_auth(&CLIENT, 1, 80, auth, None);
+}
+
+// ============================================
+// 61. giveFeedback — Mandatory feedbackAuth Toggle
+// ============================================
+
+#[test]
+fn test_feedback_auth_required_toggle() {
+    let (mut state, key) = setup_signing_agent();
+
+    state.set_feedback_auth_required(
+        &CLIENT,
+        true,
+        Some("E1007: Caller does not have the required role"),
+    );
+
+    // Optional by default
+    state.give_feedback(&CLIENT, 1, 50, b"quality", None);
+
+    state.set_feedback_auth_required(&OWNER_ADDRESS, true, None);
+    state.give_feedback(
+        &CLIENT,
+        1,
+        60,
+        b"",
+        Some("E4007: Feedback authorization required"),
+    );
+
+    let auth = sign_feedback_auth(&key, 1, &CLIENT, 2, 5_000, &AGENT_OWNER);
+    state.give_feedback_with_auth(&CLIENT, 1, 60, auth, None);
+
+    state.set_feedback_auth_required(&OWNER_ADDRESS, false, None);
+    state.give_feedback(&CLIENT, 1, 70, b"quality", None);
+}
+
+// ============================================
+// 62. Leaderboard — Top-N Overall and per Service
+// ============================================
+
+fn setup_leaderboard_agents() -> AgentTestState {
+    let mut state = AgentTestState::new();
+    for owner in [&AGENT_OWNER, &WORKER, &VALIDATOR] {
+        state.register_agent(
+            owner,
+            b"RankedAgent",
+            b"https://agent.example.com",
+            b"pubkey123",
+            vec![],
+            vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+        );
+    }
+    state
+}
+
+fn rate_job(
+    state: &mut AgentTestState,
+    job_id: &[u8],
+    agent_nonce: u64,
+    service_id: Option<u32>,
+    rating: u64,
+) {
+    match service_id {
+        Some(sid) => {
+            state.init_job_with_payment(&CLIENT, job_id, agent_nonce, sid, "USDC-abcdef", 0, 100)
+        }
+        None => state.init_job(&CLIENT, job_id, agent_nonce, None),
+    }
+    state.give_feedback_simple(&CLIENT, job_id, agent_nonce, rating);
+}
+
+fn ranked_nonces(board: &ManagedVec<StaticApi, LeaderboardEntry<StaticApi>>) -> Vec<u64> {
+    board.iter().map(|entry| entry.agent_nonce).collect()
+}
+
+#[test]
+fn test_leaderboard_top_agents() {
+    let mut state = setup_leaderboard_agents();
+
+    rate_job(&mut state, b"job-1", 1, None, 90);
+    rate_job(&mut state, b"job-2", 2, Some(1), 70);
+    rate_job(&mut state, b"job-3", 3, Some(1), 80);
+
+    assert_eq!(ranked_nonces(&state.query_top_agents(10)), vec![1, 3, 2]);
+    assert_eq!(ranked_nonces(&state.query_top_agents(2)), vec![1, 3]);
+
+    let top = state.query_top_agents(1);
+    assert_eq!(top.get(0).score, 90u64);
+    assert_eq!(top.get(0).total_jobs, 1);
+
+    // Only jobs created for service 1 rank on its board
+    assert_eq!(
+        ranked_nonces(&state.query_top_agents_for_service(1, 10)),
+        vec![3, 2]
+    );
+    assert!(state.query_top_agents_for_service(2, 10).is_empty());
+
+    // Shrink the board: agent 2 climbs to 85 and pushes agent 3 out
+    state.set_leaderboard_config(&OWNER_ADDRESS, 2, 0, None);
+    rate_job(&mut state, b"job-4", 2, Some(1), 100);
+    assert_eq!(ranked_nonces(&state.query_top_agents(10)), vec![1, 2]);
+    assert_eq!(state.query_top_agents(10).get(1).score, 85u64);
+
+    // Service board: agent 2 (85) now leads agent 3 (80)
+    assert_eq!(
+        ranked_nonces(&state.query_top_agents_for_service(1, 10)),
+        vec![2, 3]
+    );
+
+    // Full tie (85, 2 jobs each): the row already on the board keeps its place
+    rate_job(&mut state, b"job-5", 1, None, 80);
+    assert_eq!(state.query_reputation_score(1), 85u64);
+    assert_eq!(ranked_nonces(&state.query_top_agents(10)), vec![2, 1]);
+
+    // Equal score: more jobs ranks first
+    rate_job(&mut state, b"job-6", 1, None, 85);
+    assert_eq!(state.query_reputation_score(1), 85u64);
+    assert_eq!(ranked_nonces(&state.query_top_agents(10)), vec![1, 2]);
+}
+
+#[test]
+fn test_leaderboard_trimmed_after_shrink() {
+    let mut state = setup_leaderboard_agents();
+
+    rate_job(&mut state, b"job-1", 1, None, 90);
+    rate_job(&mut state, b"job-2", 2, None, 70);
+    rate_job(&mut state, b"job-3", 3, None, 80);
+    assert_eq!(state.stored_leaderboard(), vec![1, 3, 2]);
+
+    // Agent 2 climbs past agent 3 but not agent 1: the stored board is cut
+    // down to the new size, not just the views
+    state.set_leaderboard_config(&OWNER_ADDRESS, 1, 0, None);
+    rate_job(&mut state, b"job-4", 2, None, 100);
+    assert_eq!(state.stored_leaderboard(), vec![1]);
+}
+
+// ============================================
+// 63. Leaderboard — Minimum Job Count and Config
+// ============================================
+
+#[test]
+fn test_leaderboard_min_jobs() {
+    let mut state = setup_leaderboard_agents();
+
+    state.set_leaderboard_config(
+        &CLIENT,
+        10,
+        2,
+        Some("E1007: Caller does not have the required role"),
+    );
+    state.set_leaderboard_config(
+        &OWNER_ADDRESS,
+        0,
+        2,
+        Some("E4013: Leaderboard size must be between 1 and 100"),
+    );
+    state.set_leaderboard_config(
+        &OWNER_ADDRESS,
+        101,
+        2,
+        Some("E4013: Leaderboard size must be between 1 and 100"),
+    );
+
+    state.set_leaderboard_config(&OWNER_ADDRESS, 10, 2, None);
+
+    rate_job(&mut state, b"job-1", 1, None, 100);
+    assert!(state.query_top_agents(10).is_empty());
+
+    rate_job(&mut state, b"job-2", 2, None, 60);
+    rate_job(&mut state, b"job-3", 2, None, 60);
+    assert_eq!(ranked_nonces(&state.query_top_agents(10)), vec![2]);
+
+    rate_job(&mut state, b"job-4", 1, None, 80);
+    assert_eq!(ranked_nonces(&state.query_top_agents(10)), vec![1, 2]);
+
+    // Raising the threshold hides under-qualified rows right away
+    state.set_leaderboard_config(&OWNER_ADDRESS, 10, 3, None);
+    assert!(state.query_top_agents(10).is_empty());
+}
+
+// ============================================
+// 64. Feedback Disputes — Strike
+// ============================================
+
+#[test]
+fn test_feedback_dispute_strike() {
+    let mut state = setup_leaderboard_agents();
+
+    rate_job(&mut state, b"job-1", 1, Some(1), 90);
+    rate_job(&mut state, b"job-2", 1, Some(1), 30);
+    rate_job(&mut state, b"job-3", 1, None, 60);
+
+    // Overall: 90 → 60 → 60; service 1: 90 → 60
+    assert_eq!(state.query_reputation_score(1), 60u64);
+    assert_eq!(state.query_service_reputation(1, 1).score, 60u64);
+
+    // Only the agent owner can flag
+    state.flag_feedback(
+        &CLIENT,
+        b"job-2",
+        b"https://evidence",
+        Some("E4014: Only the agent owner can dispute feedback"),
+    );
+    state.flag_feedback(
+        &WORKER,
+        b"job-2",
+        b"https://evidence",
+        Some("E4014: Only the agent owner can dispute feedback"),
+    );
+
+    state.world.current_block().block_timestamp_seconds(1_000);
+    state.flag_feedback(&AGENT_OWNER, b"job-2", b"https://evidence", None);
+    state.flag_feedback(
+        &AGENT_OWNER,
+        b"job-2",
+        b"https://again",
+        Some("E4015: Feedback already disputed"),
+    );
+
+    let dispute = state.query_feedback_dispute(b"job-2");
+    assert_eq!(dispute.agent_nonce, 1);
+    assert_eq!(dispute.status, DisputeStatus::Pending);
+    assert_eq!(
+        dispute.evidence_uri,
+        ManagedBuffer::<StaticApi>::from(b"https://evidence")
+    );
+    assert_eq!(dispute.flagged_at.as_u64_seconds(), 1_000);
+
+    // Flagging alone changes nothing
+    assert_eq!(state.query_reputation_score(1), 60u64);
+
+    // Only moderators resolve; the Moderator role is granted by admins
+    state.strike_feedback(
+        &VALIDATOR,
+        b"job-2",
+        Some("E4018: Only a moderator can resolve disputes"),
+    );
+    grant_role(
+        &mut state.world,
+        &CLIENT,
+        REPUTATION_SC_ADDRESS,
+        Role::Moderator,
+        &VALIDATOR,
+        Some("E1008: Only the owner or an admin can manage roles"),
+    );
+    grant_role(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        REPUTATION_SC_ADDRESS,
+        Role::Moderator,
+        &VALIDATOR,
+        None,
+    );
+
+    state.strike_feedback(&VALIDATOR, b"job-2", None);
+    assert_eq!(
+        state.query_feedback_dispute(b"job-2").status,
+        DisputeStatus::Struck
+    );
+
+    // Overall: (60 * 3 - 30) / 2 = 75; service 1: (60 * 2 - 30) / 1 = 90
+    assert_eq!(state.query_reputation_score(1), 75u64);
+    assert_eq!(state.query_total_jobs(1), 2);
+    let svc1 = state.query_service_reputation(1, 1);
+    assert_eq!(svc1.score, 90u64);
+    assert_eq!(svc1.total_jobs, 1);
+
+    let top = state.query_top_agents(10);
+    assert_eq!(top.get(0).score, 75u64);
+    assert_eq!(top.get(0).total_jobs, 2);
+    let top_svc = state.query_top_agents_for_service(1, 10);
+    assert_eq!(top_svc.get(0).score, 90u64);
+    assert_eq!(top_svc.get(0).total_jobs, 1);
+
+    state.strike_feedback(
+        &VALIDATOR,
+        b"job-2",
+        Some("E4017: Dispute already resolved"),
+    );
+    state.uphold_feedback(
+        &VALIDATOR,
+        b"job-2",
+        Some("E4017: Dispute already resolved"),
+    );
+
+    // The employer still cannot re-rate the job
+    state.give_feedback_simple_expect_err(
+        &CLIENT,
+        b"job-2",
+        1,
+        100,
+        "E4002: Feedback already provided for this job",
+    );
+}
+
+#[test]
+fn test_feedback_dispute_strike_is_exact() {
+    let mut state = setup_leaderboard_agents();
+    grant_role(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        REPUTATION_SC_ADDRESS,
+        Role::Moderator,
+        &VALIDATOR,
+        None,
+    );
+
+    rate_job(&mut state, b"job-1", 1, Some(1), 100);
+    rate_job(&mut state, b"job-2", 1, Some(1), 0);
+    rate_job(&mut state, b"job-3", 1, Some(1), 0);
+    assert_eq!(state.query_reputation_score(1), 33u64);
+
+    // Recomputed from the rating sum: 100 / 2, not the rounded 33 * 3 / 2
+    state.flag_feedback(&AGENT_OWNER, b"job-3", b"https://evidence", None);
+    state.strike_feedback(&VALIDATOR, b"job-3", None);
+    assert_eq!(state.query_reputation_score(1), 50u64);
+    assert_eq!(state.query_service_reputation(1, 1).score, 50u64);
+
+    // Adding again continues from the exact sum: (100 + 0 + 80) / 3
+    rate_job(&mut state, b"job-4", 1, Some(1), 80);
+    assert_eq!(state.query_reputation_score(1), 60u64);
+}
+
+// ============================================
+// 65. Feedback Disputes — Uphold and Errors
+// ============================================
+
+#[test]
+fn test_feedback_dispute_uphold() {
+    let mut state = setup_leaderboard_agents();
+    rate_job(&mut state, b"job-1", 1, Some(1), 20);
+
+    state.flag_feedback(
+        &AGENT_OWNER,
+        b"unknown",
+        b"https://evidence",
+        Some("E4005: Feedback not found"),
+    );
+
+    grant_role(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        REPUTATION_SC_ADDRESS,
+        Role::Moderator,
+        &VALIDATOR,
+        None,
+    );
+    state.uphold_feedback(&VALIDATOR, b"job-1", Some("E4016: Dispute not found"));
+
+    state.flag_feedback(&AGENT_OWNER, b"job-1", b"https://evidence", None);
+    state.uphold_feedback(&VALIDATOR, b"job-1", None);
+    assert_eq!(
+        state.query_feedback_dispute(b"job-1").status,
+        DisputeStatus::Upheld
+    );
+    assert_eq!(state.query_reputation_score(1), 20u64);
+    assert_eq!(state.query_total_jobs(1), 1);
+
+    // Striking the only rating empties the agent's aggregates and boards
+    rate_job(&mut state, b"job-2", 2, Some(1), 70);
+    state.flag_feedback(&WORKER, b"job-2", b"https://evidence", None);
+
+    revoke_role(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        REPUTATION_SC_ADDRESS,
+        Role::Moderator,
+        &VALIDATOR,
+    );
+    state.strike_feedback(
+        &VALIDATOR,
+        b"job-2",
+        Some("E4018: Only a moderator can resolve disputes"),
+    );
+
+    grant_role(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        REPUTATION_SC_ADDRESS,
+        Role::Moderator,
+        &OWNER_ADDRESS,
+        None,
+    );
+    state.strike_feedback(&OWNER_ADDRESS, b"job-2", None);
+    assert_eq!(state.query_reputation_score(2), 0u64);
+    assert_eq!(state.query_total_jobs(2), 0);
+    assert!(state.query_ranked_services(2).is_empty());
+    assert_eq!(ranked_nonces(&state.query_top_agents(10)), vec![1]);
+    assert_eq!(
+        ranked_nonces(&state.query_top_agents_for_service(1, 10)),
+        vec![1]
+    );
+}
+
+// ============================================
+// 66. Clean Old Jobs — Expired and Finished Jobs
+// ============================================
+
+#[test]
+fn test_clean_old_jobs_prunes_expired_jobs() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+
+    state.world.current_block().block_timestamp_millis(0);
+    state.init_job(&CLIENT, b"job_new", 1, None);
+    state.init_job(&CLIENT, b"job_pending", 1, None);
+    state.submit_proof(&AGENT_OWNER, b"job_pending", b"proof");
+    state.init_job(&CLIENT, b"job_requested", 1, None);
+    state.validation_request(&AGENT_OWNER, b"job_requested", &VALIDATOR, b"uri", b"hash");
+
+    let four_days_ms: u64 = 4 * 24 * 60 * 60 * 1000;
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(four_days_ms);
+    state.clean_old_jobs(vec![
+        b"job_new",
+        b"job_pending",
+        b"job_requested",
+        b"job_unknown",
+    ]);
+
+    // Expired jobs go; a job waiting on its validator stays
+    assert!(state.query_job_data(b"job_new").is_none());
+    assert!(state.query_job_data(b"job_pending").is_none());
+    assert!(state.query_job_data(b"job_requested").is_some());
+    assert!(state.query_validation_status(b"hash").is_some());
+}
+
+// ============================================
+// 67. Clean Old Jobs — Configurable Retention
+// ============================================
+
+#[test]
+fn test_job_retention_config() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    assert_eq!(state.query_job_retention(), 3 * 24 * 60 * 60 * 1000);
+
+    state.set_job_retention(
+        &CLIENT,
+        1_000,
+        Some("E1007: Caller does not have the required role"),
+    );
+    let one_hour_ms: u64 = 60 * 60 * 1000;
+    state.set_job_retention(&OWNER_ADDRESS, one_hour_ms, None);
+    assert_eq!(state.query_job_retention(), one_hour_ms);
+
+    state.world.current_block().block_timestamp_millis(0);
+    state.init_job(&CLIENT, b"job", 1, None);
+    state.validation_request(&AGENT_OWNER, b"job", &VALIDATOR, b"uri", b"hash");
+    state.validation_response(&VALIDATOR, b"hash", 100, b"uri", b"resp", b"tag");
+
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(one_hour_ms);
+    state.clean_old_jobs(vec![b"job"]);
+    assert!(state.query_job_data(b"job").is_some());
+
+    state
+        .world
+        .current_block()
+        .block_timestamp_millis(one_hour_ms + 1);
+    state.clean_old_jobs(vec![b"job"]);
+    assert!(state.query_job_data(b"job").is_none());
+}
+
+// ============================================
+// 68. Clean Job Feedback After Job Cleanup
+// ============================================
+
+#[test]
+fn test_clean_job_feedback() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.set_job_retention(&OWNER_ADDRESS, 0, None);
+
+    for (job_id, hash) in [(&b"job-1"[..], &b"hash-1"[..]), (b"job-2", b"hash-2")] {
+        state.init_job(&CLIENT, job_id, 1, None);
+        state.validation_request(&AGENT_OWNER, job_id, &VALIDATOR, b"uri", hash);
+        state.validation_response(&VALIDATOR, hash, 100, b"uri", b"resp", b"tag");
+        state.give_feedback_simple(&CLIENT, job_id, 1, 80);
+    }
+    state.flag_feedback(&AGENT_OWNER, b"job-2", b"https://evidence", None);
+
+    // Nothing is cleared while the job still exists
+    state.clean_job_feedback(vec![b"job-1"]);
+    assert!(state.query_has_given_feedback(b"job-1"));
+
+    state.world.current_block().block_timestamp_millis(1);
+    state.clean_old_jobs(vec![b"job-1", b"job-2"]);
+    state.clean_job_feedback(vec![b"job-1", b"job-2"]);
+
+    // A pending dispute keeps its feedback until a moderator decides
+    assert!(!state.query_has_given_feedback(b"job-1"));
+    assert!(state.query_has_given_feedback(b"job-2"));
+    assert_eq!(state.query_reputation_score(1), 80u64);
+
+    // The job id starts clean when reused
+    state.init_job(&CLIENT, b"job-1", 1, None);
+    state.give_feedback_simple(&CLIENT, b"job-1", 1, 60);
+    assert_eq!(state.query_reputation_score(1), 73u64);
+}
+
+// ============================================
+// 69. Registration Fee and Bond
+// ============================================
+
+#[test]
+fn test_registration_fee_and_bond() {
+    let mut state = AgentTestState::new();
+    let egld = EgldOrEsdtTokenIdentifier::<StaticApi>::egld();
+    let usdc = EgldOrEsdtTokenIdentifier::<StaticApi>::esdt(PAYMENT_TOKEN.to_token_identifier());
+
+    state.set_registration_fee(
+        &CLIENT,
+        egld.clone(),
+        1_000,
+        Some("E1007: Caller does not have the required role"),
+    );
+    state.set_registration_fee(
+        &OWNER_ADDRESS,
+        egld.clone(),
+        1_000,
+        Some("E5029: Treasury address not set"),
+    );
+
+    state.world.account(TREASURY).nonce(1);
+    state.set_identity_treasury(&TREASURY);
+    let bad_token = EgldOrEsdtTokenIdentifier::<StaticApi>::esdt("usdc");
+    state.set_registration_fee(
+        &OWNER_ADDRESS,
+        bad_token.clone(),
+        1_000,
+        Some("E2009: Registration fee and bond must be in EGLD or a valid ESDT"),
+    );
+    state.set_registration_bond(
+        bad_token,
+        5_000,
+        Some("E2009: Registration fee and bond must be in EGLD or a valid ESDT"),
+    );
+    state.set_registration_fee(&OWNER_ADDRESS, egld.clone(), 1_000, None);
+    state.set_registration_bond(usdc.clone(), 5_000, None);
+    assert_eq!(state.query_registration_fee().unwrap().amount, 1_000u64);
+    assert_eq!(
+        state.query_registration_bond().unwrap().token_identifier,
+        usdc
+    );
+
+    // Exactly the fee plus the bond, nothing missing or extra
+    state.register_agent_expect_err(
+        &CLIENT,
+        b"Spam",
+        b"https://agent.com",
+        b"pubkey",
+        "E2006: Payment must match the registration fee and bond",
+    );
+    for payments in [
+        vec![(egld.clone(), 1_000)],
+        vec![(egld.clone(), 1_000), (usdc.clone(), 4_999)],
+        vec![
+            (egld.clone(), 1_000),
+            (usdc.clone(), 5_000),
+            (usdc.clone(), 1),
+        ],
+    ] {
+        state.register_agent_paying(
+            &CLIENT,
+            b"Spam",
+            &payments,
+            Some("E2006: Payment must match the registration fee and bond"),
+        );
+    }
+
+    state.register_agent_paying(
+        &CLIENT,
+        b"Paid",
+        &[(usdc.clone(), 5_000), (egld, 1_000)],
+        None,
+    );
+    state.world.check_account(TREASURY).balance(1_000u64);
+    state
+        .world
+        .check_account(IDENTITY_SC_ADDRESS)
+        .esdt_balance(PAYMENT_TOKEN, 5_000u64);
+    let bond = state.query_agent_bond(1).unwrap();
+    assert_eq!(bond.token_identifier, usdc);
+    assert_eq!(bond.amount, 5_000u64);
+}
+
+// ============================================
+// 70. Deregistration Returns the Bond Unless Slashed
+// ============================================
+
+#[test]
+fn test_deregister_and_slash_bond() {
+    let mut state = AgentTestState::new();
+    let egld = EgldOrEsdtTokenIdentifier::<StaticApi>::egld();
+    state.world.account(TREASURY).nonce(1);
+    state.set_identity_treasury(&TREASURY);
+
+    // Fee and bond in the same token are paid together
+    state.set_registration_fee(&OWNER_ADDRESS, egld.clone(), 100, None);
+    state.set_registration_bond(egld.clone(), 500, None);
+    state.register_agent_paying(&AGENT_OWNER, b"Bonded", &[(egld.clone(), 600)], None);
+    state.world.check_account(TREASURY).balance(100u64);
+
+    // Later bond changes leave existing bonds alone
+    state.set_registration_bond(egld.clone(), 0, None);
+    assert!(state.query_registration_bond().is_none());
+    assert_eq!(state.query_agent_bond(1).unwrap().amount, 500u64);
+
+    state.deregister_agent(&AGENT_OWNER, 1);
+    assert!(state.query_agent_bond(1).is_none());
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 - 100);
+
+    // The address can register again
+    state.register_agent_paying(&AGENT_OWNER, b"Again", &[(egld.clone(), 100)], None);
+    assert_eq!(state.query_agent_owner(2), AGENT_OWNER.to_managed_address());
+
+    // A slashed bond goes to the treasury and is not returned
+    state.set_registration_bond(egld.clone(), 500, None);
+    state.register_agent_paying(&CLIENT, b"Spammer", &[(egld, 600)], None);
+    state.slash_bond(
+        &CLIENT,
+        3,
+        Some("E1007: Caller does not have the required role"),
+    );
+    state.slash_bond(&OWNER_ADDRESS, 3, None);
+    state.slash_bond(&OWNER_ADDRESS, 3, Some("E2007: Agent has no bond"));
+    state.world.check_account(TREASURY).balance(800u64);
+
+    state.deregister_agent(&CLIENT, 3);
+    state
+        .world
+        .check_account(CLIENT)
+        .balance(1_000_000u64 - 600);
+}
+
+// ============================================
+// 71. EGLD-Priced Services
+// ============================================
+
+#[test]
+fn test_init_job_egld_service() {
+    let mut state = AgentTestState::new();
+    // The legacy "EGLD" name is stored as the native token
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![(1u32, 1_000u64, b"EGLD", 0u64)],
+    );
+    let OptionalValue::Some(config) = state.query_service_config(1, 1) else {
+        panic!("service config missing");
+    };
+    assert!(config.token_identifier.is_egld());
+
+    for (token, nonce) in [(&b"EGLD-000000"[..], 1u64), (&b"not-a-token"[..], 0u64)] {
+        state.set_service_configs_expect_err(
+            &AGENT_OWNER,
+            1,
+            vec![(2u32, 1_000u64, token, nonce)],
+            "E2008: Service price must be in EGLD (nonce 0) or a valid ESDT",
+        );
+    }
+
+    state.init_job_with_egld(
+        &CLIENT,
+        b"job_short",
+        1,
+        1,
+        999,
+        Some("E3002: Insufficient payment"),
+    );
+    state.init_job_with_payment_expect_err(
+        &CLIENT,
+        b"job_esdt",
+        1,
+        1,
+        "USDC-abcdef",
+        0,
+        1_000,
+        "E3004: Invalid payment token",
+    );
+    state.init_job_expect_err(
+        &CLIENT,
+        b"job_unpaid",
+        1,
+        Some(1),
+        "E3002: Insufficient payment",
+    );
+
+    // The agent owner gets the price, the rest goes back to the client
+    state.init_job_with_egld(&CLIENT, b"job_egld", 1, 1, 1_500, None);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 1_000);
+    state
+        .world
+        .check_account(CLIENT)
+        .balance(1_000_000u64 - 1_000);
+    state
+        .world
+        .check_account(VALIDATION_SC_ADDRESS)
+        .balance(0u64);
+}
+
+// ============================================
+// 72. ESDT Overpayment Is Refunded
+// ============================================
+
+#[test]
+fn test_init_job_esdt_overpayment_refund() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+
+    state.init_job_with_payment(&CLIENT, b"job_over", 1, 1, "USDC-abcdef", 0, 250);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 100u64);
+    state
+        .world
+        .check_account(CLIENT)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 100);
+    state
+        .world
+        .check_account(VALIDATION_SC_ADDRESS)
+        .esdt_balance(PAYMENT_TOKEN, 0u64);
+}
+
+// ============================================
+// 73. Meta-ESDT-Priced Services
+// ============================================
+
+#[test]
+fn test_init_job_meta_esdt_service() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![
+            (1u32, 400u64, b"META-abcdef", META_TOKEN_NONCE),
+            (2u32, 400u64, b"META-abcdef", META_TOKEN_NONCE + 1),
+        ],
+    );
+
+    // The nonce is part of the price
+    state.init_job_with_payment_expect_err(
+        &CLIENT,
+        b"job_nonce",
+        1,
+        2,
+        "META-abcdef",
+        META_TOKEN_NONCE,
+        400,
+        "E3004: Invalid payment token",
+    );
+
+    state.init_job_with_payment(
+        &CLIENT,
+        b"job_meta",
+        1,
+        1,
+        "META-abcdef",
+        META_TOKEN_NONCE,
+        500,
+    );
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_nft_balance_and_attributes(
+            META_TOKEN,
+            META_TOKEN_NONCE,
+            400u64,
+            ManagedBuffer::<StaticApi>::new(),
+        );
+    state
+        .world
+        .check_account(CLIENT)
+        .esdt_nft_balance_and_attributes(
+            META_TOKEN,
+            META_TOKEN_NONCE,
+            1_000_000u64 - 400,
+            ManagedBuffer::<StaticApi>::new(),
+        );
+}
+
+// ============================================
+// 74. Job Quote Replaces the Service Price
+// ============================================
+
+#[test]
+fn test_job_quote_accepted_by_init_job() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![(1u32, 100u64, b"USDC-abcdef", 0u64)],
+    );
+    state.world.current_block().block_timestamp_seconds(10);
+
+    state.post_quote(
+        &CLIENT,
+        b"quoted_job",
+        1,
+        &CLIENT,
+        (PAYMENT_TOKEN.as_bytes(), 0, 100),
+        1_000,
+        Some("E1002: Only the agent owner can perform this action"),
+    );
+    state.post_quote(
+        &AGENT_OWNER,
+        b"quoted_job",
+        1,
+        &CLIENT,
+        (PAYMENT_TOKEN.as_bytes(), 0, 100),
+        10,
+        Some("E3010: Quote has expired"),
+    );
+    state.post_quote(
+        &AGENT_OWNER,
+        b"quoted_job",
+        1,
+        &CLIENT,
+        (b"USDC-abcdef", 0, 60),
+        1_000,
+        None,
+    );
+    let quote = state.query_job_quote(b"quoted_job", 1).unwrap();
+    assert_eq!(quote.employer, CLIENT.to_managed_address());
+    assert_eq!(quote.price.amount.as_big_uint(), &BigUint::from(60u64));
+
+    // Only the quoted employer can use it, and only for the quoting agent
+    state.init_quoted_job(
+        &WORKER,
+        b"quoted_job",
+        1,
+        1,
+        ("USDC-abcdef", 0, 0),
+        Some("E3011: Quote was made for another employer"),
+    );
+    state.init_quoted_job(
+        &CLIENT,
+        b"quoted_job",
+        2,
+        1,
+        ("USDC-abcdef", 0, 0),
+        Some("E3009: Quote not found"),
+    );
+
+    // The quoted price applies instead of the service's, overpayment comes back
+    state.init_quoted_job(&CLIENT, b"quoted_job", 1, 1, ("USDC-abcdef", 0, 80), None);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .esdt_balance(PAYMENT_TOKEN, 60u64);
+    state
+        .world
+        .check_account(CLIENT)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64 - 60);
+    assert!(state.query_job_quote(b"quoted_job", 1).is_none());
+
+    state.post_quote(
+        &AGENT_OWNER,
+        b"quoted_job",
+        1,
+        &CLIENT,
+        (PAYMENT_TOKEN.as_bytes(), 0, 100),
+        1_000,
+        Some("E3001: Job already initialized"),
+    );
+}
+
+// ============================================
+// 75. Job Quote Expiry and Cancellation
+// ============================================
+
+#[test]
+fn test_job_quote_expiry_and_cancel() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"TestAgent",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.post_quote(
+        &AGENT_OWNER,
+        b"egld_job",
+        1,
+        &CLIENT,
+        (b"EGLD-000000", 0, 5_000),
+        100,
+        None,
+    );
+
+    state.world.current_block().block_timestamp_seconds(101);
+    state.init_quoted_job(
+        &CLIENT,
+        b"egld_job",
+        1,
+        1,
+        ("EGLD-000000", 0, 5_000),
+        Some("E3010: Quote has expired"),
+    );
+
+    // A new quote replaces the expired one
+    state.post_quote(
+        &AGENT_OWNER,
+        b"egld_job",
+        1,
+        &CLIENT,
+        (b"EGLD-000000", 0, 4_000),
+        200,
+        None,
+    );
+    state.init_quoted_job(
+        &CLIENT,
+        b"egld_job",
+        1,
+        1,
+        ("EGLD-000000", 0, 3_999),
+        Some("E3002: Insufficient payment"),
+    );
+    state.init_quoted_job(&CLIENT, b"egld_job", 1, 1, ("EGLD-000000", 0, 4_000), None);
+    state
+        .world
+        .check_account(AGENT_OWNER)
+        .balance(1_000_000u64 + 4_000);
+
+    // Cancelled quotes no longer price the job
+    state.post_quote(
+        &AGENT_OWNER,
+        b"cancelled_job",
+        1,
+        &CLIENT,
+        (b"USDC-abcdef", 0, 100),
+        1_000,
+        None,
+    );
+    state.cancel_quote(
+        &CLIENT,
+        b"cancelled_job",
+        1,
+        Some("E1002: Only the agent owner can perform this action"),
+    );
+    state.cancel_quote(&AGENT_OWNER, b"cancelled_job", 1, None);
+    state.cancel_quote(
+        &AGENT_OWNER,
+        b"cancelled_job",
+        1,
+        Some("E3009: Quote not found"),
+    );
+    state.init_quoted_job(
+        &CLIENT,
+        b"cancelled_job",
+        1,
+        1,
+        ("USDC-abcdef", 0, 100),
+        Some("E3009: Quote not found"),
+    );
+    state.init_job(&CLIENT, b"cancelled_job", 1, None);
+    state
+        .world
+        .check_account(CLIENT)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64);
+}
+
+#[test]
+fn test_job_quote_needs_opt_in() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"AgentOne",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.post_quote(
+        &AGENT_OWNER,
+        b"plain_job",
+        1,
+        &CLIENT,
+        (b"USDC-abcdef", 0, 100),
+        1_000,
+        None,
+    );
+
+    // Without the opt-in the quote is dropped and nothing is charged
+    state.init_job(&CLIENT, b"plain_job", 1, None);
+    assert!(state.query_job_quote(b"plain_job", 1).is_none());
+    state
+        .world
+        .check_account(CLIENT)
+        .esdt_balance(PAYMENT_TOKEN, 1_000_000_000u64);
+}
+
+#[test]
+fn test_job_quotes_from_several_agents() {
+    let mut state = AgentTestState::new();
+    state.register_agent(
+        &AGENT_OWNER,
+        b"AgentOne",
+        b"https://agent.example.com",
+        b"pubkey123",
+        vec![],
+        vec![],
+    );
+    state.register_agent(
+        &WORKER,
+        b"AgentTwo",
+        b"https://agent.example.com",
+        b"pubkey456",
+        vec![],
+        vec![],
+    );
+
+    // A second agent can quote the same job id without touching the first
+    // agent's quote
+    state.post_quote(
+        &AGENT_OWNER,
+        b"shared_job",
+        1,
+        &CLIENT,
+        (b"USDC-abcdef", 0, 100),
+        1_000,
+        None,
+    );
+    state.post_quote(
+        &WORKER,
+        b"shared_job",
+        2,
+        &CLIENT,
+        (b"USDC-abcdef", 0, 70),
+        1_000,
+        None,
+    );
+    state.post_quote(
+        &WORKER,
+        b"shared_job",
+        1,
+        &CLIENT,
+        (b"USDC-abcdef", 0, 10),
+        1_000,
+        Some("E1002: Only the agent owner can perform this action"),
+    );
+    let first = state.query_job_quote(b"shared_job", 1).unwrap();
+    assert_eq!(first.price.amount.as_big_uint(), &BigUint::from(100u64));
+    let second = state.query_job_quote(b"shared_job", 2).unwrap();
+    assert_eq!(second.price.amount.as_big_uint(), &BigUint::from(70u64));
+
+    // The employer picks the second agent's quote
+    state.init_quoted_job(&CLIENT, b"shared_job", 2, 1, ("USDC-abcdef", 0, 70), None);
+    state
+        .world
+        .check_account(WORKER)
+        .esdt_balance(PAYMENT_TOKEN, 70u64);
+    assert!(state.query_job_quote(b"shared_job", 2).is_none());
+
+    // The first agent's quote can no longer be used and is left to cancel
+    state.post_quote(
+        &AGENT_OWNER,
+        b"shared_job",
+        1,
+        &CLIENT,
+        (b"USDC-abcdef", 0, 90),
+        1_000,
+        Some("E3001: Job already initialized"),
+    );
+    state.cancel_quote(&AGENT_OWNER, b"shared_job", 1, None);
+    assert!(state.query_job_quote(b"shared_job", 1).is_none());
+}
+
+// ============================================
+// 76. Feedback Disputes — giveFeedback Entries
+// ============================================
+
+#[test]
+fn test_client_feedback_dispute() {
+    let mut state = setup_leaderboard_agents();
+    grant_role(
+        &mut state.world,
+        &OWNER_ADDRESS,
+        REPUTATION_SC_ADDRESS,
+        Role::Moderator,
+        &VALIDATOR,
+        None,
+    );
+
+    state.give_feedback(&CLIENT, 1, 10, b"quality", None);
+    state.give_feedback(&CLIENT, 1, 20, b"quality", None);
+    state.give_feedback(&CLIENT, 1, 30, b"quality", None);
+
+    state.flag_client_feedback(
+        &AGENT_OWNER,
+        1,
+        &CLIENT,
+        4,
+        b"https://evidence",
+        Some("E4005: Feedback not found"),
+    );
+    state.flag_client_feedback(
+        &WORKER,
+        1,
+        &CLIENT,
+        1,
+        b"https://evidence",
+        Some("E4014: Only the agent owner can dispute feedback"),
+    );
+
+    state.world.current_block().block_timestamp_seconds(2_000);
+    state.flag_client_feedback(&AGENT_OWNER, 1, &CLIENT, 1, b"https://evidence", None);
+    state.flag_client_feedback(
+        &AGENT_OWNER,
+        1,
+        &CLIENT,
+        1,
+        b"https://evidence",
+        Some("E4015: Feedback already disputed"),
+    );
+    let dispute = state.query_client_feedback_dispute(1, &CLIENT, 1);
+    assert_eq!(dispute.agent_nonce, 1);
+    assert_eq!(dispute.status, DisputeStatus::Pending);
+    assert_eq!(dispute.flagged_at.as_u64_seconds(), 2_000);
+
+    // Struck entries are marked revoked
+    state.strike_client_feedback(
+        &CLIENT,
+        1,
+        &CLIENT,
+        1,
+        Some("E4018: Only a moderator can resolve disputes"),
+    );
+    state.strike_client_feedback(&VALIDATOR, 1, &CLIENT, 1, None);
+    assert!(state.query_read_feedback(1, &CLIENT, 1).is_revoked);
+    assert_eq!(
+        state.query_client_feedback_dispute(1, &CLIENT, 1).status,
+        DisputeStatus::Struck
+    );
+    state.strike_client_feedback(
+        &VALIDATOR,
+        1,
+        &CLIENT,
+        1,
+        Some("E4017: Dispute already resolved"),
+    );
+
+    // Upheld entries stand
+    state.flag_client_feedback(&AGENT_OWNER, 1, &CLIENT, 2, b"https://evidence", None);
+    state.uphold_client_feedback(&VALIDATOR, 1, &CLIENT, 2);
+    assert!(!state.query_read_feedback(1, &CLIENT, 2).is_revoked);
+    assert_eq!(
+        state.query_client_feedback_dispute(1, &CLIENT, 2).status,
+        DisputeStatus::Upheld
+    );
+
+    // Undisputed entries cannot be resolved; revoked ones cannot be flagged
+    state.strike_client_feedback(&VALIDATOR, 1, &CLIENT, 3, Some("E4016: Dispute not found"));
+    state.flag_client_feedback(
+        &AGENT_OWNER,
+        1,
+        &CLIENT,
+        1,
+        b"https://evidence",
+        Some("E4006: Feedback already revoked"),
     );
 }
@@ -154,6 +154,7 @@ impl ContractInteract {
         let job_id = ManagedBuffer::new_from_bytes(&b""[..]);
         let agent_nonce = 0u64;
         let service_id = OptionalValue::Some(0u32);
+        let accept_quote = OptionalValue::Some(false);
 
         let response = self
             .interactor
@@ -162,7 +163,7 @@ impl ContractInteract {
             .to(self.state.current_address())
             .gas(30_000_000u64)
             .typed(validation_registry_proxy::ValidationRegistryProxy)
-            .init_job(job_id, agent_nonce, service_id)
+            .init_job(job_id, agent_nonce, service_id, accept_quote)
             .payment((
                 EsdtTokenIdentifier::from(token_id.as_str()),
                 token_nonce,
@@ -1,12 +1,31 @@
 multiversx_sc::imports!();
 
+use common::roles::Role;
+
+pub const DEFAULT_JOB_RETENTION: DurationMillis = DurationMillis::new(3 * 24 * 60 * 60 * 1000);
+
 #[multiversx_sc::module]
 pub trait ConfigModule:
-    common::cross_contract::CrossContractModule + crate::storage::ExternalStorageModule
+    common::cross_contract::CrossContractModule
+    + crate::storage::ExternalStorageModule
+    + common::roles::RolesModule
 {
-    #[only_owner]
     #[endpoint(set_identity_registry_address)]
     fn set_identity_registry_address(&self, address: ManagedAddress) {
+        self.require_role(Role::ConfigManager);
         self.identity_registry_address().set(&address);
     }
+
+    #[endpoint(set_escrow_contract_address)]
+    fn set_escrow_contract_address(&self, address: ManagedAddress) {
+        self.require_role(Role::ConfigManager);
+        self.escrow_contract_address().set(&address);
+    }
+
+    /// Minimum age of a verified job before `clean_old_jobs` may prune it.
+    #[endpoint(set_job_retention)]
+    fn set_job_retention(&self, retention: DurationMillis) {
+        self.require_role(Role::ConfigManager);
+        self.job_retention().set(retention);
+    }
 }
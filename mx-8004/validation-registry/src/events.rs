@@ -1,7 +1,7 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::structs::ValidationRequestData;
+use crate::structs::{JobQuote, ValidationRequestData};
 
 #[multiversx_sc::module]
 pub trait EventsModule {
@@ -22,4 +22,13 @@ pub trait EventsModule {
         #[indexed] request_hash: ManagedBuffer,
         data: ValidationRequestData<Self::Api>,
     );
+
+    #[event("jobCleaned")]
+    fn job_cleaned_event(&self, #[indexed] job_id: &ManagedBuffer, #[indexed] agent_nonce: u64);
+
+    #[event("quotePosted")]
+    fn quote_posted_event(&self, #[indexed] job_id: &ManagedBuffer, quote: &JobQuote<Self::Api>);
+
+    #[event("quoteCancelled")]
+    fn quote_cancelled_event(&self, #[indexed] job_id: &ManagedBuffer, #[indexed] agent_nonce: u64);
 }
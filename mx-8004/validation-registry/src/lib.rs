@@ -6,6 +6,8 @@ multiversx_sc::derive_imports!();
 pub mod config;
 pub mod errors;
 pub mod events;
+pub mod migration;
+pub mod quotes;
 pub mod storage;
 pub mod structs;
 pub mod views;
@@ -14,75 +16,103 @@ pub use structs::*;
 
 use errors::*;
 
-const THREE_DAYS: DurationMillis = DurationMillis::new(3 * 24 * 60 * 60 * 1000);
+/// Layout version written by this code; see `common::migration`.
+pub const STORAGE_VERSION: u32 = 1;
 
 #[multiversx_sc::contract]
 pub trait ValidationRegistry:
     common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
     + storage::ExternalStorageModule
     + views::ViewsModule
     + events::EventsModule
     + config::ConfigModule
+    + migration::MigrationModule
+    + quotes::QuotesModule
+    + common::roles::RolesModule
+    + common::pause::PauseModule
+    + common::migration::StorageVersionModule
+    + common::errors::ErrorCatalogModule
 {
     #[init]
     fn init(&self, identity_registry_address: ManagedAddress) {
         self.identity_registry_address()
             .set(&identity_registry_address);
+        self.job_retention().set(config::DEFAULT_JOB_RETENTION);
+        self.storage_version().set(STORAGE_VERSION);
     }
 
+    /// Brings storage up to `STORAGE_VERSION`. `job_ids` are migrated right
+    /// away; larger sets go through `migrate_jobs` in batches.
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self, job_ids: MultiValueEncoded<ManagedBuffer>) {
+        self.job_retention()
+            .set_if_empty(config::DEFAULT_JOB_RETENTION);
+        self.migrate_job_records(job_ids);
+        self.set_storage_version(STORAGE_VERSION);
+    }
 
+    /// Creates a job. Pass `accept_quote = true` (after `service_id`) to take
+    /// up the agent's quote for `job_id`; without it that quote is dropped
+    /// and the service config prices the job.
     #[payable("*")]
+    #[allow_multiple_var_args]
     #[endpoint(init_job)]
-    fn init_job(&self, job_id: ManagedBuffer, agent_nonce: u64, service_id: OptionalValue<u32>) {
+    fn init_job(
+        &self,
+        job_id: ManagedBuffer,
+        agent_nonce: u64,
+        service_id: OptionalValue<u32>,
+        accept_quote: OptionalValue<bool>,
+    ) {
+        self.require_not_paused();
         let job_mapper = self.job_data(&job_id);
         require!(job_mapper.is_empty(), ERR_JOB_ALREADY_INITIALIZED);
 
         let caller = self.blockchain().get_caller();
+        let service_id = service_id.into_option();
         job_mapper.set(JobData {
             status: JobStatus::New,
             proof: ManagedBuffer::new(),
-            employer: caller,
+            employer: caller.clone(),
             creation_timestamp: self.blockchain().get_block_timestamp_millis(),
             agent_nonce,
+            service_id,
         });
 
-        // If service_id provided, validate payment and forward to agent owner
-        if let OptionalValue::Some(sid) = service_id {
-            let identity_addr = self.identity_registry_address().get();
-            let agent_owner = self
-                .external_agents(identity_addr.clone())
-                .get_value(&agent_nonce);
+        // An accepted quote sets the price; otherwise the service config does,
+        // if service_id is provided. The price goes to the agent owner, or
+        // into escrow if the service has an escrow duration.
+        let quote_mapper = self.job_quote(&job_id, agent_nonce);
+        if accept_quote.into_option().unwrap_or(false) {
+            require!(!quote_mapper.is_empty(), ERR_QUOTE_NOT_FOUND);
+            let quote = quote_mapper.take();
+            require!(quote.employer == caller, ERR_QUOTE_MISMATCH);
+            require!(
+                self.blockchain().get_block_timestamp_seconds() <= quote.expires_at,
+                ERR_QUOTE_EXPIRED
+            );
+            let price = self.take_service_payment(&caller, quote.price);
+            self.pay_for_job(&job_id, agent_nonce, &caller, service_id, price);
+            return;
+        }
 
+        quote_mapper.clear();
+        if let Some(sid) = service_id {
+            let identity_addr = self.identity_registry_address().get();
+            self.require_same_shard(&identity_addr);
             let service_config_map = self.external_agent_service_config(identity_addr, agent_nonce);
 
-            if let Some(service_payment) = service_config_map.get(&sid) {
-                if let Some(pay) = self.call_value().single_optional() {
-                    require!(
-                        pay.token_identifier == service_payment.token_identifier
-                            && pay.token_nonce == service_payment.token_nonce,
-                        ERR_INVALID_PAYMENT
-                    );
-
-                    require!(
-                        pay.amount >= service_payment.amount,
-                        ERR_INSUFFICIENT_PAYMENT
-                    );
-
-                    if pay.amount > 0u64 {
-                        self.tx().to(&agent_owner).payment(pay.clone()).transfer();
-                    }
-                } else {
-                    // No payment sent — only valid if service is free
-                    require!(service_payment.amount == 0u64, ERR_INSUFFICIENT_PAYMENT);
-                }
+            if let Some(price) = service_config_map.get(&sid) {
+                let price = self.take_service_payment(&caller, price);
+                self.pay_for_job(&job_id, agent_nonce, &caller, service_id, price);
             }
         }
     }
 
     #[endpoint(submit_proof)]
     fn submit_proof(&self, job_id: ManagedBuffer, proof: ManagedBuffer) {
+        self.require_not_paused();
         let job_mapper = self.job_data(&job_id);
         require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
 
@@ -97,6 +127,7 @@ pub trait ValidationRegistry:
     #[payable("*")]
     #[endpoint(submit_proof_with_nft)]
     fn submit_proof_with_nft(&self, job_id: ManagedBuffer, proof: ManagedBuffer) {
+        self.require_not_paused();
         let job_mapper = self.job_data(&job_id);
         require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
 
@@ -105,6 +136,7 @@ pub trait ValidationRegistry:
 
         // Read agent token ID from identity-registry
         let identity_addr = self.identity_registry_address().get();
+        self.require_same_shard(&identity_addr);
         let expected_token_id = self.external_agent_token_id(identity_addr).get();
         require!(
             payment.token_identifier == expected_token_id,
@@ -132,6 +164,14 @@ pub trait ValidationRegistry:
             .transfer();
     }
 
+    /// Caches the owner of an agent when identity-registry is on another
+    /// shard, so `validation_request` can check it. Anyone can call this.
+    #[endpoint(sync_agent)]
+    fn sync_agent(&self, agent_nonce: u64) {
+        let identity_addr = self.identity_registry_address().get();
+        self.sync_agent_owner(identity_addr, agent_nonce);
+    }
+
     /// ERC-8004: Agent requests validation from a specific validator.
     /// MUST be called by the owner of the agent (agentId).
     #[endpoint(validation_request)]
@@ -142,6 +182,7 @@ pub trait ValidationRegistry:
         request_uri: ManagedBuffer,
         request_hash: ManagedBuffer,
     ) {
+        self.require_not_paused();
         let job_mapper = self.job_data(&job_id);
         require!(!job_mapper.is_empty(), ERR_JOB_NOT_FOUND);
 
@@ -150,10 +191,8 @@ pub trait ValidationRegistry:
         // Caller must be agent owner
         let caller = self.blockchain().get_caller();
         let identity_addr = self.identity_registry_address().get();
-        let agent_owner = self
-            .external_agents(identity_addr)
-            .get_value(&job_data.agent_nonce);
-        require!(caller == agent_owner, ERR_NOT_AGENT_OWNER);
+        let agent_owner = self.resolve_agent_owner(identity_addr, job_data.agent_nonce);
+        require!(agent_owner == Some(caller), ERR_NOT_AGENT_OWNER);
 
         // Store validation request
         let request_data = ValidationRequestData {
@@ -170,6 +209,7 @@ pub trait ValidationRegistry:
             .set(&request_data);
         self.agent_validations(job_data.agent_nonce)
             .insert(request_hash.clone());
+        self.job_validations(&job_id).insert(request_hash.clone());
 
         // Update job status
         job_mapper.update(|job| {
@@ -196,6 +236,7 @@ pub trait ValidationRegistry:
         response_hash: ManagedBuffer,
         tag: ManagedBuffer,
     ) {
+        self.require_not_paused();
         let request_mapper = self.validation_request_data(&request_hash);
         require!(!request_mapper.is_empty(), ERR_VALIDATION_REQUEST_NOT_FOUND);
 
@@ -218,6 +259,8 @@ pub trait ValidationRegistry:
             job_mapper.update(|job| {
                 job.status = JobStatus::Verified;
             });
+            self.job_verified_at(&updated_data.job_id)
+                .set_if_empty(self.blockchain().get_block_timestamp_seconds());
         }
 
         self.validation_response_event(
@@ -228,18 +271,170 @@ pub trait ValidationRegistry:
         );
     }
 
+    /// Prunes jobs older than the retention period, together with their
+    /// validation requests: verified jobs, and `New` / `Pending` jobs that
+    /// expired without being verified. Jobs awaiting a validator and jobs an
+    /// escrow still holds funds for are kept. Anyone can call this.
     #[endpoint(clean_old_jobs)]
     fn clean_old_jobs(&self, job_ids: MultiValueEncoded<ManagedBuffer>) {
+        self.require_not_paused();
         let current_time = self.blockchain().get_block_timestamp_millis();
+        let retention = self.job_retention().get();
         for job_id in job_ids {
             let job_mapper = self.job_data(&job_id);
             if job_mapper.is_empty() {
                 continue;
             }
             let job_data = job_mapper.get();
-            if current_time > job_data.creation_timestamp + THREE_DAYS {
-                job_mapper.clear();
+            if job_data.status == JobStatus::ValidationRequested
+                || current_time <= job_data.creation_timestamp + retention
+                || self.has_active_escrow(&job_id)
+            {
+                continue;
+            }
+
+            job_mapper.clear();
+            self.job_verified_at(&job_id).clear();
+            let mut agent_validations = self.agent_validations(job_data.agent_nonce);
+            for request_hash in self.job_validations(&job_id).iter() {
+                let request_mapper = self.validation_request_data(&request_hash);
+                if !request_mapper.is_empty() && request_mapper.get().job_id == job_id {
+                    request_mapper.clear();
+                    agent_validations.swap_remove(&request_hash);
+                }
             }
+            self.job_validations(&job_id).clear();
+
+            self.job_cleaned_event(&job_id, job_data.agent_nonce);
+        }
+    }
+
+    /// Whether the escrow contract still holds funds for the job, either in
+    /// the job's own escrow or in a milestone escrow released on it. Without
+    /// a configured escrow contract there is nothing to check; an escrow on
+    /// another shard cannot be read, so its jobs are always kept.
+    fn has_active_escrow(&self, job_id: &ManagedBuffer) -> bool {
+        let escrow_addr_mapper = self.escrow_contract_address();
+        if escrow_addr_mapper.is_empty() {
+            return false;
+        }
+        let escrow_addr = escrow_addr_mapper.get();
+        if !self.is_same_shard(&escrow_addr) {
+            return true;
         }
+        self.holds_funds(&escrow_addr, job_id)
+            || self
+                .external_verification_job_escrows(escrow_addr.clone(), job_id)
+                .iter()
+                .any(|escrow_job_id| self.holds_funds(&escrow_addr, &escrow_job_id))
+    }
+
+    /// A record that does not decode as the current `EscrowData` predates the
+    /// escrow's `migrate_escrow_records`; its job is kept until it is migrated.
+    fn holds_funds(&self, escrow_addr: &ManagedAddress, escrow_job_id: &ManagedBuffer) -> bool {
+        let escrow_mapper = self.external_escrow_data(escrow_addr.clone(), escrow_job_id);
+        if escrow_mapper.is_empty() {
+            return false;
+        }
+        match EscrowData::<Self::Api>::top_decode(escrow_mapper.get()) {
+            Ok(escrow) => escrow.locked_amount() > 0u64,
+            Err(_) => true,
+        }
+    }
+
+    /// Checks the call pays at least `price`, in EGLD or in the service's
+    /// ESDT, SFT or meta-ESDT nonce, and refunds anything above it to the
+    /// caller. Returns the part owed for the service. EGLD is matched as the
+    /// native `EGLD-000000` token, whether sent as call value or in a
+    /// multi-transfer, and always sent on as a plain EGLD transfer.
+    fn take_service_payment(
+        &self,
+        caller: &ManagedAddress,
+        price: Payment<Self::Api>,
+    ) -> Payment<Self::Api> {
+        let Some(payment) = self.call_value().single_optional() else {
+            sc_panic!(ERR_INSUFFICIENT_PAYMENT);
+        };
+        require!(
+            payment.token_identifier == price.token_identifier
+                && payment.token_nonce == price.token_nonce,
+            ERR_INVALID_PAYMENT
+        );
+        require!(payment.amount >= price.amount, ERR_INSUFFICIENT_PAYMENT);
+
+        let excess = payment.amount.as_big_uint() - price.amount.as_big_uint();
+        if excess > 0u64 {
+            self.tx()
+                .to(caller)
+                .egld_or_single_esdt(
+                    payment.token_identifier.as_legacy(),
+                    payment.token_nonce,
+                    &excess,
+                )
+                .transfer();
+        }
+        price
+    }
+
+    /// Sends a job's price to the agent owner, or locks it in escrow if the
+    /// service has an escrow duration.
+    fn pay_for_job(
+        &self,
+        job_id: &ManagedBuffer,
+        agent_nonce: u64,
+        employer: &ManagedAddress,
+        service_id: Option<u32>,
+        price: Payment<Self::Api>,
+    ) {
+        let identity_addr = self.identity_registry_address().get();
+        let escrow_duration = service_id.and_then(|sid| {
+            self.require_same_shard(&identity_addr);
+            self.external_agent_service_escrow_duration(identity_addr.clone(), agent_nonce)
+                .get(&sid)
+        });
+        if let Some(duration) = escrow_duration {
+            self.lock_in_escrow(job_id, agent_nonce, employer, duration, price);
+            return;
+        }
+
+        let Some(agent_owner) = self.resolve_agent_owner(identity_addr, agent_nonce) else {
+            sc_panic!(ERR_AGENT_NOT_FOUND);
+        };
+        self.tx()
+            .to(&agent_owner)
+            .egld_or_single_esdt(
+                price.token_identifier.as_legacy(),
+                price.token_nonce,
+                price.amount.as_big_uint(),
+            )
+            .transfer();
+    }
+
+    /// Deposits the `init_job` payment into the escrow contract on behalf of
+    /// the employer, refundable after `duration` if the job is not verified.
+    fn lock_in_escrow(
+        &self,
+        job_id: &ManagedBuffer,
+        agent_nonce: u64,
+        employer: &ManagedAddress,
+        duration: DurationSeconds,
+        payment: Payment<Self::Api>,
+    ) {
+        let escrow_mapper = self.escrow_contract_address();
+        require!(!escrow_mapper.is_empty(), ERR_ESCROW_NOT_SET);
+        let escrow_addr = escrow_mapper.get();
+        self.require_same_shard(&escrow_addr);
+        let deadline = self.blockchain().get_block_timestamp_seconds() + duration;
+
+        self.tx()
+            .to(escrow_addr)
+            .typed(proxies::escrow_proxy::EscrowProxy)
+            .deposit_for_job(job_id, agent_nonce, employer, deadline)
+            .egld_or_single_esdt(
+                payment.token_identifier.as_legacy(),
+                payment.token_nonce,
+                payment.amount.as_big_uint(),
+            )
+            .sync_call();
     }
 }
@@ -0,0 +1,64 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+use crate::structs::{JobData, JobStatus};
+use common::roles::Role;
+
+/// `JobData` as stored before `service_id` was added (storage version 0).
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Debug)]
+pub struct JobDataV0<M: ManagedTypeApi> {
+    pub status: JobStatus,
+    pub proof: ManagedBuffer<M>,
+    pub employer: ManagedAddress<M>,
+    pub creation_timestamp: TimestampMillis,
+    pub agent_nonce: u64,
+}
+
+/// Rewrites jobs stored in an older `JobData` layout. Job ids cannot be
+/// enumerated on-chain, so callers pass them in batches; jobs that are missing
+/// or already current are skipped, which makes every batch safe to repeat.
+#[multiversx_sc::module]
+pub trait MigrationModule:
+    common::cross_contract::CrossContractModule
+    + crate::storage::ExternalStorageModule
+    + common::roles::RolesModule
+    + common::migration::StorageVersionModule
+{
+    /// Returns how many jobs were rewritten.
+    #[endpoint(migrate_jobs)]
+    fn migrate_jobs(&self, job_ids: MultiValueEncoded<ManagedBuffer>) -> u32 {
+        self.require_role(Role::Admin);
+        self.migrate_job_records(job_ids)
+    }
+
+    fn migrate_job_records(&self, job_ids: MultiValueEncoded<ManagedBuffer>) -> u32 {
+        self.require_migration_batch(job_ids.len());
+        let mut migrated = 0u32;
+        for job_id in job_ids {
+            let raw_mapper = self.raw_job_data(&job_id);
+            if raw_mapper.is_empty() {
+                continue;
+            }
+            // `JobData` also decodes the old layout, so only an exact V0 match
+            // tells them apart
+            let Ok(legacy) = JobDataV0::<Self::Api>::top_decode(raw_mapper.get()) else {
+                continue;
+            };
+
+            self.job_data(&job_id).set(JobData {
+                status: legacy.status,
+                proof: legacy.proof,
+                employer: legacy.employer,
+                creation_timestamp: legacy.creation_timestamp,
+                agent_nonce: legacy.agent_nonce,
+                service_id: None,
+            });
+            migrated += 1;
+        }
+        migrated
+    }
+
+    /// `jobData` as raw bytes, to tell layouts apart.
+    #[storage_mapper("jobData")]
+    fn raw_job_data(&self, job_id: &ManagedBuffer) -> SingleValueMapper<ManagedBuffer>;
+}
@@ -0,0 +1,73 @@
+multiversx_sc::imports!();
+
+use crate::errors::{
+    ERR_JOB_ALREADY_INITIALIZED, ERR_NOT_AGENT_OWNER, ERR_QUOTE_EXPIRED, ERR_QUOTE_NOT_FOUND,
+};
+use crate::structs::JobQuote;
+
+/// Per-job prices agreed off-chain. The agent owner posts a quote under the
+/// job id it is for; the employer accepts it by calling `init_job` with that
+/// job id, the agent and `accept_quote` before it expires, paying the quoted
+/// price instead of the service config's. Quotes are kept per agent, so
+/// several agents can quote the same job id and the employer picks one.
+#[multiversx_sc::module]
+pub trait QuotesModule:
+    common::cross_contract::CrossContractModule
+    + common::cross_shard::CrossShardModule
+    + crate::storage::ExternalStorageModule
+    + crate::events::EventsModule
+    + common::roles::RolesModule
+    + common::pause::PauseModule
+{
+    /// Posts or replaces the agent's quote for `job_id`. Only the agent owner
+    /// can quote, and only for a job that has not been initialized yet.
+    #[endpoint(post_quote)]
+    fn post_quote(
+        &self,
+        job_id: ManagedBuffer,
+        agent_nonce: u64,
+        employer: ManagedAddress,
+        token: TokenId,
+        token_nonce: u64,
+        price: BigUint,
+        expires_at: TimestampSeconds,
+    ) {
+        self.require_not_paused();
+        require!(
+            self.job_data(&job_id).is_empty(),
+            ERR_JOB_ALREADY_INITIALIZED
+        );
+        self.require_quoting_agent_owner(agent_nonce);
+        let now = self.blockchain().get_block_timestamp_seconds();
+        require!(expires_at > now, ERR_QUOTE_EXPIRED);
+        let quote = JobQuote {
+            agent_nonce,
+            employer,
+            price: Payment::new(token, token_nonce, NonZeroBigUint::new_or_panic(price)),
+            expires_at,
+        };
+        self.quote_posted_event(&job_id, &quote);
+        self.job_quote(&job_id, agent_nonce).set(quote);
+    }
+
+    /// Withdraws the agent's quote for `job_id`. Expired quotes, and quotes
+    /// left over after the employer took another agent's, can be cancelled
+    /// too.
+    #[endpoint(cancel_quote)]
+    fn cancel_quote(&self, job_id: ManagedBuffer, agent_nonce: u64) {
+        self.require_not_paused();
+        let quote_mapper = self.job_quote(&job_id, agent_nonce);
+        require!(!quote_mapper.is_empty(), ERR_QUOTE_NOT_FOUND);
+        self.require_quoting_agent_owner(agent_nonce);
+
+        quote_mapper.clear();
+        self.quote_cancelled_event(&job_id, agent_nonce);
+    }
+
+    fn require_quoting_agent_owner(&self, agent_nonce: u64) {
+        let caller = self.blockchain().get_caller();
+        let identity_addr = self.identity_registry_address().get();
+        let agent_owner = self.resolve_agent_owner(identity_addr, agent_nonce);
+        require!(agent_owner == Some(caller), ERR_NOT_AGENT_OWNER);
+    }
+}
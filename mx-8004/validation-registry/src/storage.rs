@@ -1,6 +1,6 @@
 multiversx_sc::imports!();
 
-use crate::structs::{JobData, ValidationRequestData};
+use crate::structs::{JobData, JobQuote, ValidationRequestData};
 
 #[multiversx_sc::module]
 pub trait ExternalStorageModule: common::cross_contract::CrossContractModule {
@@ -9,9 +9,30 @@ pub trait ExternalStorageModule: common::cross_contract::CrossContractModule {
     #[storage_mapper("jobData")]
     fn job_data(&self, job_id: &ManagedBuffer) -> SingleValueMapper<JobData<Self::Api>>;
 
+    /// When the job first reached `Verified`; escrow uses it for the challenge window.
+    #[storage_mapper("jobVerifiedAt")]
+    fn job_verified_at(&self, job_id: &ManagedBuffer) -> SingleValueMapper<TimestampSeconds>;
+
+    /// An agent's open quote for a job id, until `init_job` for that agent
+    /// consumes it or the agent cancels it.
+    #[storage_mapper("jobQuote")]
+    fn job_quote(
+        &self,
+        job_id: &ManagedBuffer,
+        agent_nonce: u64,
+    ) -> SingleValueMapper<JobQuote<Self::Api>>;
+
     #[storage_mapper("identityRegistryAddress")]
     fn identity_registry_address(&self) -> SingleValueMapper<ManagedAddress>;
 
+    /// Escrow contract that receives payments for services with an escrow duration.
+    #[storage_mapper("escrowContractAddress")]
+    fn escrow_contract_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// How long after creation a verified job may be pruned by `clean_old_jobs`.
+    #[storage_mapper("jobRetention")]
+    fn job_retention(&self) -> SingleValueMapper<DurationMillis>;
+
     // ── ERC-8004 Validation storage ──
 
     #[storage_mapper("validationRequestData")]
@@ -22,4 +43,29 @@ pub trait ExternalStorageModule: common::cross_contract::CrossContractModule {
 
     #[storage_mapper("agentValidations")]
     fn agent_validations(&self, agent_nonce: u64) -> UnorderedSetMapper<ManagedBuffer>;
+
+    /// Request hashes filed for a job, so cleanup can remove them with it.
+    #[storage_mapper("jobValidations")]
+    fn job_validations(&self, job_id: &ManagedBuffer) -> UnorderedSetMapper<ManagedBuffer>;
+
+    // ── External storage ──
+
+    /// Read an escrow from the escrow contract's `escrowData` storage, raw so
+    /// that records the escrow has not migrated yet can still be told apart
+    /// from current `EscrowData`.
+    #[storage_mapper_from_address("escrowData")]
+    fn external_escrow_data(
+        &self,
+        address: ManagedAddress,
+        job_id: &ManagedBuffer,
+    ) -> SingleValueMapper<ManagedBuffer, ManagedAddress>;
+
+    /// Read which milestone escrows are released on a job from the escrow
+    /// contract's `verificationJobEscrows` storage.
+    #[storage_mapper_from_address("verificationJobEscrows")]
+    fn external_verification_job_escrows(
+        &self,
+        address: ManagedAddress,
+        verification_job_id: &ManagedBuffer,
+    ) -> UnorderedSetMapper<ManagedBuffer, ManagedAddress>;
 }
@@ -1,7 +1,7 @@
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-use crate::structs::{JobData, ValidationRequestData};
+use crate::structs::{JobData, JobQuote, JobSnapshot, SyncedJob, ValidationRequestData};
 
 #[multiversx_sc::module]
 pub trait ViewsModule:
@@ -13,6 +13,27 @@ pub trait ViewsModule:
         !job_mapper.is_empty() && job_mapper.get().status == crate::structs::JobStatus::Verified
     }
 
+    /// Timestamp of the first verifying response, or 0 if the job is not verified.
+    #[view(get_job_verified_at)]
+    fn get_job_verified_at(&self, job_id: ManagedBuffer) -> TimestampSeconds {
+        self.job_verified_at(&job_id).get()
+    }
+
+    #[view(get_escrow_contract_address)]
+    fn get_escrow_contract_address(&self) -> OptionalValue<ManagedAddress> {
+        let mapper = self.escrow_contract_address();
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    #[view(get_job_retention)]
+    fn get_job_retention(&self) -> DurationMillis {
+        self.job_retention().get()
+    }
+
     #[view(get_job_data)]
     fn get_job_data(&self, job_id: ManagedBuffer) -> OptionalValue<JobData<Self::Api>> {
         let job_mapper = self.job_data(&job_id);
@@ -23,6 +44,38 @@ pub trait ViewsModule:
         }
     }
 
+    /// The agent's open quote for a job id, if it posted one.
+    #[view(get_job_quote)]
+    fn get_job_quote(
+        &self,
+        job_id: ManagedBuffer,
+        agent_nonce: u64,
+    ) -> OptionalValue<JobQuote<Self::Api>> {
+        let mapper = self.job_quote(&job_id, agent_nonce);
+        (!mapper.is_empty()).then(|| mapper.get()).into()
+    }
+
+    /// What escrow needs to know about a job, stamped with the time of the
+    /// read; read by escrow's `sync_job` when the two contracts are on
+    /// different shards.
+    #[view(get_job_snapshot)]
+    fn get_job_snapshot(&self, job_id: ManagedBuffer) -> SyncedJob<Self::Api> {
+        let job_mapper = self.job_data(&job_id);
+        let job = (!job_mapper.is_empty()).then(|| {
+            let job = job_mapper.get();
+            JobSnapshot {
+                employer: job.employer,
+                agent_nonce: job.agent_nonce,
+                status: job.status,
+                verified_at: self.job_verified_at(&job_id).get(),
+            }
+        });
+        SyncedJob {
+            job,
+            synced_at: self.blockchain().get_block_timestamp_seconds(),
+        }
+    }
+
     /// ERC-8004: Returns validation status for a request hash.
     #[view(get_validation_status)]
     fn get_validation_status(
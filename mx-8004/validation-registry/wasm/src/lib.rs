@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           11
-// Async Callback (empty):               1
-// Total number of exported functions:  14
+// Endpoints:                           36
+// Async Callback:                       1
+// Total number of exported functions:  39
 
 #![no_std]
 
@@ -23,15 +23,40 @@ multiversx_sc_wasm_adapter::endpoints! {
         init_job => init_job
         submit_proof => submit_proof
         submit_proof_with_nft => submit_proof_with_nft
+        sync_agent => sync_agent
         validation_request => validation_request
         validation_response => validation_response
         clean_old_jobs => clean_old_jobs
+        get_synced_agent => synced_agent
         is_job_verified => is_job_verified
+        get_job_verified_at => get_job_verified_at
+        get_escrow_contract_address => get_escrow_contract_address
+        get_job_retention => get_job_retention
         get_job_data => get_job_data
+        get_job_quote => get_job_quote
+        get_job_snapshot => get_job_snapshot
         get_validation_status => get_validation_status
         get_agent_validations => get_agent_validations
         set_identity_registry_address => set_identity_registry_address
+        set_escrow_contract_address => set_escrow_contract_address
+        set_job_retention => set_job_retention
+        migrate_jobs => migrate_jobs
+        post_quote => post_quote
+        cancel_quote => cancel_quote
+        grant_role => grant_role
+        revoke_role => revoke_role
+        transfer_ownership => transfer_ownership
+        accept_ownership => accept_ownership
+        get_owner => current_owner
+        get_pending_owner => get_pending_owner
+        has_role => has_role
+        get_role_members => role_members
+        pause => pause
+        unpause => unpause
+        is_paused => paused
+        get_storage_version => storage_version
+        get_error_codes => get_error_codes
     )
 }
 
-multiversx_sc_wasm_adapter::async_callback_empty! {}
+multiversx_sc_wasm_adapter::async_callback! { validation_registry }